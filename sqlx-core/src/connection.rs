@@ -52,6 +52,15 @@ where
 
     /// Checks if a connection to the database is still valid.
     fn ping(&mut self) -> BoxFuture<crate::Result<()>>;
+
+    /// Returns `true` if this connection has encountered an error that it can not recover from;
+    /// e.g., the server closed the connection or the stream of messages can not be understood.
+    ///
+    /// A broken connection is closed when it is released to a [`Pool`] rather than being
+    /// returned to the idle connections of the pool.
+    fn is_broken(&self) -> bool {
+        false
+    }
 }
 
 /// Represents a type that can directly establish a new connection.
@@ -39,9 +39,15 @@ pub struct Column<DB>
 where
     DB: Database + ?Sized,
 {
+    /// The name of the column, if known.
     pub name: Option<Box<str>>,
+
+    /// The table the column is from, if it is a column of a table.
     pub table_id: Option<DB::TableId>,
+
+    /// The SQL type of the column, if known.
     pub type_info: Option<DB::TypeInfo>,
+
     /// Whether or not the column cannot be `NULL` (or if that is even knowable).
     pub non_null: Option<bool>,
 }
@@ -78,3 +78,47 @@ where
         self.as_ref().map_or(0, Encode::size_hint)
     }
 }
+
+/// A string that is bound as `NULL` if it is empty.
+///
+/// ```rust,ignore
+/// // binds `NULL` for an empty nickname
+/// sqlx::query("UPDATE users SET nickname = $1 WHERE id = $2")
+///     .bind(EmptyAsNull(&nickname))
+///     .bind(id)
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EmptyAsNull<'a>(pub &'a str);
+
+impl<DB> Type<DB> for EmptyAsNull<'_>
+where
+    DB: Database,
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+}
+
+impl<DB> Encode<DB> for EmptyAsNull<'_>
+where
+    DB: Database,
+    str: Encode<DB>,
+{
+    fn encode(&self, buf: &mut DB::RawBuffer) {
+        // Forward to [encode_nullable] and ignore the result
+        let _ = self.encode_nullable(buf);
+    }
+
+    fn encode_nullable(&self, buf: &mut DB::RawBuffer) -> IsNull {
+        if self.0.is_empty() {
+            IsNull::Yes
+        } else {
+            self.0.encode_nullable(buf)
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
@@ -34,7 +34,10 @@ pub enum Error {
     /// An error was returned by the database.
     Database(Box<dyn DatabaseError>),
 
-    /// No row was returned during [`query::Map::fetch_one`] or `QueryAs::fetch_one`.
+    /// No row was returned during [`query::Map::fetch_one`], `QueryAs::fetch_one` or
+    /// `QueryScalar::fetch_one`.
+    ///
+    /// Use `fetch_optional` instead to get `None` when a query may return no rows.
     ///
     /// [`query::Map::fetch_one`]: crate::query::Map::fetch_one
     RowNotFound,
@@ -73,6 +76,9 @@ pub enum Error {
 
     /// An error occurred decoding data received from the database.
     Decode(Box<dyn StdError + Send + Sync>),
+
+    /// An error occurred while running migrations; e.g., a `MigrateError`.
+    Migrate(Box<dyn StdError + Send + Sync>),
 }
 
 impl Error {
@@ -109,6 +115,7 @@ impl StdError for Error {
             Error::Decode(error) => Some(&**error),
             Error::Tls(error) => Some(&**error),
             Error::Database(error) => Some(error.as_ref_err()),
+            Error::Migrate(error) => Some(&**error),
 
             _ => None,
         }
@@ -153,6 +160,8 @@ impl Display for Error {
             Error::PoolClosed => f.write_str("attempted to acquire a connection on a closed pool"),
 
             Error::Tls(ref err) => write!(f, "error during TLS upgrade: {}", err),
+
+            Error::Migrate(ref err) => write!(f, "error while running migrations: {}", err),
         }
     }
 }
@@ -385,3 +394,40 @@ impl Display for UnexpectedNullError {
 }
 
 impl StdError for UnexpectedNullError {}
+
+/// A string that is not the label of any variant of an enumeration.
+///
+/// Returned from decoding an enum deriving `Decode` (or `Type`) from an unexpected value, and
+/// from the `FromStr` implementation that is derived with `#[sqlx(from_str)]`.
+#[derive(Debug, Clone)]
+pub struct UnexpectedLabelError {
+    type_name: &'static str,
+    label: String,
+}
+
+impl UnexpectedLabelError {
+    #[doc(hidden)]
+    pub fn new(type_name: &'static str, label: &str) -> Self {
+        Self {
+            type_name,
+            label: label.to_owned(),
+        }
+    }
+
+    /// The string that was not recognized.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl Display for UnexpectedLabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid value {:?} for enum {}",
+            self.label, self.type_name
+        )
+    }
+}
+
+impl StdError for UnexpectedLabelError {}
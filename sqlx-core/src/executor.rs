@@ -44,11 +44,21 @@ where
         E: Execute<'q, Self::Database>;
 
     /// Prepare the SQL query and return type information about its parameters
-    /// and results.
+    /// and results, without executing it.
     ///
     /// This is used by the query macros during compilation to
-    /// power their type inference.
-    #[doc(hidden)]
+    /// power their type inference, and may be useful for generic tooling at runtime.
+    ///
+    /// ```rust,ignore
+    /// let describe = conn.describe("SELECT id, name FROM users WHERE id = $1").await?;
+    ///
+    /// // the type that Postgres inferred for $1
+    /// let id_type = describe.param_types[0].as_ref();
+    ///
+    /// for column in &*describe.result_columns {
+    ///     println!("{:?}: {:?}", column.name, column.type_info);
+    /// }
+    /// ```
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -79,6 +89,13 @@ where
     /// prepare the query. Returning `Some(Default::default())` is an empty arguments object that
     /// will be prepared (and cached) before execution.
     fn into_parts(self) -> (&'q str, Option<DB::Arguments>);
+
+    // The number of rows to fetch from the database at a time; 0 fetches all rows at once
+    // This is set by `prefetch` on a query, which only Postgres supports
+    #[doc(hidden)]
+    fn prefetch(&self) -> usize {
+        0
+    }
 }
 
 impl<'q, DB> Execute<'q, DB> for &'q str
@@ -114,7 +131,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
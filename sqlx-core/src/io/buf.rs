@@ -38,6 +38,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_uint<T: ByteOrder>(&mut self, n: usize) -> io::Result<u64> {
+        check_len(self, n)?;
+
         let val = T::read_uint(*self, n);
         self.advance(n);
 
@@ -45,6 +47,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_i8(&mut self) -> io::Result<i8> {
+        check_len(self, 1)?;
+
         let val = self[0];
         self.advance(1);
 
@@ -52,6 +56,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_u8(&mut self) -> io::Result<u8> {
+        check_len(self, 1)?;
+
         let val = self[0];
         self.advance(1);
 
@@ -59,6 +65,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_u16<T: ByteOrder>(&mut self) -> io::Result<u16> {
+        check_len(self, 2)?;
+
         let val = T::read_u16(*self);
         self.advance(2);
 
@@ -66,6 +74,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_i16<T: ByteOrder>(&mut self) -> io::Result<i16> {
+        check_len(self, 2)?;
+
         let val = T::read_i16(*self);
         self.advance(2);
 
@@ -73,6 +83,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_u24<T: ByteOrder>(&mut self) -> io::Result<u32> {
+        check_len(self, 3)?;
+
         let val = T::read_u24(*self);
         self.advance(3);
 
@@ -80,6 +92,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_i32<T: ByteOrder>(&mut self) -> io::Result<i32> {
+        check_len(self, 4)?;
+
         let val = T::read_i32(*self);
         self.advance(4);
 
@@ -87,13 +101,17 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_i64<T: ByteOrder>(&mut self) -> io::Result<i64> {
+        check_len(self, 8)?;
+
         let val = T::read_i64(*self);
-        self.advance(4);
+        self.advance(8);
 
         Ok(val)
     }
 
     fn get_u32<T: ByteOrder>(&mut self) -> io::Result<u32> {
+        check_len(self, 4)?;
+
         let val = T::read_u32(*self);
         self.advance(4);
 
@@ -101,6 +119,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_u64<T: ByteOrder>(&mut self) -> io::Result<u64> {
+        check_len(self, 8)?;
+
         let val = T::read_u64(*self);
         self.advance(8);
 
@@ -120,6 +140,8 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 
     fn get_bytes(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        check_len(self, len)?;
+
         let buf = &self[..len];
         self.advance(len);
 
@@ -127,6 +149,19 @@ impl<'a> Buf<'a> for &'a [u8] {
     }
 }
 
+// Reading past the end of the buffer is an error rather than a panic; e.g., for a value of a
+// truncated or malformed message
+fn check_len(buf: &[u8], len: usize) -> io::Result<()> {
+    if buf.len() < len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("expected {} more bytes, got {}", len, buf.len()),
+        ));
+    }
+
+    Ok(())
+}
+
 pub trait ToBuf {
     fn to_buf(&self) -> &[u8];
 }
@@ -142,3 +177,20 @@ impl ToBuf for u8 {
         slice::from_ref(self)
     }
 }
+
+#[test]
+fn test_get_past_end() {
+    let mut buf: &[u8] = &[1, 2, 3];
+
+    assert_eq!(buf.get_u16::<byteorder::BigEndian>().unwrap(), 0x0102);
+    assert_eq!(
+        buf.get_u16::<byteorder::BigEndian>().unwrap_err().kind(),
+        io::ErrorKind::UnexpectedEof
+    );
+
+    // nothing is read from a buffer that is too short
+    assert_eq!(buf, [3]);
+    assert!(buf.get_bytes(2).is_err());
+    assert_eq!(buf.get_u8().unwrap(), 3);
+    assert!(buf.get_u8().is_err());
+}
@@ -51,6 +51,13 @@ where
         &self.rbuf[self.rbuf_rindex..]
     }
 
+    // The number of bytes that were read from the stream but not consumed yet
+    #[cfg(feature = "postgres")]
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.rbuf_windex - self.rbuf_rindex
+    }
+
     #[inline]
     pub fn buffer_mut(&mut self) -> &mut Vec<u8> {
         &mut self.wbuf
@@ -41,7 +41,6 @@ mod url;
 pub mod arguments;
 pub mod decode;
 
-#[doc(hidden)]
 pub mod describe;
 
 pub mod encode;
@@ -51,6 +50,9 @@ pub mod query;
 #[macro_use]
 pub mod query_as;
 
+#[macro_use]
+pub mod query_scalar;
+
 pub mod types;
 
 #[macro_use]
@@ -68,4 +70,9 @@ pub mod postgres;
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub mod sqlite;
 
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub mod migrate;
+
+pub use either::Either;
 pub use error::{Error, Result};
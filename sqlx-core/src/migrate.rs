@@ -0,0 +1,185 @@
+//! Run migrations embedded in the application against a Postgres database.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+
+use sha2::{Digest, Sha256};
+
+use crate::connection::Connection;
+use crate::executor::Executor;
+use crate::postgres::{PgQueryAs, Postgres};
+use crate::query_as::query_as;
+
+/// A single migration; a script of SQL statements identified by a version.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// The version of this migration. Migrations are applied in ascending order of version.
+    pub version: i64,
+
+    pub description: Cow<'static, str>,
+
+    pub sql: Cow<'static, str>,
+
+    /// The SHA-256 checksum of the SQL of this migration.
+    pub checksum: Cow<'static, [u8]>,
+}
+
+impl Migration {
+    pub fn new(
+        version: i64,
+        description: impl Into<Cow<'static, str>>,
+        sql: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let sql = sql.into();
+        let checksum = Sha256::digest(sql.as_bytes()).to_vec();
+
+        Self {
+            version,
+            description: description.into(),
+            sql,
+            checksum: Cow::Owned(checksum),
+        }
+    }
+}
+
+/// Applies a set of migrations to a database, keeping track of the applied migrations in the
+/// `_sqlx_migrations` table.
+///
+/// Use the [`migrate!`] macro to embed the migrations of a directory in the application.
+///
+/// ```rust,ignore
+/// let conn = sqlx::migrate!("migrations").run(conn).await?;
+/// ```
+///
+/// [`migrate!`]: ../macro.migrate.html
+#[derive(Debug, Clone)]
+pub struct Migrator {
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// Creates a `Migrator` for the given migrations, which may be in any order.
+    pub fn new(mut migrations: Vec<Migration>) -> Self {
+        migrations.sort_by_key(|migration| migration.version);
+
+        Self { migrations }
+    }
+
+    /// Returns the migrations of this `Migrator`, ordered by version.
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Applies all pending migrations. Returns the connection once done.
+    ///
+    /// Each migration is applied in its own transaction with its version recorded; a migration
+    /// that was already applied is skipped. If the SQL of an applied migration has since
+    /// changed, an `Error::Migrate` of [`MigrateError::VersionMismatch`] is returned before any
+    /// migration is applied.
+    pub async fn run<C>(&self, mut conn: C) -> crate::Result<C>
+    where
+        C: Connection<Database = Postgres>,
+    {
+        // The applied migrations are read through a transaction; any connection can execute a
+        // query through its transaction
+        let mut tx = conn.begin().await?;
+
+        tx.execute(
+            r#"
+CREATE TABLE IF NOT EXISTS _sqlx_migrations (
+    version BIGINT PRIMARY KEY,
+    description TEXT NOT NULL,
+    installed_on TIMESTAMPTZ NOT NULL DEFAULT now(),
+    checksum BYTEA NOT NULL
+)
+            "#,
+        )
+        .await?;
+
+        let applied: HashMap<i64, Vec<u8>> =
+            query_as::<Postgres, (i64, Vec<u8>)>("SELECT version, checksum FROM _sqlx_migrations")
+                .fetch_all(&mut tx)
+                .await?
+                .into_iter()
+                .collect();
+
+        conn = tx.commit().await?;
+
+        for migration in &self.migrations {
+            match applied.get(&migration.version) {
+                Some(checksum) if **checksum != *migration.checksum => {
+                    return Err(MigrateError::VersionMismatch(migration.version).into());
+                }
+
+                _ => {}
+            }
+        }
+
+        for migration in &self.migrations {
+            if applied.contains_key(&migration.version) {
+                continue;
+            }
+
+            let mut tx = conn.begin().await?;
+
+            tx.execute(&*migration.sql).await?;
+
+            crate::query::query(
+                "INSERT INTO _sqlx_migrations (version, description, checksum) \
+                 VALUES ($1, $2, $3)",
+            )
+            .bind(migration.version)
+            .bind(&*migration.description)
+            .bind(&*migration.checksum)
+            .execute(&mut tx)
+            .await?;
+
+            conn = tx.commit().await?;
+        }
+
+        Ok(conn)
+    }
+}
+
+/// An error that occurred while running migrations.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MigrateError {
+    /// The migration of the given version was previously applied but its SQL has since changed.
+    VersionMismatch(i64),
+}
+
+impl Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrateError::VersionMismatch(version) => write!(
+                f,
+                "migration {} was previously applied but has been modified",
+                version
+            ),
+        }
+    }
+}
+
+impl StdError for MigrateError {}
+
+impl From<MigrateError> for crate::Error {
+    fn from(err: MigrateError) -> Self {
+        crate::Error::Migrate(Box::new(err))
+    }
+}
+
+#[test]
+fn test_migrator_sorts_migrations() {
+    let migrator = Migrator::new(vec![
+        Migration::new(2, "second", "SELECT 2"),
+        Migration::new(1, "first", "SELECT 1"),
+    ]);
+
+    let versions: Vec<_> = migrator.migrations().iter().map(|m| m.version).collect();
+
+    assert_eq!(versions, [1, 2]);
+    assert_eq!(migrator.migrations()[0].checksum.len(), 32);
+}
@@ -14,6 +14,7 @@ pub struct MySqlCursor<'c, 'q> {
     source: ConnectionSource<'c, MySqlConnection>,
     query: Option<(&'q str, Option<MySqlArguments>)>,
     column_names: Arc<HashMap<Box<str>, u16>>,
+    column_names_by_index: Arc<[Option<Box<str>>]>,
     column_types: Vec<MySqlTypeInfo>,
     binary: bool,
 }
@@ -32,6 +33,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
         Self {
             source: ConnectionSource::Pool(pool.clone()),
             column_names: Arc::default(),
+            column_names_by_index: Arc::new([]),
             column_types: Vec::new(),
             binary: true,
             query: Some(query.into_parts()),
@@ -47,6 +49,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
         Self {
             source: ConnectionSource::ConnectionRef(conn),
             column_names: Arc::default(),
+            column_names_by_index: Arc::new([]),
             column_types: Vec::new(),
             binary: true,
             query: Some(query.into_parts()),
@@ -119,6 +122,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 cursor.column_types.reserve(cc.columns as usize);
 
                 let mut column_names = HashMap::with_capacity(cc.columns as usize);
+                let mut column_names_by_index = Vec::with_capacity(cc.columns as usize);
 
                 for i in 0..cc.columns {
                     let column = ColumnDefinition::read(conn.stream.receive().await?)?;
@@ -130,6 +134,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     if let Some(name) = column.name() {
                         column_names.insert(name.to_owned().into_boxed_str(), i as u16);
                     }
+
+                    column_names_by_index.push(column.name().map(Into::into));
                 }
 
                 if cc.columns > 0 {
@@ -137,6 +143,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 }
 
                 cursor.column_names = Arc::new(column_names);
+                cursor.column_names_by_index = column_names_by_index.into();
                 initial = false;
             }
 
@@ -151,6 +158,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 let row = MySqlRow {
                     row,
                     names: Arc::clone(&cursor.column_names),
+                    names_by_index: Arc::clone(&cursor.column_names_by_index),
                 };
 
                 return Ok(Some(row));
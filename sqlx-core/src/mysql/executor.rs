@@ -197,7 +197,6 @@ impl Executor for super::MySqlConnection {
         MySqlCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
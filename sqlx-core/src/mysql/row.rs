@@ -3,11 +3,14 @@ use std::sync::Arc;
 
 use crate::mysql::protocol;
 use crate::mysql::{MySql, MySqlValue};
-use crate::row::{ColumnIndex, Row};
+use crate::row::{CaseInsensitive, ColumnIndex, Row};
 
 pub struct MySqlRow<'c> {
     pub(super) row: protocol::Row<'c>,
     pub(super) names: Arc<HashMap<Box<str>, u16>>,
+
+    // the name of each column, by its position
+    pub(super) names_by_index: Arc<[Option<Box<str>>]>,
 }
 
 impl crate::row::private_row::Sealed for MySqlRow<'_> {}
@@ -19,6 +22,10 @@ impl<'c> Row<'c> for MySqlRow<'c> {
         self.row.len()
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.names_by_index.get(index)?.as_deref()
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<MySqlValue<'c>>
     where
@@ -57,3 +64,9 @@ impl<'c> ColumnIndex<'c, MySqlRow<'c>> for str {
             .map(|&index| index as usize)
     }
 }
+
+impl<'c> ColumnIndex<'c, MySqlRow<'c>> for CaseInsensitive<'_> {
+    fn index(&self, row: &MySqlRow<'c>) -> crate::Result<usize> {
+        self.find(&*row.names).map(|index| index as usize)
+    }
+}
@@ -116,5 +116,5 @@ async fn try_upgrade(
         )
         .await?;
 
-    stream.stream.upgrade(url, connector).await
+    stream.stream.upgrade(url.host(), connector).await
 }
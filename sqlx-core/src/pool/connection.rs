@@ -6,6 +6,7 @@ use std::time::Instant;
 
 use super::inner::{DecrementSizeGuard, SharedPool};
 use crate::connection::{Connect, Connection};
+use crate::runtime::spawn;
 
 /// A connection checked out from [`Pool`][crate::pool::Pool].
 ///
@@ -89,20 +90,60 @@ where
     fn ping(&mut self) -> BoxFuture<crate::Result<()>> {
         Box::pin(self.deref_mut().ping())
     }
+
+    #[inline]
+    fn is_broken(&self) -> bool {
+        self.deref().is_broken()
+    }
 }
 
-/// Returns the connection to the [`Pool`][crate::pool::Pool] it was checked-out from.
+/// Returns the connection to the [`Pool`][crate::pool::Pool] it was checked-out from, unless
+/// the connection is broken; see [`Builder::after_release`][crate::pool::Builder::after_release].
 impl<C> Drop for PoolConnection<C>
 where
     C: Connect,
 {
     fn drop(&mut self) {
         if let Some(live) = self.live.take() {
-            self.pool.release(live.float(&self.pool));
+            if self.pool.options().after_release.is_some() && !live.raw.is_broken() {
+                spawn_release(Arc::clone(&self.pool), live);
+                return;
+            }
+
+            let floating = live.float(&self.pool);
+
+            if floating.raw.is_broken() {
+                // dropping the connection decrements the size of the pool so that a new
+                // connection may be opened in its place
+                return;
+            }
+
+            self.pool.release(floating);
         }
     }
 }
 
+// The connection is released once the `after_release` statement has completed
+fn spawn_release<C>(pool: Arc<SharedPool<C>>, live: Live<C>)
+where
+    C: Connect,
+{
+    spawn(async move {
+        let mut floating = live.float(&pool);
+        let statement = pool.options().after_release.as_deref().unwrap_or_default();
+
+        if let Err(e) = floating.raw.execute(statement).await {
+            log::info!("after_release on connection returned error: {}", e);
+            // dropping the connection decrements the size of the pool
+            return;
+        }
+
+        if !floating.raw.is_broken() {
+            pool.release(floating);
+        }
+    });
+}
+
 impl<C> Live<C> {
     pub fn float(self, pool: &SharedPool<C>) -> Floating<Self> {
         Floating {
@@ -34,7 +34,6 @@ where
         DB::Cursor::from_pool(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -86,7 +85,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -1,7 +1,7 @@
 use std::cmp;
 use std::mem;
 use std::ptr;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -24,6 +24,8 @@ pub(crate) struct SharedPool<C> {
     idle_conns: ArrayQueue<Idle<C>>,
     waiters: SegQueue<Waker>,
     pub(super) size: AtomicU32,
+    // The total number of connections that have been opened by the pool
+    num_opened: AtomicU64,
     is_closed: AtomicBool,
     options: Options,
 }
@@ -49,6 +51,10 @@ where
         self.idle_conns.len()
     }
 
+    pub(super) fn num_opened(&self) -> u64 {
+        self.num_opened.load(Ordering::Acquire)
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
@@ -138,6 +144,7 @@ where
             idle_conns: ArrayQueue::new(options.max_size as usize),
             waiters: SegQueue::new(),
             size: AtomicU32::new(0),
+            num_opened: AtomicU64::new(0),
             is_closed: AtomicBool::new(false),
             options,
         };
@@ -218,7 +225,11 @@ where
         // result here is `Result<Result<C, Error>, TimeoutError>`
         match crate::runtime::timeout(timeout, C::connect(&self.url)).await {
             // successfully established connection
-            Ok(Ok(raw)) => Ok(Some(Floating::new_live(raw, guard))),
+            Ok(Ok(raw)) => {
+                self.num_opened.fetch_add(1, Ordering::AcqRel);
+
+                Ok(Some(Floating::new_live(raw, guard)))
+            }
 
             // an IO error while connecting is assumed to be the system starting up
             Ok(Err(crate::Error::Io(_))) => Ok(None),
@@ -270,7 +281,6 @@ where
         let _ = conn.close().await;
         return None;
     } else if options.test_on_acquire {
-        // TODO: Check on acquire should be a configuration setting
         // Check that the connection is still live
         if let Err(e) = conn.ping().await {
             // an error here means the other end has hung up or we lost connectivity
@@ -22,6 +22,15 @@ pub use self::connection::PoolConnection;
 pub use self::options::Builder;
 
 /// A pool of database connections.
+///
+/// A reference to the pool is an executor; each query that is executed on `&pool` acquires a
+/// connection from the pool and returns it once the query completes.
+///
+/// ```rust,ignore
+/// let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+///     .fetch_one(&pool)
+///     .await?;
+/// ```
 pub struct Pool<C>(pub(crate) Arc<SharedPool<C>>);
 
 impl<C> Pool<C>
@@ -92,6 +101,22 @@ where
         self.0.num_idle()
     }
 
+    /// Returns a snapshot of the connection counts of the pool.
+    ///
+    /// The counts are read separately while the pool may be in use so they are not guaranteed
+    /// to be consistent with each other.
+    pub fn stats(&self) -> PoolStats {
+        let size = self.0.size();
+        let idle = self.0.num_idle();
+
+        PoolStats {
+            size,
+            idle,
+            in_use: (size as usize).saturating_sub(idle) as u32,
+            opened: self.0.num_opened(),
+        }
+    }
+
     /// Returns the configured maximum pool size.
     pub fn max_size(&self) -> u32 {
         self.0.options().max_size
@@ -118,6 +143,23 @@ where
     }
 }
 
+/// Connection counts of a [Pool]; returned by [`Pool::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PoolStats {
+    /// The number of connections currently being managed by the pool.
+    pub size: u32,
+
+    /// The number of connections waiting in the pool to be acquired.
+    pub idle: usize,
+
+    /// The number of connections that have been acquired (or are being opened).
+    pub in_use: u32,
+
+    /// The total number of connections that have been opened by the pool.
+    pub opened: u64,
+}
+
 /// Returns a new [Pool] tied to the same shared connection pool.
 impl<C> Clone for Pool<C> {
     fn clone(&self) -> Self {
@@ -35,6 +35,8 @@ where
                 idle_timeout: None,
                 // If true, test the health of a connection on acquire
                 test_on_acquire: true,
+                // don't reset the state of a connection when it is released
+                after_release: None,
             },
         }
     }
@@ -107,6 +109,25 @@ where
         self
     }
 
+    /// Set a statement to execute on each connection that is released to the pool, before the
+    /// connection may be acquired again; e.g., to reset the state of the session.
+    ///
+    /// ```rust,ignore
+    /// // a role that was set with `SET ROLE` while the connection was checked out
+    /// // does not leak into the next checkout
+    /// let pool = PgPool::builder()
+    ///     .after_release("RESET ROLE")
+    ///     .build(&url)
+    ///     .await?;
+    /// ```
+    ///
+    /// A connection for which the statement returns an error is closed instead of being
+    /// returned to the pool.
+    pub fn after_release(mut self, statement: impl Into<String>) -> Self {
+        self.options.after_release = Some(statement.into());
+        self
+    }
+
     /// Spin up the connection pool.
     ///
     /// If [`min_size`] was set to a non-zero value, that many connections will be immediately
@@ -139,4 +160,5 @@ pub(crate) struct Options {
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub test_on_acquire: bool,
+    pub after_release: Option<String>,
 }
@@ -27,21 +27,34 @@ impl Arguments for PgArguments {
     where
         T: Type<Self::Database> + Encode<Self::Database>,
     {
-        // TODO: When/if we receive types that do _not_ support BINARY, we need to check here
-        // TODO: There is no need to be explicit unless we are expecting mixed BINARY / TEXT
-
+        // The type is recorded even if the value is NULL; a `None::<T>` is sent as a NULL
+        // of the type of `T` so Postgres does not need to infer one
         self.types.push(<T as Type<Postgres>>::type_info());
 
-        // Reserves space for the length of the value
+        // Reserves space for the length of the value and the value itself
+        self.buffer.reserve(4 + value.size_hint());
+
         let pos = self.buffer.len();
         self.buffer.put_i32::<NetworkEndian>(0);
 
+        let start = self.buffer.encoded_len();
+        let mark = self.buffer.mark();
+
         let len = if let IsNull::No = value.encode_nullable(&mut self.buffer) {
-            (self.buffer.len() - pos - 4) as i32
+            (self.buffer.encoded_len() - start) as i32
         } else {
+            // It is illegal for [encode_nullable] to write any data if it returns IsNull::Yes
+            debug_assert_eq!(
+                self.buffer.mark(),
+                mark,
+                "encode_nullable wrote data for a NULL value"
+            );
+
+            // Discard anything that was written, including any shared or streamed values, so
+            // the frame stays consistent
+            self.buffer.truncate_to(mark);
+
             // Write a -1 for the len to indicate NULL
-            // TODO: It is illegal for [encode] to write any data
-            //       if IsSql::No; fail a debug assertion
             -1
         };
 
@@ -49,3 +62,66 @@ impl Arguments for PgArguments {
         NetworkEndian::write_i32(&mut self.buffer[pos..], len as i32);
     }
 }
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_add_bytes_is_not_copied() {
+    let blob = vec![0xAB_u8; 4 * 1024 * 1024];
+
+    let mut shared = PgArguments::default();
+    shared.add(1_i32);
+    shared.add(bytes::Bytes::from(blob.clone()));
+    shared.add(2_i32);
+
+    // only the lengths and the integers were copied into the arguments
+    assert_eq!(shared.buffer.len(), 3 * 4 + 2 * 4);
+    assert_eq!(shared.buffer.encoded_len(), 3 * 4 + 2 * 4 + blob.len());
+
+    let mut copied = PgArguments::default();
+    copied.add(1_i32);
+    copied.add(blob);
+    copied.add(2_i32);
+
+    // the data of the `Vec` was copied into the arguments
+    assert_eq!(copied.buffer.len(), shared.buffer.encoded_len());
+
+    let mut shared_buf = Vec::new();
+    shared.buffer.write_to(&mut shared_buf);
+
+    let mut copied_buf = Vec::new();
+    copied.buffer.write_to(&mut copied_buf);
+
+    assert_eq!(shared_buf, copied_buf);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_add_shared_and_inline_values() {
+    let mut shared = PgArguments::default();
+    shared.add(1_i32);
+    shared.add(bytes::Bytes::from_static(b"first"));
+    shared.add(None::<bytes::Bytes>);
+    shared.add("inline");
+    shared.add(bytes::Bytes::new());
+    shared.add(bytes::Bytes::from_static(b"second"));
+    shared.add(2_i32);
+
+    let mut copied = PgArguments::default();
+    copied.add(1_i32);
+    copied.add(&b"first"[..]);
+    copied.add(None::<Vec<u8>>);
+    copied.add("inline");
+    copied.add(&b""[..]);
+    copied.add(&b"second"[..]);
+    copied.add(2_i32);
+
+    assert_eq!(shared.buffer.encoded_len(), copied.buffer.encoded_len());
+
+    let mut shared_buf = Vec::new();
+    shared.buffer.write_to(&mut shared_buf);
+
+    let mut copied_buf = Vec::new();
+    copied.buffer.write_to(&mut copied_buf);
+
+    assert_eq!(shared_buf, copied_buf);
+}
@@ -1,7 +1,15 @@
+use crate::postgres::stream::PgStream;
 use crate::postgres::type_info::SharedStr;
 use crate::postgres::PgConnection;
+use crate::runtime::{AsyncRead, AsyncReadExt};
 use byteorder::{ByteOrder, NetworkEndian};
 use core::ops::{Deref, DerefMut};
+use std::fmt::{self, Debug};
+use std::io;
+use std::sync::Mutex;
+
+// The most that is read from the reader of a streamed value before it is sent
+const STREAMED_CHUNK_SIZE: usize = 64 * 1024;
 
 #[derive(Debug, Default, PartialEq)]
 pub struct PgRawBuffer {
@@ -13,6 +21,45 @@ pub struct PgRawBuffer {
     // This is done for Records and Arrays as the OID is needed well before we are in an async
     // function and can just ask postgres
     type_holes: Vec<(usize, SharedStr)>,
+
+    // Values that are written to the connection as-is instead of being copied into the buffer
+    // Each is a `usize` offset into the buffer that the value is to be inserted at
+    #[cfg(feature = "bytes")]
+    shared: Vec<(usize, bytes::Bytes)>,
+
+    // Values that are read from a reader as they are written to the connection
+    // Each is a `usize` offset into the buffer that the value is to be inserted at
+    streamed: Vec<(usize, StreamedValue)>,
+}
+
+// The length of each part of a buffer; a buffer is truncated back to a mark with [truncate_to]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PgRawBufferMark {
+    inner: usize,
+    type_holes: usize,
+    shared: usize,
+    streamed: usize,
+}
+
+// The reader is taken when the value is written out
+pub(crate) struct StreamedValue {
+    len: usize,
+    reader: Mutex<Option<Box<dyn AsyncRead + Send + Unpin>>>,
+}
+
+impl Debug for StreamedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamedValue")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+// A reader can not be compared; a streamed value is only equal to itself
+impl PartialEq for StreamedValue {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
 }
 
 impl PgRawBuffer {
@@ -38,6 +85,221 @@ impl PgRawBuffer {
 
         Ok(())
     }
+
+    // Pushes a value that is inserted at the current end of the buffer when it is written out
+    // The value is only referenced so large values are not copied an extra time
+    #[cfg(feature = "bytes")]
+    pub(crate) fn push_shared(&mut self, bytes: bytes::Bytes) {
+        self.shared.push((self.len(), bytes));
+    }
+
+    // Pushes a value of [len] bytes that is read from [reader] as it is written out
+    // A value without a reader fails when it is written out
+    pub(crate) fn push_streamed(
+        &mut self,
+        len: usize,
+        reader: Option<Box<dyn AsyncRead + Send + Unpin>>,
+    ) {
+        self.streamed.push((
+            self.len(),
+            StreamedValue {
+                len,
+                reader: Mutex::new(reader),
+            },
+        ));
+    }
+
+    // The current length of each part of the buffer
+    pub(crate) fn mark(&self) -> PgRawBufferMark {
+        PgRawBufferMark {
+            inner: self.inner.len(),
+            type_holes: self.type_holes.len(),
+            #[cfg(feature = "bytes")]
+            shared: self.shared.len(),
+            #[cfg(not(feature = "bytes"))]
+            shared: 0,
+            streamed: self.streamed.len(),
+        }
+    }
+
+    // Discard everything that was pushed since [mark]; unlike truncating the inner buffer,
+    // this also discards the shared and streamed values and the type holes
+    pub(crate) fn truncate_to(&mut self, mark: PgRawBufferMark) {
+        self.inner.truncate(mark.inner);
+        self.type_holes.truncate(mark.type_holes);
+        #[cfg(feature = "bytes")]
+        self.shared.truncate(mark.shared);
+        self.streamed.truncate(mark.streamed);
+    }
+
+    // A buffer with streamed values can only be written out with [write_streamed]
+    pub(crate) fn has_streamed(&self) -> bool {
+        !self.streamed.is_empty()
+    }
+
+    // The length of the buffer when it is written out; this is what lengths written by
+    // `Encode` implementations must be computed from
+    pub(crate) fn encoded_len(&self) -> usize {
+        let streamed: usize = self.streamed.iter().map(|(_, value)| value.len).sum();
+
+        #[cfg(feature = "bytes")]
+        {
+            let shared: usize = self.shared.iter().map(|(_, bytes)| bytes.len()).sum();
+
+            self.inner.len() + shared + streamed
+        }
+
+        #[cfg(not(feature = "bytes"))]
+        {
+            self.inner.len() + streamed
+        }
+    }
+
+    // Write the buffer with any shared values inserted to the write buffer of the connection,
+    // reading each streamed value from its reader as it is reached
+    //
+    // Everything that was written is flushed to the connection after each chunk of a streamed
+    // value so a streamed value is never held in memory as a whole. A reader that fails or ends
+    // early must not break the framing of the message. The rest of its value is written as
+    // zeros and the error is returned once the whole buffer has been written.
+    pub(crate) async fn write_streamed(&self, stream: &mut PgStream) -> crate::Result<()> {
+        let mut written = 0;
+        let mut error = None;
+
+        #[cfg(feature = "bytes")]
+        let mut shared = self.shared.iter().peekable();
+
+        for (offset, value) in &self.streamed {
+            // Shared values are written as-is up to the streamed value
+            #[cfg(feature = "bytes")]
+            while let Some((shared_offset, bytes)) = shared.peek() {
+                if shared_offset > offset {
+                    break;
+                }
+
+                let buf = stream.stream.buffer_mut();
+
+                buf.extend_from_slice(&self.inner[written..*shared_offset]);
+                buf.extend_from_slice(bytes);
+
+                written = *shared_offset;
+                shared.next();
+            }
+
+            stream
+                .stream
+                .buffer_mut()
+                .extend_from_slice(&self.inner[written..*offset]);
+
+            written = *offset;
+
+            let reader = value.reader.lock().unwrap().take();
+
+            if let Err(err) = write_streamed_value(stream, value.len, reader).await? {
+                error.get_or_insert(err);
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        for (shared_offset, bytes) in shared {
+            let buf = stream.stream.buffer_mut();
+
+            buf.extend_from_slice(&self.inner[written..*shared_offset]);
+            buf.extend_from_slice(bytes);
+
+            written = *shared_offset;
+        }
+
+        stream
+            .stream
+            .buffer_mut()
+            .extend_from_slice(&self.inner[written..]);
+
+        match error {
+            Some(err) => Err(err.into()),
+            None => Ok(()),
+        }
+    }
+
+    // Write the buffer with any shared values inserted
+    pub(crate) fn write_to(&self, buf: &mut Vec<u8>) {
+        debug_assert!(
+            self.streamed.is_empty(),
+            "a buffer with streamed values must be written with write_streamed"
+        );
+
+        #[cfg(feature = "bytes")]
+        {
+            let mut written = 0;
+
+            for (offset, bytes) in &self.shared {
+                buf.extend_from_slice(&self.inner[written..*offset]);
+                buf.extend_from_slice(bytes);
+
+                written = *offset;
+            }
+
+            buf.extend_from_slice(&self.inner[written..]);
+        }
+
+        #[cfg(not(feature = "bytes"))]
+        {
+            buf.extend_from_slice(&self.inner);
+        }
+    }
+}
+
+// Write exactly [len] bytes from [reader], flushing the connection after each chunk
+// The outer result is an error of the connection and the inner result an error of the reader
+async fn write_streamed_value(
+    stream: &mut PgStream,
+    len: usize,
+    mut reader: Option<Box<dyn AsyncRead + Send + Unpin>>,
+) -> crate::Result<io::Result<()>> {
+    let mut remaining = len;
+    let mut result = Ok(());
+
+    while remaining > 0 {
+        let buf = stream.stream.buffer_mut();
+        let start = buf.len();
+
+        buf.resize(start + remaining.min(STREAMED_CHUNK_SIZE), 0);
+
+        match (&mut reader, result.is_ok()) {
+            (Some(reader), true) => match reader.read(&mut buf[start..]).await {
+                Ok(0) => {
+                    result = Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!(
+                            "streamed value ended after {} of {} bytes",
+                            len - remaining,
+                            len
+                        ),
+                    ));
+                }
+
+                Ok(read) => buf.truncate(start + read),
+
+                Err(err) => result = Err(err),
+            },
+
+            (None, true) => {
+                result = Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the reader of a streamed value was already used",
+                ));
+            }
+
+            // After an error, the rest of the value is left as zeros
+            (_, false) => {}
+        }
+
+        remaining -= buf.len() - start;
+
+        stream.flush().await?;
+    }
+
+    Ok(result)
 }
 
 impl Deref for PgRawBuffer {
@@ -0,0 +1,59 @@
+use crate::io::{BufStream, MaybeTlsStream};
+use crate::postgres::protocol::{CancelRequest, Write};
+
+/// A handle to cancel the query in-flight on a [`PgConnection`], from another task.
+///
+/// A cancel token is created with [`PgConnection::cancel_token`]. It holds only the address of
+/// the server and the key of the connection, so it can be cloned and sent to other tasks freely.
+///
+/// ```rust,ignore
+/// let token = conn.cancel_token();
+///
+/// spawn(async move {
+///     sleep(Duration::from_secs(5)).await;
+///     token.cancel().await
+/// });
+///
+/// // fails with `57014` (query_canceled) if this takes longer than 5 seconds
+/// conn.execute("SELECT pg_sleep(10)").await?;
+/// ```
+///
+/// [`PgConnection`]: struct.PgConnection.html
+/// [`PgConnection::cancel_token`]: struct.PgConnection.html#method.cancel_token
+#[derive(Debug, Clone)]
+pub struct PgCancelToken {
+    pub(super) host: String,
+    pub(super) port: u16,
+    pub(super) process_id: u32,
+    pub(super) secret_key: u32,
+}
+
+impl PgCancelToken {
+    /// Asks the server to cancel the query currently executing on the connection.
+    ///
+    /// This opens a new connection to send the request. The request is only advisory; the
+    /// server does not reply, so success of this method does not mean that a query was
+    /// cancelled. A cancelled query fails with the SQLSTATE `57014` (`query_canceled`) on the
+    /// original connection, which otherwise remains usable.
+    ///
+    /// The request is always sent without TLS, as `libpq` does.
+    pub async fn cancel(&self) -> crate::Result<()> {
+        // https://www.postgresql.org/docs/12/protocol-flow.html#id-1.10.5.7.9
+        let stream = MaybeTlsStream::connect_to(&self.host, self.port).await?;
+        let mut stream = BufStream::new(stream);
+
+        CancelRequest {
+            process_id: self.process_id,
+            secret_key: self.secret_key,
+        }
+        .write(stream.buffer_mut());
+
+        stream.flush().await?;
+
+        // The server closes the connection after processing the request; waiting for that
+        // makes sure the request was received before we return
+        let _ = stream.try_peek(1).await?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
-use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures_core::future::BoxFuture;
-use futures_util::TryFutureExt;
 
 use crate::connection::{Connect, Connection};
 use crate::executor::Executor;
@@ -16,9 +16,12 @@ use crate::postgres::protocol::{
 use crate::postgres::row::Statement;
 use crate::postgres::stream::PgStream;
 use crate::postgres::type_info::SharedStr;
-use crate::postgres::{sasl, tls};
+use crate::postgres::{sasl, tls, PgCancelToken, PgConnectOptions, PgNotice};
 use crate::url::Url;
 
+// Hook invoked with the metadata of each query once it completes
+type QueryHook = Box<dyn Fn(&PgQueryLog<'_>) + Send + Sync>;
+
 /// An asynchronous connection to a [Postgres](struct.Postgres.html) database.
 ///
 /// The connection string expected by [Connect::connect] should be a PostgreSQL connection
@@ -30,7 +33,7 @@ use crate::url::Url;
 /// connection strings: <https://www.postgresql.org/docs/12/libpq-ssl.html>
 ///
 /// ```text
-/// postgresql://<user>[:<password>]@<host>[:<port>]/<database>[?sslmode=<ssl-mode>[&sslcrootcert=<path>]]
+/// postgresql://<user>[:<password>]@<host>[:<port>]/<database>[?sslmode=<ssl-mode>[&sslrootcert=<path>][&sslcert=<path>&sslkey=<path>]]
 /// ```
 /// where
 /// ```text
@@ -38,6 +41,19 @@ use crate::url::Url;
 /// path = percent (URL) encoded path on the local machine
 /// ```
 ///
+/// The same options can be set programmatically with [`PgConnectOptions`] and
+/// [`connect_with`](#method.connect_with).
+///
+/// ### Statement Names
+/// Prepared statements are named by a number which is only unique on each connection. With the
+/// `statement_name_prefix` query parameter (or [`PgConnectOptions::statement_name_prefix`]),
+/// they are instead named by the prefix and a hash of their SQL so the same query has the
+/// same name on every connection; e.g., in `pg_prepared_statements` or the server logs.
+///
+/// With `prepared_statements=false` (or [`PgConnectOptions::prepared_statements`]), no
+/// statements are prepared at all; the arguments of a query are written into it as literals
+/// and it is sent as a simple query, e.g., for PgBouncer in transaction pooling mode.
+///
 /// If the `tls` feature is not enabled, `disable`, `allow` and `prefer` are no-ops and `require`,
 /// `verify-ca` and `verify-full` are forbidden (attempting to connect with these will return
 /// an error).
@@ -79,11 +95,32 @@ use crate::url::Url;
 /// `sslmode=verify-ca`, the hostname in the connection string will be verified
 /// against the hostname in the server certificate, so they must be the same for the TLS
 /// upgrade to succeed.
+///
+/// If the server requests a client certificate, the PEM-encoded certificate and (PKCS #8)
+/// private key given by the `sslcert` and `sslkey` query parameters are sent.
+///
+/// [`PgConnectOptions`]: struct.PgConnectOptions.html
+/// [`PgConnectOptions::prepared_statements`]: struct.PgConnectOptions.html#method.prepared_statements
 pub struct PgConnection {
     pub(super) stream: PgStream,
     pub(super) next_statement_id: u32,
+
+    // Prefix of the names of prepared statements which are named by the hash of their SQL
+    pub(super) statement_name_prefix: Option<Box<str>>,
+
+    // If queries with arguments are prepared; otherwise, the arguments are written into the
+    // query as literals and it is sent as a simple query
+    pub(super) prepared_statements: bool,
     pub(super) is_ready: bool,
 
+    // If the query in-flight fetches its rows in chunks; its portal is kept open without a
+    // [Sync] until all of its rows have been fetched
+    pub(super) is_chunked: bool,
+
+    // If a `COPY .. FROM STDIN` was started; it is aborted if it was not finished before the
+    // next query
+    pub(super) is_copy_in: bool,
+
     // cache query -> statement ID
     pub(super) cache_statement_id: HashMap<Box<str>, StatementId>,
 
@@ -100,24 +137,51 @@ pub struct PgConnection {
     // This is used as the backing memory for each Row's value indexes
     pub(super) current_row_values: Vec<Option<(u32, u32)>>,
 
-    // TODO: Find a use for these values. Perhaps in a debug impl of PgConnection?
-    #[allow(dead_code)]
-    process_id: u32,
+    // Hook invoked with the metadata of each query once it completes
+    pub(super) on_query: Option<QueryHook>,
+
+    // SQL, parameter count, and start time of the query in-flight
+    // This is only tracked when [on_query] is set
+    pub(super) current_query: Option<(Box<str>, usize, Instant)>,
+
+    // Set while the driver runs a query of its own (e.g., to look up the OID of a type)
+    // These queries are not passed to [on_query]
+    pub(super) is_internal_query: bool,
 
-    #[allow(dead_code)]
-    secret_key: u32,
+    // Address and key of the backend process; used to cancel a query in-flight
+    cancel_token: PgCancelToken,
+}
+
+/// Metadata of an executed query, as given to the hook set with [PgConnection::on_query].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PgQueryLog<'a> {
+    /// The SQL of the query.
+    pub sql: &'a str,
+
+    /// The number of parameters bound to the query.
+    pub arguments: usize,
+
+    /// The time elapsed from sending the query until Postgres completed it.
+    ///
+    /// This is measured until the response that completes the query was received; it does
+    /// not include the time the rows of the query were left unread.
+    pub elapsed: Duration,
 }
 
 // https://www.postgresql.org/docs/12/protocol-flow.html#id-1.10.5.7.3
-async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyData> {
-    // Defaults to postgres@.../postgres
-    let username = url.username().unwrap_or(Cow::Borrowed("postgres"));
-    let database = url.database().unwrap_or("postgres");
+async fn startup(
+    stream: &mut PgStream,
+    options: &PgConnectOptions,
+) -> crate::Result<BackendKeyData> {
+    let username = &*options.username;
+    let database = &*options.database;
+    let password = options.password.as_deref().unwrap_or_default();
 
     // See this doc for more runtime parameters
     // https://www.postgresql.org/docs/12/runtime-config-client.html
-    let params = &[
-        ("user", username.as_ref()),
+    let mut params = vec![
+        ("user", username),
         ("database", database),
         // Sets the display format for date and time values,
         // as well as the rules for interpreting ambiguous date input values.
@@ -131,7 +195,11 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyDa
         ("client_encoding", "UTF-8"),
     ];
 
-    stream.write(StartupMessage { params });
+    if let Some(name) = &options.application_name {
+        params.push(("application_name", name.as_str()));
+    }
+
+    stream.write(StartupMessage { params: &params });
     stream.flush().await?;
 
     let mut key_data = BackendKeyData {
@@ -147,9 +215,7 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyDa
                 }
 
                 Authentication::CleartextPassword => {
-                    stream.write(PasswordMessage::ClearText(
-                        &url.password().unwrap_or_default(),
-                    ));
+                    stream.write(PasswordMessage::ClearText(password));
 
                     stream.flush().await?;
                 }
@@ -160,8 +226,8 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyDa
                     let data = AuthenticationMd5::read(&stream.buffer()[4..])?;
 
                     stream.write(PasswordMessage::Md5 {
-                        password: &url.password().unwrap_or_default(),
-                        user: username.as_ref(),
+                        password,
+                        user: username,
                         salt: data.salt,
                     });
 
@@ -194,12 +260,7 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyDa
 
                     if has_sasl || has_sasl_plus {
                         // TODO: Handle -PLUS differently if we're in a TLS stream
-                        sasl::authenticate(
-                            stream,
-                            username.as_ref(),
-                            &url.password().unwrap_or_default(),
-                        )
-                        .await?;
+                        sasl::authenticate(stream, username, password).await?;
                     } else {
                         return Err(protocol_err!(
                             "unsupported SASL auth mechanisms: {:?}",
@@ -221,10 +282,6 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<BackendKeyDa
                 key_data = BackendKeyData::read(stream.buffer())?;
             }
 
-            Message::ParameterStatus => {
-                // do nothing. we do not care about the server values here.
-            }
-
             Message::ReadyForQuery => {
                 // done. connection is now fully established and can accept
                 // queries for execution.
@@ -251,25 +308,154 @@ async fn terminate(mut stream: PgStream) -> crate::Result<()> {
 
 impl PgConnection {
     pub(super) async fn new(url: std::result::Result<Url, url::ParseError>) -> crate::Result<Self> {
-        let url = url?;
-        let mut stream = PgStream::new(&url).await?;
+        let options = PgConnectOptions::from_url(&url?)?;
+
+        Self::connect_with(&options).await
+    }
 
-        tls::request_if_needed(&mut stream, &url).await?;
-        let key_data = startup(&mut stream, &url).await?;
+    /// Establish a new connection to a Postgres database with the given options.
+    ///
+    /// ```rust,ignore
+    /// let options = PgConnectOptions::new()
+    ///     .host("db.example.com")
+    ///     .ssl_mode(PgSslMode::Require);
+    ///
+    /// let conn = PgConnection::connect_with(&options).await?;
+    /// ```
+    pub async fn connect_with(options: &PgConnectOptions) -> crate::Result<Self> {
+        let mut stream = PgStream::new(options).await?;
+
+        tls::request_if_needed(&mut stream, options).await?;
+        let key_data = startup(&mut stream, options).await?;
 
         Ok(Self {
             stream,
             current_row_values: Vec::with_capacity(10),
             next_statement_id: 1,
+            statement_name_prefix: options.statement_name_prefix.as_deref().map(Into::into),
+            prepared_statements: options.prepared_statements,
             is_ready: true,
+            is_chunked: false,
+            is_copy_in: false,
             cache_type_oid: HashMap::new(),
             cache_type_name: HashMap::new(),
             cache_statement_id: HashMap::with_capacity(10),
             cache_statement: HashMap::with_capacity(10),
-            process_id: key_data.process_id,
-            secret_key: key_data.secret_key,
+            on_query: None,
+            current_query: None,
+            is_internal_query: false,
+            cancel_token: PgCancelToken {
+                host: options.host.clone(),
+                port: options.port,
+                process_id: key_data.process_id,
+                secret_key: key_data.secret_key,
+            },
         })
     }
+
+    /// Returns a handle that can cancel the query in-flight on this connection from another
+    /// task; see [`PgCancelToken`].
+    ///
+    /// [`PgCancelToken`]: struct.PgCancelToken.html
+    pub fn cancel_token(&self) -> PgCancelToken {
+        self.cancel_token.clone()
+    }
+
+    /// Sets a hook to be invoked after each query on this connection completes.
+    ///
+    /// The hook receives the SQL of the query, the number of bound parameters, and the
+    /// time it took to execute. This replaces any previously set hook.
+    ///
+    /// ```rust,ignore
+    /// conn.on_query(|log| {
+    ///     log::info!("{} ({:?})", log.sql, log.elapsed);
+    /// });
+    /// ```
+    pub fn on_query<F>(&mut self, hook: F)
+    where
+        F: Fn(&PgQueryLog<'_>) + Send + Sync + 'static,
+    {
+        self.on_query = Some(Box::new(hook));
+    }
+
+    /// Sets a hook to be invoked with each notice sent by Postgres on this connection.
+    ///
+    /// Notices are non-error messages such as `NOTICE` or `WARNING`; for instance,
+    /// `CREATE TABLE IF NOT EXISTS` on an existing table emits a notice that the relation
+    /// already exists. Notices are otherwise ignored. This replaces any previously set hook.
+    ///
+    /// ```rust,ignore
+    /// conn.on_notice(|notice| {
+    ///     log::warn!("{} ({})", notice.message(), notice.code());
+    /// });
+    /// ```
+    pub fn on_notice<F>(&mut self, hook: F)
+    where
+        F: Fn(&PgNotice) + Send + Sync + 'static,
+    {
+        self.stream.on_notice = Some(Box::new(hook));
+    }
+
+    // Mark the queries run through the returned guard as queries of the driver until it is
+    // dropped, even if the future running them is dropped or returns early
+    pub(super) fn internal_query(&mut self) -> InternalQuery<'_> {
+        let was_internal = self.is_internal_query;
+        self.is_internal_query = true;
+
+        InternalQuery {
+            conn: self,
+            was_internal,
+        }
+    }
+
+    // Start tracking a query which was just sent to the server
+    pub(super) fn start_query_log(&mut self, sql: &str, arguments: usize) {
+        if self.on_query.is_some() && !self.is_internal_query {
+            self.current_query = Some((sql.into(), arguments, Instant::now()));
+        }
+    }
+
+    // Invoke the [on_query] hook for the query in-flight, if any
+    // This must be called once we receive the [ReadyForQuery] that completes the query; the
+    // query is timed until that message arrived rather than until it was read
+    pub(super) fn finish_query_log(&mut self) {
+        if let (Some(hook), Some((sql, arguments, started))) =
+            (&self.on_query, self.current_query.take())
+        {
+            hook(&PgQueryLog {
+                sql: &sql,
+                arguments,
+                elapsed: self.stream.received_at.saturating_duration_since(started),
+            });
+        }
+    }
+}
+
+// The previous state is restored on drop so a query of the driver that is run from within
+// another one does not end it early
+pub(super) struct InternalQuery<'c> {
+    conn: &'c mut PgConnection,
+    was_internal: bool,
+}
+
+impl Deref for InternalQuery<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        self.conn
+    }
+}
+
+impl DerefMut for InternalQuery<'_> {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        self.conn
+    }
+}
+
+impl Drop for InternalQuery<'_> {
+    fn drop(&mut self) {
+        self.conn.is_internal_query = self.was_internal;
+    }
 }
 
 impl Connect for PgConnection {
@@ -288,6 +474,14 @@ impl Connection for PgConnection {
     }
 
     fn ping(&mut self) -> BoxFuture<crate::Result<()>> {
-        Box::pin(Executor::execute(self, "SELECT 1").map_ok(|_| ()))
+        Box::pin(async move {
+            let mut conn = self.internal_query();
+
+            Executor::execute(&mut *conn, "SELECT 1").await.map(|_| ())
+        })
+    }
+
+    fn is_broken(&self) -> bool {
+        self.stream.is_broken
     }
 }
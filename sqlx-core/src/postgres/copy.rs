@@ -0,0 +1,143 @@
+use crate::postgres::protocol::{self, CommandComplete, Message};
+use crate::postgres::PgConnection;
+
+// The data is flushed to the connection once this much is buffered
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+impl PgConnection {
+    /// Start a `COPY .. FROM STDIN`; the data is then sent as-is with [`PgCopyIn::send`] in
+    /// the format given in the statement.
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn
+    ///     .copy_in_raw("COPY users (id, name) FROM STDIN (FORMAT text, DELIMITER '|')")
+    ///     .await?;
+    ///
+    /// copy.send(b"1|alice\n").await?;
+    /// copy.send(b"2|bob\n").await?;
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    ///
+    /// The data does not need to be sent in whole rows; e.g., a file can be sent in chunks of
+    /// any size. A `COPY` that is neither finished nor aborted is aborted before the next query
+    /// on the connection.
+    ///
+    /// [`PgCopyIn::send`]: struct.PgCopyIn.html#method.send
+    pub async fn copy_in_raw(&mut self, statement: &str) -> crate::Result<PgCopyIn<'_>> {
+        self.close_copy_in().await?;
+        self.close_chunked().await?;
+        self.wait_until_ready().await?;
+
+        self.write_simple_query(statement);
+
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        match self.stream.receive().await? {
+            Message::CopyInResponse => {
+                self.is_copy_in = true;
+
+                Ok(PgCopyIn { conn: self })
+            }
+
+            message => Err(protocol_err!(
+                "copy_in_raw: expected a `COPY .. FROM STDIN` statement; unexpected message: {:?}",
+                message
+            )
+            .into()),
+        }
+    }
+
+    // Abort a `COPY .. FROM STDIN` that was abandoned before it was finished
+    pub(super) async fn close_copy_in(&mut self) -> crate::Result<()> {
+        if self.is_copy_in {
+            self.abort_copy_in("COPY was not finished").await?;
+        }
+
+        Ok(())
+    }
+
+    async fn abort_copy_in(&mut self, message: &str) -> crate::Result<()> {
+        self.is_copy_in = false;
+
+        self.stream.write(protocol::CopyFail(message));
+        self.stream.flush().await?;
+
+        // postgres responds to [CopyFail] with the error of the `COPY`; that error is expected
+        loop {
+            match self.stream.receive().await {
+                Ok(Message::ReadyForQuery) => {
+                    self.is_ready = true;
+                    break;
+                }
+
+                Ok(_) | Err(crate::Error::Database(_)) => {}
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A `COPY .. FROM STDIN` in progress; returned by [`PgConnection::copy_in_raw`].
+///
+/// [`PgConnection::copy_in_raw`]: struct.PgConnection.html#method.copy_in_raw
+#[must_use = "the COPY must be finished with `finish` or aborted with `abort`"]
+pub struct PgCopyIn<'c> {
+    conn: &'c mut PgConnection,
+}
+
+impl PgCopyIn<'_> {
+    /// Send a chunk of the data; e.g., one or more lines of the text format.
+    pub async fn send(&mut self, data: impl AsRef<[u8]>) -> crate::Result<&mut Self> {
+        self.conn.stream.write(protocol::CopyData(data.as_ref()));
+
+        if self.conn.stream.stream.buffer_mut().len() >= COPY_BUFFER_SIZE {
+            self.conn.stream.flush().await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Complete the `COPY` and return the number of rows that were copied.
+    ///
+    /// An error in the data (e.g., a row with too many columns) is returned here; none of the
+    /// rows are copied in that case.
+    pub async fn finish(self) -> crate::Result<u64> {
+        self.conn.is_copy_in = false;
+
+        self.conn.stream.write(protocol::CopyDone);
+        self.conn.stream.flush().await?;
+
+        let mut rows = 0;
+
+        loop {
+            match self.conn.stream.receive().await? {
+                Message::CommandComplete => {
+                    rows = CommandComplete::read(self.conn.stream.buffer())?.affected_rows;
+                }
+
+                Message::ReadyForQuery => {
+                    self.conn.is_ready = true;
+                    break;
+                }
+
+                message => {
+                    return Err(
+                        protocol_err!("copy_in_raw: unexpected message: {:?}", message).into(),
+                    );
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Abort the `COPY` with an error message; none of the rows are copied.
+    pub async fn abort(self, message: &str) -> crate::Result<()> {
+        self.conn.abort_copy_in(message).await
+    }
+}
@@ -1,20 +1,36 @@
 use std::collections::HashMap;
+use std::mem;
 use std::sync::Arc;
 
+use async_stream::try_stream;
+use either::Either;
 use futures_core::future::BoxFuture;
+use futures_core::stream::Stream;
 
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
-use crate::executor::Execute;
+use crate::executor::{Execute, RefExecutor};
 use crate::pool::Pool;
-use crate::postgres::protocol::{DataRow, Message, ReadyForQuery, RowDescription, StatementId};
+use crate::postgres::protocol::{
+    CommandComplete, DataRow, Message, ReadyForQuery, RowDescription, StatementId,
+};
 use crate::postgres::row::{Column, Statement};
 use crate::postgres::{PgArguments, PgConnection, PgRow, Postgres};
+use crate::query::{Map, Query, TryMapRow};
+use crate::query_as::QueryAs;
+use crate::query_scalar::QueryScalar;
+use crate::row::FromRow;
 
 pub struct PgCursor<'c, 'q> {
     source: ConnectionSource<'c, PgConnection>,
     query: Option<(&'q str, Option<PgArguments>)>,
     statement: Arc<Statement>,
+
+    // The number of rows to fetch from postgres at a time; 0 fetches all rows at once
+    limit: i32,
+
+    // Set while the rows of a result set are being read; see [PgCursor::next_result_set]
+    in_result_set: bool,
 }
 
 impl crate::cursor::private::Sealed for PgCursor<'_, '_> {}
@@ -28,10 +44,15 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
+        let limit = prefetch_limit(query.prefetch());
+        let (query, arguments) = query.into_parts();
+
         Self {
             source: ConnectionSource::Pool(pool.clone()),
             statement: Arc::default(),
-            query: Some(query.into_parts()),
+            limit,
+            query: Some((query, arguments)),
+            in_result_set: false,
         }
     }
 
@@ -41,19 +62,332 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
+        let limit = prefetch_limit(query.prefetch());
+        let (query, arguments) = query.into_parts();
+
         Self {
             source: ConnectionSource::ConnectionRef(conn),
             statement: Arc::default(),
-            query: Some(query.into_parts()),
+            limit,
+            query: Some((query, arguments)),
+            in_result_set: false,
         }
     }
 
     fn next(&mut self) -> BoxFuture<crate::Result<Option<PgRow<'_>>>> {
-        Box::pin(next(self))
+        Box::pin(async move { Ok(next(self, false).await?.and_then(Either::right)) })
+    }
+}
+
+impl<'c, 'q> PgCursor<'c, 'q> {
+    /// Fetch the next result of the query.
+    ///
+    /// Unlike [Cursor::next], the number of rows affected by each command of the query is
+    /// returned as [Either::Left] as the command completes; rows are returned as [Either::Right].
+    /// For a `SELECT`, the count is the number of rows that were returned.
+    pub fn next_many(&mut self) -> BoxFuture<'_, crate::Result<Option<Either<u64, PgRow<'_>>>>> {
+        Box::pin(next(self, true))
+    }
+
+    /// Advance to the next result set of the query.
+    ///
+    /// A query of several statements (which must be executed without arguments) returns a
+    /// result set for each statement, in order. Any rows of the previous result set that were
+    /// not read are skipped. A statement that returns no rows, such as an `INSERT`, has a
+    /// result set without rows. Returns `None` once all result sets have been returned.
+    ///
+    /// ```rust,ignore
+    /// let mut cursor = conn.fetch("SELECT 1; SELECT 'a', 'b'");
+    ///
+    /// while let Some(mut result_set) = cursor.next_result_set().await? {
+    ///     while let Some(row) = result_set.next().await? {
+    ///         // each row of this result set
+    ///     }
+    /// }
+    /// ```
+    pub fn next_result_set(
+        &mut self,
+    ) -> BoxFuture<'_, crate::Result<Option<PgResultSet<'_, 'c, 'q>>>> {
+        Box::pin(async move {
+            Ok(next_result_set(self)
+                .await?
+                .map(move |rows_affected| PgResultSet {
+                    cursor: self,
+                    rows_affected,
+                }))
+        })
+    }
+}
+
+/// A single result set of a query; see [PgCursor::next_result_set].
+pub struct PgResultSet<'r, 'c, 'q> {
+    cursor: &'r mut PgCursor<'c, 'q>,
+    rows_affected: Option<u64>,
+}
+
+impl PgResultSet<'_, '_, '_> {
+    /// Fetch the next row of this result set.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> BoxFuture<'_, crate::Result<Option<PgRow<'_>>>> {
+        Box::pin(async move {
+            if !self.cursor.in_result_set {
+                return Ok(None);
+            }
+
+            match next(&mut *self.cursor, true).await? {
+                Some(Either::Right(row)) => Ok(Some(row)),
+
+                Some(Either::Left(rows_affected)) => {
+                    self.rows_affected = Some(rows_affected);
+
+                    Ok(None)
+                }
+
+                None => Ok(None),
+            }
+        })
+    }
+
+    /// Returns the number of rows affected by the statement of this result set, once all of
+    /// its rows have been read. For a `SELECT`, this is the number of rows that were returned.
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.rows_affected
+    }
+}
+
+// Split the rows of a cursor into chunks of (up to) [size], mapping each row with [map]
+// The rows are fetched from postgres one chunk at a time as the stream is polled
+fn fetch_chunks<'e, O: 'e + Unpin>(
+    mut cursor: PgCursor<'e, 'e>,
+    size: usize,
+    mut map: impl FnMut(PgRow<'_>) -> crate::Result<O> + 'e,
+) -> impl Stream<Item = crate::Result<Vec<O>>> + 'e {
+    // A chunk of no rows would never be yielded
+    let size = size.max(1);
+
+    // Without a prefetch set on the query, each chunk is fetched in one round-trip
+    // A chunk of more than i32::MAX rows is fetched in several round-trips
+    if cursor.limit == 0 {
+        cursor.limit = size.min(i32::MAX as usize) as i32;
+    }
+
+    try_stream! {
+        let mut chunk = Vec::new();
+
+        while let Some(row) = cursor.next().await? {
+            chunk.push(map(row)?);
+
+            if chunk.len() == size {
+                yield mem::replace(&mut chunk, Vec::new());
+            }
+        }
+
+        if !chunk.is_empty() {
+            yield chunk;
+        }
+    }
+}
+
+impl<'q, F> Map<'q, Postgres, F>
+where
+    F: TryMapRow<Postgres>,
+{
+    /// Execute the query and get a [Stream] of both the results, returning our mapped type,
+    /// and the number of rows affected by each command of the query.
+    ///
+    /// This is useful for a query of several statements that mixes commands and `SELECT`s.
+    ///
+    /// Only a simple (unprepared) query may contain several statements, so if no arguments are
+    /// bound, the query is executed as a simple query instead of being prepared. Its statement
+    /// is not cached and its rows are received in the text format. A query with arguments is
+    /// prepared as usual and must be a single statement.
+    ///
+    /// A row borrows the cursor it was read from, so this is only available on a query that
+    /// maps its rows; use [Query::map] to get one. To read the rows themselves, use
+    /// [PgCursor::next_many] on the cursor of [Query::fetch].
+    pub fn fetch_many<'e: 'q, E>(
+        mut self,
+        executor: E,
+    ) -> impl Stream<Item = crate::Result<Either<u64, F::Output>>> + 'e
+    where
+        'q: 'e,
+        E: RefExecutor<'e, Database = Postgres> + 'e,
+        F: 'e,
+        F::Output: 'e,
+    {
+        try_stream! {
+            // Only a simple query may contain more than one statement
+            let mut cursor = if self.query.arguments.types.is_empty() {
+                executor.fetch_by_ref(self.query.query)
+            } else {
+                executor.fetch_by_ref(self.query)
+            };
+
+            while let Some(next) = cursor.next_many().await? {
+                let mapped = match next {
+                    Either::Left(rows) => Either::Left(rows),
+                    Either::Right(row) => Either::Right(self.mapper.try_map_row(row)?),
+                };
+
+                yield mapped;
+            }
+        }
+    }
+}
+
+impl<'q, F> Map<'q, Postgres, F>
+where
+    F: TryMapRow<Postgres>,
+{
+    /// Execute the query and get a [Stream] of the results, returning our mapped type, in
+    /// chunks of `size`. The last chunk may be smaller.
+    ///
+    /// The rows are fetched from Postgres through a portal one chunk at a time, as the stream
+    /// is polled, rather than all at once. This allows a large result set to be processed in
+    /// batches without holding it in memory.
+    ///
+    /// A `size` of zero is treated as one.
+    pub fn fetch_chunks<'e: 'q, E>(
+        self,
+        size: usize,
+        executor: E,
+    ) -> impl Stream<Item = crate::Result<Vec<F::Output>>> + 'e
+    where
+        'q: 'e,
+        E: RefExecutor<'e, Database = Postgres> + 'e,
+        F: 'e,
+        F::Output: 'e,
+    {
+        let mut mapper = self.mapper;
+        let cursor = executor.fetch_by_ref(self.query);
+
+        fetch_chunks(cursor, size, move |row| mapper.try_map_row(row))
+    }
+}
+
+impl<'q, O> QueryAs<'q, Postgres, O>
+where
+    O: Send,
+{
+    /// Execute the query and get a [Stream] of the results in chunks of `size`. The last
+    /// chunk may be smaller.
+    ///
+    /// The rows are fetched from Postgres through a portal one chunk at a time, as the stream
+    /// is polled, rather than all at once. This allows a large result set to be processed in
+    /// batches without holding it in memory.
+    ///
+    /// ```rust,ignore
+    /// let mut chunks = Box::pin(
+    ///     sqlx::query_as::<_, (i64,)>("SELECT id FROM events").fetch_chunks(100, &mut conn),
+    /// );
+    ///
+    /// while let Some(chunk) = chunks.try_next().await? {
+    ///     assert!(chunk.len() <= 100);
+    /// }
+    /// ```
+    ///
+    /// A `size` of zero is treated as one.
+    pub fn fetch_chunks<'e: 'q, E>(
+        self,
+        size: usize,
+        executor: E,
+    ) -> impl Stream<Item = crate::Result<Vec<O>>> + 'e
+    where
+        'q: 'e,
+        E: RefExecutor<'e, Database = Postgres> + 'e,
+        O: 'e + Unpin + for<'c> FromRow<'c, PgRow<'c>>,
+    {
+        let cursor = executor.fetch_by_ref(self);
+
+        fetch_chunks(cursor, size, |row| O::from_row(&row))
     }
 }
 
-fn parse_row_description(conn: &mut PgConnection, rd: RowDescription) -> Statement {
+// The row limit of the portal of a query; a prefetch of more than i32::MAX rows is the same as
+// fetching all rows at once
+fn prefetch_limit(rows: usize) -> i32 {
+    if rows > i32::MAX as usize {
+        0
+    } else {
+        rows as i32
+    }
+}
+
+// A prefetch of zero would be the same as not setting one
+fn clamp_prefetch(rows: usize) -> usize {
+    rows.max(1)
+}
+
+impl<'q> Query<'q, Postgres> {
+    /// Sets the number of rows fetched from Postgres in each round-trip as the rows of this
+    /// query are streamed; e.g., with [Query::fetch].
+    ///
+    /// By default, Postgres sends all rows of a query as soon as it executes, and they are
+    /// buffered until they are read. With a prefetch of `rows`, the query is executed through
+    /// a portal that is suspended after each `rows` rows; the next rows are only requested
+    /// once those were read. This bounds the memory held by a query of many or wide rows, at
+    /// the cost of a round-trip to the server for each `rows` rows, so it is best set to a few
+    /// hundred or thousand rows rather than a handful.
+    ///
+    /// This only applies to a prepared query; a query without arguments executed directly
+    /// with a `&str` is unaffected, as is any query on a connection without prepared
+    /// statements (see `PgConnectOptions::prepared_statements`). The prefetch is kept when
+    /// the arguments are replaced with [Query::bind_all]. For [QueryAs::fetch_chunks], this is
+    /// the number of rows per round-trip while the size of a chunk is the number of rows
+    /// yielded at a time.
+    ///
+    /// ```rust,ignore
+    /// let mut rows = sqlx::query("SELECT payload FROM events")
+    ///     .prefetch(500)
+    ///     .fetch(&mut conn);
+    ///
+    /// while let Some(row) = rows.next().await? {
+    ///     // at most 500 rows are buffered from Postgres at a time
+    /// }
+    /// ```
+    ///
+    /// A prefetch of zero rows is treated as one.
+    pub fn prefetch(mut self, rows: usize) -> Self {
+        self.prefetch = clamp_prefetch(rows);
+        self
+    }
+}
+
+impl<'q, O> QueryAs<'q, Postgres, O> {
+    /// Sets the number of rows fetched from Postgres in each round-trip as the rows of this
+    /// query are streamed. See [Query::prefetch].
+    ///
+    /// A prefetch of zero rows is treated as one.
+    pub fn prefetch(mut self, rows: usize) -> Self {
+        self.prefetch = clamp_prefetch(rows);
+        self
+    }
+}
+
+impl<'q, O> QueryScalar<'q, Postgres, O> {
+    /// Sets the number of rows fetched from Postgres in each round-trip as the rows of this
+    /// query are streamed. See [Query::prefetch].
+    ///
+    /// A prefetch of zero rows is treated as one.
+    pub fn prefetch(self, rows: usize) -> Self {
+        QueryScalar {
+            inner: self.inner.prefetch(rows),
+        }
+    }
+}
+
+impl<'q, F> Map<'q, Postgres, F> {
+    /// Sets the number of rows fetched from Postgres in each round-trip as the rows of this
+    /// query are streamed. See [Query::prefetch].
+    ///
+    /// A prefetch of zero rows is treated as one.
+    pub fn prefetch(mut self, rows: usize) -> Self {
+        self.query = self.query.prefetch(rows);
+        self
+    }
+}
+
+pub(super) fn parse_row_description(conn: &mut PgConnection, rd: RowDescription) -> Statement {
     let mut names = HashMap::new();
     let mut columns = Vec::new();
 
@@ -68,6 +402,7 @@ fn parse_row_description(conn: &mut PgConnection, rd: RowDescription) -> Stateme
         let type_info = conn.get_type_info_by_oid(field.type_id.0);
 
         columns.push(Column {
+            name: field.name.clone(),
             type_info,
             format: field.type_format,
         });
@@ -117,22 +452,91 @@ async fn get_or_describe(
     if !conn.cache_statement.contains_key(&id) {
         let statement = expect_desc(conn).await?;
 
-        conn.cache_statement.insert(id, Arc::new(statement));
+        conn.cache_statement.insert(id.clone(), Arc::new(statement));
     }
 
     Ok(Arc::clone(&conn.cache_statement[&id]))
 }
 
+// Start the next result set of the query, skipping what remains of the current one
+// Returns the number of rows affected if the result set is already complete, having no rows
+async fn next_result_set(cursor: &mut PgCursor<'_, '_>) -> crate::Result<Option<Option<u64>>> {
+    while cursor.in_result_set {
+        if next(cursor, true).await?.is_none() {
+            break;
+        }
+    }
+
+    let conn = cursor.source.resolve().await?;
+
+    if let Some((query, arguments)) = cursor.query.take() {
+        let statement = conn.run(query, arguments, cursor.limit).await?;
+
+        // A prepared query has a single result set that is described up front
+        if let Some(statement) = statement {
+            cursor.statement = get_or_describe(&mut *conn, statement).await?;
+            cursor.in_result_set = true;
+
+            return Ok(Some(None));
+        }
+    } else if conn.is_ready {
+        // All result sets have been returned
+        return Ok(None);
+    }
+
+    loop {
+        match conn.stream.receive().await? {
+            Message::ParseComplete | Message::BindComplete => {}
+
+            Message::RowDescription => {
+                let rd = RowDescription::read(conn.stream.buffer())?;
+
+                cursor.statement = Arc::new(parse_row_description(conn, rd));
+                cursor.in_result_set = true;
+
+                return Ok(Some(None));
+            }
+
+            // A statement that returns no rows
+            Message::CommandComplete => {
+                let cc = CommandComplete::read(conn.stream.buffer())?;
+
+                cursor.statement = Arc::default();
+
+                return Ok(Some(Some(cc.affected_rows)));
+            }
+
+            Message::ReadyForQuery => {
+                let _ready = ReadyForQuery::read(conn.stream.buffer())?;
+
+                conn.is_ready = true;
+                conn.finish_query_log();
+
+                return Ok(None);
+            }
+
+            message => {
+                return Err(
+                    protocol_err!("next_result_set: unexpected message: {:?}", message).into(),
+                );
+            }
+        }
+    }
+}
+
+// Fetch the next row of the query; if [commands] is set, the number of rows affected by each
+// command is also returned as it completes
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut PgCursor<'c, 'q>,
-) -> crate::Result<Option<PgRow<'a>>> {
+    commands: bool,
+) -> crate::Result<Option<Either<u64, PgRow<'a>>>> {
     let mut conn = cursor.source.resolve().await?;
 
     // The first time [next] is called we need to actually execute our
     // contained query. We guard against this happening on _all_ next calls
     // by using [Option::take] which replaces the potential value in the Option with `None
     if let Some((query, arguments)) = cursor.query.take() {
-        let statement = conn.run(query, arguments).await?;
+        let statement = conn.run(query, arguments, cursor.limit).await?;
 
         // If there is a statement ID, this is a non-simple or prepared query
         if let Some(statement) = statement {
@@ -151,15 +555,40 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             // We as SQLx don't generally care as long as it is happening
             Message::ParseComplete | Message::BindComplete => {}
 
+            // Indicates that the row limit of a chunked query was reached
+            // We ask for the next chunk of rows once this chunk has been read
+            Message::PortalSuspended => {
+                conn.write_execute("", cursor.limit);
+                conn.write_flush();
+                conn.stream.flush().await?;
+            }
+
             // Indicates that _a_ query has finished executing
-            Message::CommandComplete => {}
+            Message::CommandComplete => {
+                cursor.in_result_set = false;
+
+                if conn.is_chunked {
+                    // All rows of a chunked query have been fetched
+                    conn.is_chunked = false;
+                    conn.write_sync();
+                    conn.stream.flush().await?;
+                }
+
+                if commands {
+                    let cc = CommandComplete::read(conn.stream.buffer())?;
+
+                    return Ok(Some(Either::Left(cc.affected_rows)));
+                }
+            }
 
             // Indicates that all queries have finished executing
             Message::ReadyForQuery => {
                 // TODO: How should we handle an ERROR status form ReadyForQuery
                 let _ready = ReadyForQuery::read(conn.stream.buffer())?;
 
+                cursor.in_result_set = false;
                 conn.is_ready = true;
+                conn.finish_query_log();
                 break;
             }
 
@@ -171,10 +600,11 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             Message::DataRow => {
                 let data = DataRow::read(conn.stream.buffer(), &mut conn.current_row_values)?;
 
-                return Ok(Some(PgRow {
+                return Ok(Some(Either::Right(PgRow {
                     statement: Arc::clone(&cursor.statement),
                     data,
-                }));
+                    time_zone_offset: conn.stream.time_zone_offset,
+                })));
             }
 
             message => {
@@ -4,9 +4,89 @@ use std::fmt::{self, Display};
 use crate::error::DatabaseError;
 use crate::postgres::protocol::Response;
 
+/// An error returned by Postgres.
+///
+/// Besides the methods of its `DatabaseError` implementation, this gives the fields of the error that are specific
+/// to Postgres and helps in telling apart the most common constraint violations.
+///
+/// ```rust,ignore
+/// match sqlx::query("INSERT INTO users (email) VALUES ($1)").bind(email).execute(&mut conn).await {
+///     Err(sqlx::Error::Database(err)) if err.downcast_ref::<PgError>().is_unique_violation() => {
+///         // the email is already taken
+///     }
+///
+///     res => {
+///         res?;
+///     }
+/// }
+/// ```
 #[derive(Debug)]
 pub struct PgError(pub(super) Response);
 
+// https://www.postgresql.org/docs/current/errcodes-appendix.html
+const NOT_NULL_VIOLATION: &str = "23502";
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const UNIQUE_VIOLATION: &str = "23505";
+const CHECK_VIOLATION: &str = "23514";
+
+impl PgError {
+    /// The severity of the error; one of `ERROR`, `FATAL`, or `PANIC`.
+    pub fn severity(&self) -> &str {
+        self.0.severity.as_str()
+    }
+
+    /// The SQLSTATE code of the error.
+    pub fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    /// An optional secondary message with more detail about the error; e.g., the conflicting
+    /// key of a unique violation.
+    pub fn detail(&self) -> Option<&str> {
+        self.0.detail.as_deref()
+    }
+
+    /// The name of the schema of the object the error is associated with, if any.
+    pub fn schema(&self) -> Option<&str> {
+        self.0.schema.as_deref()
+    }
+
+    /// The name of the table the error is associated with, if any.
+    pub fn table(&self) -> Option<&str> {
+        self.0.table.as_deref()
+    }
+
+    /// The name of the column the error is associated with, if any.
+    pub fn column(&self) -> Option<&str> {
+        self.0.column.as_deref()
+    }
+
+    /// The name of the constraint the error is associated with, if any.
+    pub fn constraint(&self) -> Option<&str> {
+        self.0.constraint.as_deref()
+    }
+
+    /// Returns `true` if this is a violation of a `UNIQUE` constraint or a primary key.
+    pub fn is_unique_violation(&self) -> bool {
+        self.code() == UNIQUE_VIOLATION
+    }
+
+    /// Returns `true` if this is a violation of a foreign key constraint.
+    pub fn is_foreign_key_violation(&self) -> bool {
+        self.code() == FOREIGN_KEY_VIOLATION
+    }
+
+    /// Returns `true` if this is a violation of a `NOT NULL` constraint.
+    pub fn is_not_null_violation(&self) -> bool {
+        self.code() == NOT_NULL_VIOLATION
+    }
+
+    /// Returns `true` if this is a violation of a `CHECK` constraint.
+    pub fn is_check_violation(&self) -> bool {
+        self.code() == CHECK_VIOLATION
+    }
+}
+
 impl DatabaseError for PgError {
     fn message(&self) -> &str {
         &self.0.message
@@ -63,6 +143,44 @@ impl From<PgError> for crate::Error {
     }
 }
 
+/// A non-error message from Postgres, such as a `NOTICE` or a `WARNING`, as given to the hook
+/// set with [PgConnection::on_notice](struct.PgConnection.html#method.on_notice).
+#[derive(Debug)]
+pub struct PgNotice(pub(super) Response);
+
+impl PgNotice {
+    /// The severity of the notice; one of `WARNING`, `NOTICE`, `DEBUG`, `INFO`, or `LOG`.
+    pub fn severity(&self) -> &str {
+        self.0.severity.as_str()
+    }
+
+    /// The SQLSTATE code of the notice.
+    pub fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    /// The primary human-readable message of the notice.
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// An optional secondary message with more detail about the notice.
+    pub fn detail(&self) -> Option<&str> {
+        self.0.detail.as_deref()
+    }
+
+    /// An optional suggestion of what to do about the notice.
+    pub fn hint(&self) -> Option<&str> {
+        self.0.hint.as_deref()
+    }
+}
+
+impl Display for PgNotice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.severity(), self.message())
+    }
+}
+
 #[test]
 fn test_error_downcasting() {
     use super::protocol::Severity;
@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 use futures_util::{stream, StreamExt, TryStreamExt};
@@ -8,10 +10,14 @@ use crate::arguments::Arguments;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
 use crate::executor::{Execute, Executor, RefExecutor};
+use crate::postgres::cursor::parse_row_description;
+use crate::postgres::literal::arguments_as_literals;
+use crate::postgres::named::is_ident;
 use crate::postgres::protocol::{
     self, CommandComplete, Field, Message, ParameterDescription, ReadyForQuery, RowDescription,
     StatementId, TypeFormat, TypeId,
 };
+use crate::postgres::row::Statement;
 use crate::postgres::type_info::SharedStr;
 use crate::postgres::types::try_resolve_type_name;
 use crate::postgres::{
@@ -30,12 +36,19 @@ impl PgConnection {
         query: &str,
         args: &PgArguments,
     ) -> crate::Result<StatementId> {
-        if let Some(&id) = self.cache_statement_id.get(query) {
-            Ok(id)
+        if let Some(id) = self.cache_statement_id.get(query) {
+            Ok(id.clone())
         } else {
-            let id = StatementId(self.next_statement_id);
+            let id = match &self.statement_name_prefix {
+                Some(prefix) => StatementId::named(prefix, query),
 
-            self.next_statement_id += 1;
+                None => {
+                    let id = StatementId::Id(self.next_statement_id);
+                    self.next_statement_id += 1;
+
+                    id
+                }
+            };
 
             // Build a list of type OIDs from the type info array provided by PgArguments
             // This may need to query Postgres for an OID of a user-defined type
@@ -51,7 +64,7 @@ impl PgConnection {
             }
 
             self.stream.write(protocol::Parse {
-                statement: id,
+                statement: id.clone(),
                 param_types: &*types,
                 query,
             });
@@ -72,14 +85,48 @@ impl PgConnection {
     ) -> crate::Result<()> {
         args.buffer.patch_type_holes(self).await?;
 
-        self.stream.write(protocol::Bind {
+        // Parameters are sent in binary unless their type declares otherwise
+        let formats = collect_formats(args.types.iter().map(|ty| ty.format));
+
+        // Results are requested in the format the driver can decode each column in
+        let result_formats = collect_formats(
+            self.cache_statement
+                .get(&statement)
+                .into_iter()
+                .flat_map(|statement| statement.columns.iter().map(|column| column.format)),
+        );
+
+        let bind = protocol::Bind {
             portal,
             statement,
-            formats: &[TypeFormat::Binary],
+            formats: &formats,
             values_len: args.types.len() as i16,
-            values: &*args.buffer,
-            result_formats: &[TypeFormat::Binary],
-        });
+            values: &args.buffer,
+            result_formats: &result_formats,
+        };
+
+        if !args.buffer.has_streamed() {
+            self.stream.write(bind);
+
+            return Ok(());
+        }
+
+        // Streamed values are flushed to the connection as they are read so anything left of
+        // the previous query must be received first
+        self.wait_until_ready().await?;
+
+        bind.write_head(self.stream.stream.buffer_mut());
+        let streamed = args.buffer.write_streamed(&mut self.stream).await;
+        bind.write_tail(self.stream.stream.buffer_mut());
+
+        if let Err(err) = streamed {
+            // The message was still written out in full; a [Sync] discards it
+            self.write_sync();
+            self.stream.flush().await?;
+            self.is_ready = false;
+
+            return Err(err);
+        }
 
         Ok(())
     }
@@ -92,7 +139,24 @@ impl PgConnection {
         self.stream.write(protocol::Sync);
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
+    pub(crate) fn write_flush(&mut self) {
+        self.stream.write(protocol::Flush);
+    }
+
+    // A query that fetches its rows in chunks has no [Sync] until its last row is received;
+    // if it was abandoned before then, [Sync] closes its portal so the query can complete
+    pub(super) async fn close_chunked(&mut self) -> crate::Result<()> {
+        if self.is_chunked {
+            self.is_chunked = false;
+
+            self.write_sync();
+            self.stream.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<()> {
         // depending on how the previous query finished we may need to continue
         // pulling messages from the stream until we receive a [ReadyForQuery] message
 
@@ -104,6 +168,7 @@ impl PgConnection {
                 if let Message::ReadyForQuery = self.stream.receive().await? {
                     // we are now ready to go
                     self.is_ready = true;
+                    self.finish_query_log();
                     break;
                 }
             }
@@ -118,35 +183,84 @@ impl PgConnection {
     // It is safe to call this method repeatedly (but all data from postgres would be lost) but
     // it is assumed that a call to [PgConnection::affected_rows] or [PgCursor::next] would
     // immediately follow.
+    //
+    // If [limit] is not zero, at most [limit] rows of a prepared query are returned before
+    // the portal is suspended; see [PgCursor].
     pub(crate) async fn run(
         &mut self,
         query: &str,
         arguments: Option<PgArguments>,
+        limit: i32,
     ) -> crate::Result<Option<StatementId>> {
-        let statement = if let Some(mut arguments) = arguments {
+        self.close_copy_in().await?;
+        self.close_chunked().await?;
+
+        let arguments_len = arguments
+            .as_ref()
+            .map_or(0, |arguments| arguments.types.len());
+
+        // Parameters bound to [PgDefault] are replaced by the DEFAULT keyword in the query text
+        // before it is prepared; the text is what we use as the key of the statement cache
+        let query = match &arguments {
+            Some(arguments) => inline_default_arguments(query, arguments),
+            None => Cow::Borrowed(query),
+        };
+
+        let query = &*query;
+
+        // Without prepared statements, the arguments are written into the query as literals and
+        // it is sent as a simple query
+        let inlined = match &arguments {
+            Some(arguments) if !self.prepared_statements => {
+                let literals = arguments_as_literals(arguments)?;
+
+                Some(replace_parameters(query, |index| {
+                    literals.get(index).map(String::as_str)
+                }))
+            }
+
+            _ => None,
+        };
+
+        let statement = if let Some(inlined) = inlined {
+            self.write_simple_query(&inlined);
+
+            None
+        } else if let Some(mut arguments) = arguments {
             // Check the statement cache for a statement ID that matches the given query
             // If it doesn't exist, we generate a new statement ID and write out [Parse] to the
             // connection command buffer
             let statement = self.write_prepare(query, &arguments).await?;
 
-            // Next, [Bind] attaches the arguments to the statement and creates a named portal
-            self.write_bind("", statement, &mut arguments).await?;
-
             // Next, [Describe] will return the expected result columns and types
             // Conditionally run [Describe] only if the results have not been cached
+            // This must be completed before [Bind] as we request the format of each result
+            // column based on its type. It costs an extra round-trip the first time a statement
+            // is executed on a connection; later executions use the cached description
             if !self.cache_statement.contains_key(&statement) {
-                self.write_describe(protocol::Describe::Portal(""));
+                self.describe_statement(query, statement.clone()).await?;
             }
 
-            // Next, [Execute] then executes the named portal
-            self.write_execute("", 0);
+            // Next, [Bind] attaches the arguments to the statement and creates a named portal
+            self.write_bind("", statement.clone(), &mut arguments)
+                .await?;
 
-            // Finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            // Next, [Execute] then executes the named portal
+            self.write_execute("", limit);
+
+            if limit > 0 {
+                // A [Sync] would close the portal after the first chunk of rows so we only ask
+                // postgres to send what it has; the [Sync] is sent once the query completes
+                self.write_flush();
+                self.is_chunked = true;
+            } else {
+                // Finally, [Sync] asks postgres to process the messages that we sent and respond
+                // with a [ReadyForQuery] message when it's completely done. Theoretically, we
+                // could send dozens of queries before a [Sync] and postgres can handle that.
+                // Execution on the server is still serial but it would reduce round-trips. Some
+                // kind of builder pattern that is termed batching might suit this.
+                self.write_sync();
+            }
 
             Some(statement)
         } else {
@@ -161,34 +275,117 @@ impl PgConnection {
         self.stream.flush().await?;
         self.is_ready = false;
 
-        // only cache
-        if let Some(statement) = statement {
-            // prefer redundant lookup to copying the query string
-            if !self.cache_statement_id.contains_key(query) {
-                // wait for `ParseComplete` on the stream or the
-                // error before we cache the statement
-                match self.stream.receive().await? {
-                    Message::ParseComplete => {
-                        self.cache_statement_id.insert(query.into(), statement);
-                    }
+        self.start_query_log(query, arguments_len);
 
-                    message => {
-                        return Err(protocol_err!("run: unexpected message: {:?}", message).into());
-                    }
-                }
+        Ok(statement)
+    }
+
+    // Describe a prepared statement and cache the description of its result columns
+    // This waits for the description of the statement but asks postgres to [Flush] rather than
+    // [Sync]; the [Bind] and [Execute] that follow are pipelined with the final [Sync]. It
+    // expects that [Parse] (if needed) has been written out to the connection command buffer
+    //
+    // The [Parse] is sent along with the [Describe] but waiting for the description is a
+    // round-trip of its own; it can't be pipelined with the [Bind] as the result formats that
+    // are requested on [Bind] depend on the types of the result columns
+    async fn describe_statement(
+        &mut self,
+        query: &str,
+        statement: StatementId,
+    ) -> crate::Result<()> {
+        self.wait_until_ready().await?;
+
+        self.write_describe(protocol::Describe::Statement(statement.clone()));
+        self.write_flush();
+
+        self.stream.flush().await?;
+
+        let description = match self.receive_statement_description(query, &statement).await {
+            Ok(description) => description,
+
+            Err(err) => {
+                // postgres discards the rest of the messages until a [Sync]
+                self.write_sync();
+                self.stream.flush().await?;
+                self.is_ready = false;
+
+                return Err(err);
             }
+        };
+
+        let mut description = match description {
+            Some(description) => parse_row_description(self, description),
+            None => Statement::default(),
+        };
+
+        // A statement description has no result formats (they are chosen on [Bind]) so we
+        // request each column in the format of its type
+        for column in description.columns.iter_mut() {
+            column.format = column.type_info.format;
         }
 
-        Ok(statement)
+        self.cache_statement
+            .insert(statement, Arc::new(description));
+
+        Ok(())
+    }
+
+    async fn receive_statement_description(
+        &mut self,
+        query: &str,
+        statement: &StatementId,
+    ) -> crate::Result<Option<RowDescription>> {
+        loop {
+            match self.stream.receive().await? {
+                Message::ParseComplete => {
+                    // the statement was successfully prepared, we can now cache it
+                    self.cache_statement_id
+                        .insert(query.into(), statement.clone());
+                }
+
+                Message::ParameterDescription => {}
+
+                Message::NoData => {
+                    return Ok(None);
+                }
+
+                Message::RowDescription => {
+                    return Ok(Some(RowDescription::read(self.stream.buffer())?));
+                }
+
+                message => {
+                    return Err(protocol_err!("describe: unexpected message: {:?}", message).into());
+                }
+            }
+        }
     }
 
     async fn do_describe<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
     ) -> crate::Result<Describe<Postgres>> {
+        // A previous query may not have been read to completion
+        self.close_copy_in().await?;
+        self.close_chunked().await?;
+        self.wait_until_ready().await?;
+
         self.is_ready = false;
 
-        let statement = self.write_prepare(query, &Default::default()).await?;
+        // A query that is not cached is prepared as the unnamed statement; it is prepared without
+        // the types of any arguments so it is not cached itself
+        let statement = match self.cache_statement_id.get(query) {
+            Some(statement) => statement.clone(),
+
+            None => {
+                self.stream.write(protocol::Parse {
+                    statement: StatementId::Id(0),
+                    param_types: &[],
+                    query,
+                });
+
+                StatementId::Id(0)
+            }
+        };
 
         self.write_describe(protocol::Describe::Statement(statement));
         self.write_sync();
@@ -259,15 +456,14 @@ impl PgConnection {
             return Ok(*oid);
         }
 
+        // The name is resolved as Postgres resolves the name of a type in a query; it may be
+        // qualified with its schema (e.g., `catalog.inventory_item`) and is otherwise looked up
+        // in the `search_path`
         // language=SQL
-        let (oid,): (u32,) = query_as(
-            "
-SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
-                ",
-        )
-        .bind(name)
-        .fetch_one(&mut *self)
-        .await?;
+        let (oid,): (u32,) = query_as("SELECT $1::text::regtype::oid")
+            .bind(quote_type_name(name))
+            .fetch_one(&mut *self.internal_query())
+            .await?;
 
         let shared = SharedStr::from(name.to_owned());
 
@@ -323,6 +519,8 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
                   inner join pg_catalog.pg_type on pg_type.oid = type_id \
                   order by types.idx";
 
+        let mut conn = self.internal_query();
+
         crate::query::query(&query)
             .bind_all(args)
             .try_map(|row: PgRow| -> crate::Result<(u32, SharedStr)> {
@@ -331,7 +529,7 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
                     row.try_get::<String, _>(1)?.into(),
                 ))
             })
-            .fetch(self)
+            .fetch(&mut *conn)
             .try_collect()
             .await
     }
@@ -374,6 +572,8 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
 
         log::trace!("describe pg_attribute query: {:#?}", query);
 
+        let mut conn = self.internal_query();
+
         crate::query::query(&query)
             .bind_all(args)
             .try_map(|row: PgRow| {
@@ -382,7 +582,7 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
 
                 Ok((idx, non_null))
             })
-            .fetch(self)
+            .fetch(&mut *conn)
             .zip(stream::iter(fields.into_vec().into_iter().enumerate()))
             .map(|(row, (fidx, field))| -> crate::Result<Column<_>> {
                 let (idx, non_null) = row?;
@@ -434,6 +634,7 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
                     let _ready = ReadyForQuery::read(self.stream.buffer())?;
 
                     self.is_ready = true;
+                    self.finish_query_log();
                     break;
                 }
 
@@ -449,6 +650,160 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
     }
 }
 
+// Collapse a list of formats to a single format code when they are all binary
+fn collect_formats(formats: impl Iterator<Item = TypeFormat>) -> Vec<TypeFormat> {
+    let formats: Vec<TypeFormat> = formats.collect();
+
+    if formats.iter().all(|&format| format == TypeFormat::Binary) {
+        vec![TypeFormat::Binary]
+    } else {
+        formats
+    }
+}
+
+// Replace each reference to a parameter that is bound to [PgDefault] with the DEFAULT keyword
+fn inline_default_arguments<'q>(query: &'q str, arguments: &PgArguments) -> Cow<'q, str> {
+    if !arguments.types.iter().any(|ty| ty.is_default) {
+        return Cow::Borrowed(query);
+    }
+
+    Cow::Owned(replace_parameters(query, |index| {
+        arguments
+            .types
+            .get(index)
+            .filter(|ty| ty.is_default)
+            .map(|_| "DEFAULT")
+    }))
+}
+
+// Quote each part of the (possibly schema-qualified) name of a type as an identifier; a part
+// that is not quoted already is folded to lowercase, as with an unquoted name, but may contain
+// characters that an unquoted name can not (e.g., `public.Traffic Lights`)
+fn quote_type_name(name: &str) -> String {
+    let (name, array) = match name.strip_suffix("[]") {
+        Some(name) => (name, "[]"),
+        None => (name, ""),
+    };
+
+    let mut parts = vec![String::new()];
+    let mut quoted = false;
+
+    for c in name.chars() {
+        match c {
+            '.' if !quoted => parts.push(String::new()),
+
+            c => {
+                quoted ^= c == '"';
+                parts.last_mut().unwrap().push(c);
+            }
+        }
+    }
+
+    let parts: Vec<String> = parts
+        .into_iter()
+        .map(|part| {
+            if part.len() > 1 && part.starts_with('"') && part.ends_with('"') {
+                part
+            } else {
+                format!("\"{}\"", part.to_lowercase().replace('"', "\"\""))
+            }
+        })
+        .collect();
+
+    parts.join(".") + array
+}
+
+// Replace each reference to a parameter by the text [replace] returns for its (zero-based)
+// index, if any. String literals, quoted identifiers, comments and dollar-quoted strings are
+// left untouched
+fn replace_parameters<'a>(query: &str, replace: impl Fn(usize) -> Option<&'a str>) -> String {
+    let bytes = query.as_bytes();
+    let find = |from: usize, pat: &str| query[from..].find(pat).map(|i| from + i);
+
+    let mut inlined = String::with_capacity(query.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ b'\'' | quote @ b'"' => {
+                // Backslash escapes are only recognized in E'' strings
+                let escapes =
+                    quote == b'\'' && i > 0 && (bytes[i - 1] == b'E' || bytes[i - 1] == b'e');
+
+                i += 1;
+
+                while i < bytes.len() && bytes[i] != quote {
+                    i += if escapes && bytes[i] == b'\\' { 2 } else { 1 };
+                }
+
+                // A doubled quote is read as two adjacent strings
+                i += 1;
+            }
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = find(i, "\n").unwrap_or(bytes.len());
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = find(i + 2, "*/").map_or(bytes.len(), |end| end + 2);
+            }
+
+            b'$' if i > 0 && is_ident(bytes[i - 1]) => {
+                i += 1;
+            }
+
+            b'$' if matches!(bytes.get(i + 1), Some(b) if b.is_ascii_digit()) => {
+                let start = i;
+
+                i += 1;
+
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+
+                let replacement = query[start + 1..i]
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| replace(index.checked_sub(1)?));
+
+                if let Some(replacement) = replacement {
+                    inlined.push_str(&query[copied..start]);
+                    inlined.push_str(replacement);
+                    copied = i;
+                }
+            }
+
+            b'$' => {
+                // A dollar-quoted string; $tag$ ... $tag$
+                let tag_end = bytes[i + 1..]
+                    .iter()
+                    .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                    .map(|len| i + 1 + len)
+                    .filter(|&end| bytes[end] == b'$');
+
+                i = match tag_end {
+                    Some(end) => {
+                        let tag = &query[i..=end];
+
+                        find(end + 1, tag).map_or(bytes.len(), |close| close + tag.len())
+                    }
+
+                    None => i + 1,
+                };
+            }
+
+            _ => {
+                i += 1;
+            }
+        }
+    }
+
+    inlined.push_str(&query[copied..]);
+
+    inlined
+}
+
 impl Executor for super::PgConnection {
     type Database = Postgres;
 
@@ -462,7 +817,7 @@ impl Executor for super::PgConnection {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
 
-            self.run(query, arguments).await?;
+            self.run(query, arguments, 0).await?;
             self.affected_rows().await
         })
     }
@@ -474,7 +829,6 @@ impl Executor for super::PgConnection {
         PgCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -496,3 +850,63 @@ impl<'c> RefExecutor<'c> for &'c mut super::PgConnection {
         PgCursor::from_connection(self, query)
     }
 }
+
+#[test]
+fn test_inline_default_arguments() {
+    use crate::postgres::types::PgDefault;
+
+    let mut arguments = PgArguments::default();
+    arguments.add(10_i32);
+    arguments.add(PgDefault);
+
+    assert_eq!(
+        inline_default_arguments("INSERT INTO t (a, b) VALUES ($1, $2)", &arguments),
+        "INSERT INTO t (a, b) VALUES ($1, DEFAULT)"
+    );
+
+    assert_eq!(
+        inline_default_arguments(
+            "UPDATE t SET b = $2, c = '$2', \"$2\" = $$ $2 $$ -- $2\nWHERE a = $1 /* $2 */",
+            &arguments
+        ),
+        "UPDATE t SET b = DEFAULT, c = '$2', \"$2\" = $$ $2 $$ -- $2\nWHERE a = $1 /* $2 */"
+    );
+
+    assert_eq!(
+        inline_default_arguments("SELECT E'\\'$2', $tag$ $2 $tag$, $20", &arguments),
+        "SELECT E'\\'$2', $tag$ $2 $tag$, $20"
+    );
+}
+
+#[test]
+fn test_inline_default_arguments_unchanged() {
+    let mut arguments = PgArguments::default();
+    arguments.add(10_i32);
+
+    assert!(matches!(
+        inline_default_arguments("SELECT $1", &arguments),
+        Cow::Borrowed("SELECT $1")
+    ));
+}
+
+#[test]
+fn test_quote_type_name() {
+    assert_eq!(quote_type_name("INT4"), r#""int4""#);
+    assert_eq!(quote_type_name("_int4"), r#""_int4""#);
+    assert_eq!(quote_type_name("INT4[]"), r#""int4"[]"#);
+
+    assert_eq!(
+        quote_type_name("catalog.Inventory_Item"),
+        r#""catalog"."inventory_item""#
+    );
+
+    assert_eq!(
+        quote_type_name("public.Traffic Lights"),
+        r#""public"."traffic lights""#
+    );
+
+    assert_eq!(
+        quote_type_name(r#""My.Schema"."Five""#),
+        r#""My.Schema"."Five""#
+    );
+}
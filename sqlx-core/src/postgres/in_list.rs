@@ -0,0 +1,179 @@
+use std::io;
+
+use crate::arguments::Arguments;
+use crate::encode::Encode;
+use crate::postgres::named::{is_ident, skip_quoted};
+use crate::postgres::{PgArguments, Postgres};
+use crate::types::Type;
+
+/// Expand the `IN (?)` marker of a query into a positional parameter for each of the values;
+/// e.g., `IN ($1, $2, $3)`. Returns the rewritten query along with the values bound in order.
+///
+/// The values take the first positions. The other parameters of the query keep their order
+/// and are bound after the values; e.g., with three values, `$1` is rewritten to `$4`.
+///
+/// ```rust,ignore
+/// let (sql, arguments) = sqlx::postgres::query_with_in(
+///     "SELECT * FROM users WHERE team_id = $1 AND id IN (?)",
+///     ids,
+/// )?;
+///
+/// // with three ids; SELECT * FROM users WHERE team_id = $4 AND id IN ($1, $2, $3)
+/// let rows = sqlx::query(&sql).bind_all(arguments).bind(team_id).fetch(&mut conn);
+/// ```
+///
+/// With no values, the marker is rewritten to `IN (NULL)`, which matches no rows. Note that
+/// `NOT IN (NULL)` does not match any rows either.
+///
+/// A `(?)` in a string literal, a quoted identifier or a comment is left untouched.
+///
+/// Returns an error of the kind [`io::ErrorKind::InvalidInput`] if the query does not contain
+/// exactly one `IN (?)` marker.
+pub fn query_with_in<I>(query: &str, values: I) -> crate::Result<(String, PgArguments)>
+where
+    I: IntoIterator,
+    I::Item: Type<Postgres> + Encode<Postgres>,
+{
+    let mut arguments = PgArguments::default();
+    let mut count = 0;
+
+    for value in values {
+        arguments.add(value);
+        count += 1;
+    }
+
+    let bytes = query.as_bytes();
+
+    let mut rewritten = String::with_capacity(query.len() + count * 4);
+    let mut copied = 0;
+    let mut markers = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if let Some(end) = skip_quoted(query, i) {
+            i = end;
+            continue;
+        }
+
+        match bytes[i] {
+            b'(' if query[i..].starts_with("(?)") && follows_in(&query[..i]) => {
+                rewritten.push_str(&query[copied..i]);
+
+                if count == 0 {
+                    rewritten.push_str("(NULL)");
+                } else {
+                    rewritten.push('(');
+
+                    for position in 1..=count {
+                        if position > 1 {
+                            rewritten.push_str(", ");
+                        }
+
+                        rewritten.push_str(&format!("${}", position));
+                    }
+
+                    rewritten.push(')');
+                }
+
+                markers += 1;
+                i += 3;
+                copied = i;
+            }
+
+            // A `$` within an identifier; e.g., `a$1`
+            b'$' if i > 0 && is_ident(bytes[i - 1]) => {
+                i += 1;
+            }
+
+            // A positional parameter; $1
+            b'$' if matches!(bytes.get(i + 1), Some(b) if b.is_ascii_digit()) => {
+                let start = i;
+
+                i += 1;
+
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+
+                let position: usize = query[start + 1..i].parse().unwrap();
+
+                rewritten.push_str(&query[copied..start]);
+                rewritten.push_str(&format!("${}", position + count));
+                copied = i;
+            }
+
+            _ => {
+                i += 1;
+            }
+        }
+    }
+
+    if markers != 1 {
+        return Err(crate::Error::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "expected exactly one `IN (?)` marker in the query, found {}: {:?}",
+                markers, query
+            ),
+        )));
+    }
+
+    rewritten.push_str(&query[copied..]);
+
+    Ok((rewritten, arguments))
+}
+
+// Whether the query up to the marker ends with the keyword `IN`; e.g., `id IN ` or `id NOT IN`
+fn follows_in(before: &str) -> bool {
+    let before = before.trim_end().as_bytes();
+
+    before.len() >= 2
+        && before[before.len() - 2..].eq_ignore_ascii_case(b"in")
+        && !matches!(
+            before[..before.len() - 2].last(),
+            Some(&b) if b.is_ascii_alphanumeric() || b == b'_'
+        )
+}
+
+#[test]
+fn test_query_with_in() {
+    let (sql, arguments) = query_with_in(
+        "SELECT * FROM users WHERE team_id = $1 AND name <> '(?)' AND id IN (?) AND $2 > 0",
+        vec![1_i32, 2, 3],
+    )
+    .unwrap();
+
+    assert_eq!(
+        sql,
+        "SELECT * FROM users WHERE team_id = $4 AND name <> '(?)' AND id IN ($1, $2, $3) AND $5 > 0"
+    );
+
+    assert_eq!(arguments.types.len(), 3);
+}
+
+#[test]
+fn test_query_with_in_identifier_with_dollar() {
+    let (sql, _) = query_with_in(
+        "SELECT a$1 FROM t WHERE a$1 = $1 AND id IN (?)",
+        vec![1_i32],
+    )
+    .unwrap();
+
+    assert_eq!(sql, "SELECT a$1 FROM t WHERE a$1 = $2 AND id IN ($1)");
+}
+
+#[test]
+fn test_query_with_in_empty() {
+    let (sql, arguments) = query_with_in("SELECT 1 WHERE 1 not in (?)", Vec::<i32>::new()).unwrap();
+
+    assert_eq!(sql, "SELECT 1 WHERE 1 not in (NULL)");
+    assert!(arguments.types.is_empty());
+}
+
+#[test]
+fn test_query_with_in_without_marker() {
+    // `join` is not `IN`
+    assert!(query_with_in("SELECT * FROM t JOIN (?)", vec![1_i32]).is_err());
+
+    assert!(query_with_in("SELECT * FROM t WHERE a IN (?) OR b IN (?)", vec![1_i32]).is_err());
+}
@@ -0,0 +1,733 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+use byteorder::NetworkEndian;
+
+use crate::io::Buf;
+use crate::postgres::protocol::{TypeFormat, TypeId};
+use crate::postgres::types::try_resolve_type_name;
+use crate::postgres::{PgArguments, PgTypeInfo};
+
+// The number of days from 1970-01-01 to 2000-01-01; Postgres counts dates from the latter
+const POSTGRES_EPOCH_DAYS: i64 = 10_957;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+// Renders the bind parameters as SQL literals; this is how they are sent when prepared
+// statements are disabled on the connection and a query is sent as a simple query
+pub(super) fn arguments_as_literals(arguments: &PgArguments) -> crate::Result<Vec<String>> {
+    if arguments.buffer.has_streamed() {
+        return Err(
+            protocol_err!("a streamed value can not be bound without prepared statements").into(),
+        );
+    }
+
+    let mut values = Vec::with_capacity(arguments.buffer.encoded_len());
+    arguments.buffer.write_to(&mut values);
+
+    let mut buf = &*values;
+    let mut literals = Vec::with_capacity(arguments.types.len());
+
+    for ty in &arguments.types {
+        let len = buf.get_i32::<NetworkEndian>()?;
+        let value = if len < 0 {
+            None
+        } else {
+            Some(buf.get_bytes(len as usize)?)
+        };
+
+        // The literal is parenthesized so it stays one operand wherever the parameter
+        // was, e.g., in `$1[1]` or `$1::text`
+        let mut literal = String::from("(");
+        write_literal(&mut literal, ty, value)?;
+        literal.push(')');
+
+        literals.push(literal);
+    }
+
+    Ok(literals)
+}
+
+// A value is written as a string literal cast to the type of the parameter; e.g.,
+// `'10'::INT4` or `E'C:\\Temp'::TEXT`. Numbers are quoted as well so a negative number can
+// not start a comment; e.g., in `1-$1`.
+fn write_literal(literal: &mut String, ty: &PgTypeInfo, value: Option<&[u8]>) -> crate::Result<()> {
+    if ty.is_default {
+        literal.push_str("DEFAULT");
+
+        return Ok(());
+    }
+
+    // The name of a builtin type is resolved from its OID; e.g., the name of `i8` is "char"
+    let name = ty.id.and_then(cast_name).unwrap_or(&*ty.name);
+
+    let value = match value {
+        Some(value) => value,
+
+        None => {
+            let _ = write!(literal, "NULL::{}", name);
+
+            return Ok(());
+        }
+    };
+
+    let mut text = String::new();
+
+    match (ty.format, ty.id) {
+        (TypeFormat::Text, _) => text.push_str(utf8(value)?),
+
+        (TypeFormat::Binary, Some(id)) => write_text(&mut text, id, value)?,
+
+        (TypeFormat::Binary, None) => {
+            return Err(unsupported(name));
+        }
+    }
+
+    if text.contains('\0') {
+        return Err(protocol_err!("a value of {} can not contain a NUL character", name).into());
+    }
+
+    write_quoted(literal, &text);
+
+    let _ = write!(literal, "::{}", name);
+
+    Ok(())
+}
+
+// Backslashes are only escapes in an E'' string; with `standard_conforming_strings` off, they
+// are also escapes in a '' string, so a value with a backslash is always written as E''
+fn write_quoted(literal: &mut String, text: &str) {
+    if text.contains('\\') {
+        literal.push('E');
+    }
+
+    literal.push('\'');
+
+    for c in text.chars() {
+        match c {
+            '\'' => literal.push_str("''"),
+            '\\' => literal.push_str("\\\\"),
+
+            c => literal.push(c),
+        }
+    }
+
+    literal.push('\'');
+}
+
+// Write the text format of a value of a builtin type from its binary format
+fn write_text(text: &mut String, id: TypeId, mut buf: &[u8]) -> crate::Result<()> {
+    match id {
+        TypeId::BOOL => text.push_str(if buf.get_u8()? != 0 { "t" } else { "f" }),
+
+        TypeId::CHAR => match buf.get_u8()? {
+            0 => {}
+            b if b < 0x80 => text.push(b as char),
+            b => {
+                let _ = write!(text, "\\{:03o}", b);
+            }
+        },
+
+        TypeId::INT2 => {
+            let _ = write!(text, "{}", buf.get_i16::<NetworkEndian>()?);
+        }
+
+        TypeId::INT4 => {
+            let _ = write!(text, "{}", buf.get_i32::<NetworkEndian>()?);
+        }
+
+        TypeId::INT8 => {
+            let _ = write!(text, "{}", buf.get_i64::<NetworkEndian>()?);
+        }
+
+        TypeId::OID => {
+            let _ = write!(text, "{}", buf.get_u32::<NetworkEndian>()?);
+        }
+
+        TypeId::FLOAT4 => {
+            let value = f32::from_bits(buf.get_u32::<NetworkEndian>()?);
+            write_float(text, f64::from(value), value);
+        }
+
+        TypeId::FLOAT8 => {
+            let value = f64::from_bits(buf.get_u64::<NetworkEndian>()?);
+            write_float(text, value, value);
+        }
+
+        TypeId::NUMERIC => write_numeric(text, buf)?,
+
+        TypeId::TEXT
+        | TypeId::VARCHAR
+        | TypeId::BPCHAR
+        | TypeId::NAME
+        | TypeId::UNKNOWN
+        | TypeId::JSON => text.push_str(utf8(buf)?),
+
+        TypeId::JSONB => {
+            // JSONB is prefixed by a version byte
+            buf.get_u8()?;
+            text.push_str(utf8(buf)?);
+        }
+
+        TypeId::BYTEA => {
+            text.push_str("\\x");
+
+            for b in buf {
+                let _ = write!(text, "{:02x}", b);
+            }
+        }
+
+        TypeId::UUID => {
+            for (i, b) in buf.iter().enumerate() {
+                if i == 4 || i == 6 || i == 8 || i == 10 {
+                    text.push('-');
+                }
+
+                let _ = write!(text, "{:02x}", b);
+            }
+        }
+
+        TypeId::DATE => match buf.get_i32::<NetworkEndian>()? {
+            i32::MAX => text.push_str("infinity"),
+            i32::MIN => text.push_str("-infinity"),
+
+            days => {
+                let era = write_date(text, i64::from(days));
+                text.push_str(era);
+            }
+        },
+
+        TypeId::TIME => write_time(text, buf.get_i64::<NetworkEndian>()?),
+
+        TypeId::TIMESTAMP | TypeId::TIMESTAMPTZ => match buf.get_i64::<NetworkEndian>()? {
+            i64::MAX => text.push_str("infinity"),
+            i64::MIN => text.push_str("-infinity"),
+
+            micros => {
+                let era = write_date(text, micros.div_euclid(MICROS_PER_DAY));
+
+                text.push(' ');
+                write_time(text, micros.rem_euclid(MICROS_PER_DAY));
+
+                if id == TypeId::TIMESTAMPTZ {
+                    text.push_str("+00");
+                }
+
+                text.push_str(era);
+            }
+        },
+
+        TypeId::INTERVAL => {
+            let micros = i128::from(buf.get_i64::<NetworkEndian>()?);
+            let days = buf.get_i32::<NetworkEndian>()?;
+            let months = buf.get_i32::<NetworkEndian>()?;
+
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.abs();
+
+            let _ = write!(
+                text,
+                "{} months {} days {}{}.{:06} seconds",
+                months,
+                days,
+                sign,
+                micros / 1_000_000,
+                micros % 1_000_000
+            );
+        }
+
+        id if is_array(id) => write_array(text, buf)?,
+
+        id => {
+            return Err(unsupported(
+                try_resolve_type_name(id.0).unwrap_or("UNKNOWN"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn write_float<T: std::fmt::Display>(text: &mut String, value: f64, display: T) {
+    if value.is_nan() {
+        text.push_str("NaN");
+    } else if value.is_infinite() {
+        text.push_str(if value > 0.0 { "Infinity" } else { "-Infinity" });
+    } else {
+        // the shortest representation that reads back as the same value
+        let _ = write!(text, "{}", display);
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c
+// numeric_send; the digits are base 10000 and `weight` is the index of the ones digit
+fn write_numeric(text: &mut String, mut buf: &[u8]) -> crate::Result<()> {
+    let ndigits = buf.get_i16::<NetworkEndian>()?;
+    let weight = buf.get_i16::<NetworkEndian>()?;
+    let sign = buf.get_u16::<NetworkEndian>()?;
+    let scale = buf.get_u16::<NetworkEndian>()? as usize;
+
+    let mut digits = Vec::with_capacity(ndigits.max(0) as usize);
+
+    for _ in 0..ndigits {
+        digits.push(buf.get_i16::<NetworkEndian>()?);
+    }
+
+    let digit = |index: i32| {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| digits.get(index))
+            .copied()
+            .unwrap_or(0)
+    };
+
+    match sign {
+        0xC000 => {
+            text.push_str("NaN");
+
+            return Ok(());
+        }
+
+        0x4000 => text.push('-'),
+
+        _ => {}
+    }
+
+    if weight < 0 {
+        text.push('0');
+    } else {
+        let _ = write!(text, "{}", digit(0));
+
+        for index in 1..=i32::from(weight) {
+            let _ = write!(text, "{:04}", digit(index));
+        }
+    }
+
+    if scale > 0 {
+        let mut fraction = String::with_capacity(scale + 4);
+        let mut index = i32::from(weight) + 1;
+
+        while fraction.len() < scale {
+            let _ = write!(fraction, "{:04}", digit(index));
+            index += 1;
+        }
+
+        fraction.truncate(scale);
+
+        text.push('.');
+        text.push_str(&fraction);
+    }
+
+    Ok(())
+}
+
+// Write a date of [days] since 2000-01-01; returns the suffix of a year before 1 AD
+fn write_date(text: &mut String, days: i64) -> &'static str {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + POSTGRES_EPOCH_DAYS + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    // there is no year 0; 1 BC is followed by 1 AD
+    let (year, era) = if year <= 0 {
+        (1 - year, " BC")
+    } else {
+        (year, "")
+    };
+
+    let _ = write!(text, "{:04}-{:02}-{:02}", year, month, day);
+
+    era
+}
+
+// Write a time of [micros] since midnight
+fn write_time(text: &mut String, micros: i64) {
+    let seconds = micros / 1_000_000;
+
+    let _ = write!(
+        text,
+        "{:02}:{:02}:{:02}.{:06}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        micros % 1_000_000
+    );
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/arrayfuncs.c
+// array_send; the elements are written in the text format of an array, e.g., `{"1","2"}`
+fn write_array(text: &mut String, mut buf: &[u8]) -> crate::Result<()> {
+    let ndim = buf.get_i32::<NetworkEndian>()?;
+    let _has_null = buf.get_i32::<NetworkEndian>()?;
+    let element = TypeId(buf.get_u32::<NetworkEndian>()?);
+
+    if ndim <= 0 {
+        text.push_str("{}");
+
+        return Ok(());
+    }
+
+    let mut dims = Vec::with_capacity(ndim as usize);
+
+    for _ in 0..ndim {
+        let len = buf.get_i32::<NetworkEndian>()?;
+        let lower = buf.get_i32::<NetworkEndian>()?;
+
+        dims.push((len, lower));
+    }
+
+    // The bounds are only written if an array does not start at 1; e.g., `[0:1]={"1","2"}`
+    if dims.iter().any(|&(_, lower)| lower != 1) {
+        for &(len, lower) in &dims {
+            let _ = write!(text, "[{}:{}]", lower, lower + len - 1);
+        }
+
+        text.push('=');
+    }
+
+    write_array_dims(text, element, &dims, &mut buf)
+}
+
+fn write_array_dims(
+    text: &mut String,
+    element: TypeId,
+    dims: &[(i32, i32)],
+    buf: &mut &[u8],
+) -> crate::Result<()> {
+    text.push('{');
+
+    for i in 0..dims[0].0 {
+        if i > 0 {
+            text.push(',');
+        }
+
+        if dims.len() > 1 {
+            write_array_dims(text, element, &dims[1..], buf)?;
+            continue;
+        }
+
+        let len = buf.get_i32::<NetworkEndian>()?;
+
+        if len < 0 {
+            text.push_str("NULL");
+            continue;
+        }
+
+        let mut value = String::new();
+        write_text(&mut value, element, buf.get_bytes(len as usize)?)?;
+
+        text.push('"');
+
+        for c in value.chars() {
+            if c == '"' || c == '\\' {
+                text.push('\\');
+            }
+
+            text.push(c);
+        }
+
+        text.push('"');
+    }
+
+    text.push('}');
+
+    Ok(())
+}
+
+// The name of a builtin type in a cast; most are the names types are displayed with but those
+// are not always valid SQL, e.g., the single-byte `"char"` is displayed as `"CHAR"` which, as a
+// quoted identifier, does not name a type
+fn cast_name(id: TypeId) -> Option<&'static str> {
+    Some(match id {
+        TypeId::BOOL => "BOOL",
+
+        TypeId::CHAR => "\"char\"",
+
+        TypeId::INT2 => "INT2",
+        TypeId::INT4 => "INT4",
+        TypeId::INT8 => "INT8",
+
+        TypeId::OID => "OID",
+
+        TypeId::INT2VECTOR => "INT2VECTOR",
+        TypeId::OIDVECTOR => "OIDVECTOR",
+
+        TypeId::FLOAT4 => "FLOAT4",
+        TypeId::FLOAT8 => "FLOAT8",
+
+        TypeId::NUMERIC => "NUMERIC",
+
+        TypeId::MONEY => "MONEY",
+
+        TypeId::TEXT => "TEXT",
+        TypeId::VARCHAR => "VARCHAR",
+        TypeId::BPCHAR => "BPCHAR",
+        TypeId::UNKNOWN => "UNKNOWN",
+        TypeId::NAME => "NAME",
+
+        TypeId::DATE => "DATE",
+        TypeId::TIME => "TIME",
+        TypeId::TIMETZ => "TIMETZ",
+        TypeId::TIMESTAMP => "TIMESTAMP",
+        TypeId::TIMESTAMPTZ => "TIMESTAMPTZ",
+        TypeId::INTERVAL => "INTERVAL",
+
+        TypeId::BYTEA => "BYTEA",
+
+        TypeId::UUID => "UUID",
+
+        TypeId::CIDR => "CIDR",
+        TypeId::INET => "INET",
+
+        TypeId::MACADDR => "MACADDR",
+        TypeId::MACADDR8 => "MACADDR8",
+
+        TypeId::PG_LSN => "PG_LSN",
+
+        TypeId::TID => "TID",
+
+        TypeId::ACLITEM => "ACLITEM",
+
+        TypeId::TSVECTOR => "TSVECTOR",
+        TypeId::TSQUERY => "TSQUERY",
+
+        TypeId::REGPROC => "REGPROC",
+        TypeId::REGPROCEDURE => "REGPROCEDURE",
+        TypeId::REGOPER => "REGOPER",
+        TypeId::REGOPERATOR => "REGOPERATOR",
+        TypeId::REGCLASS => "REGCLASS",
+        TypeId::REGTYPE => "REGTYPE",
+        TypeId::REGCONFIG => "REGCONFIG",
+        TypeId::REGDICTIONARY => "REGDICTIONARY",
+        TypeId::REGNAMESPACE => "REGNAMESPACE",
+        TypeId::REGROLE => "REGROLE",
+
+        TypeId::ARRAY_BOOL => "BOOL[]",
+
+        TypeId::ARRAY_CHAR => "\"char\"[]",
+
+        TypeId::ARRAY_INT2 => "INT2[]",
+        TypeId::ARRAY_INT4 => "INT4[]",
+        TypeId::ARRAY_INT8 => "INT8[]",
+
+        TypeId::ARRAY_OID => "OID[]",
+
+        TypeId::ARRAY_FLOAT4 => "FLOAT4[]",
+        TypeId::ARRAY_FLOAT8 => "FLOAT8[]",
+
+        TypeId::ARRAY_TEXT => "TEXT[]",
+        TypeId::ARRAY_VARCHAR => "VARCHAR[]",
+        TypeId::ARRAY_BPCHAR => "BPCHAR[]",
+        TypeId::ARRAY_NAME => "NAME[]",
+
+        TypeId::ARRAY_NUMERIC => "NUMERIC[]",
+
+        TypeId::ARRAY_MONEY => "MONEY[]",
+
+        TypeId::ARRAY_DATE => "DATE[]",
+        TypeId::ARRAY_TIME => "TIME[]",
+        TypeId::ARRAY_TIMETZ => "TIMETZ[]",
+        TypeId::ARRAY_TIMESTAMP => "TIMESTAMP[]",
+        TypeId::ARRAY_TIMESTAMPTZ => "TIMESTAMPTZ[]",
+        TypeId::ARRAY_INTERVAL => "INTERVAL[]",
+
+        TypeId::ARRAY_BYTEA => "BYTEA[]",
+
+        TypeId::ARRAY_UUID => "UUID[]",
+
+        TypeId::ARRAY_CIDR => "CIDR[]",
+        TypeId::ARRAY_INET => "INET[]",
+
+        TypeId::ARRAY_MACADDR => "MACADDR[]",
+        TypeId::ARRAY_MACADDR8 => "MACADDR8[]",
+
+        TypeId::ARRAY_PG_LSN => "PG_LSN[]",
+
+        TypeId::ARRAY_TID => "TID[]",
+
+        TypeId::ARRAY_ACLITEM => "ACLITEM[]",
+
+        TypeId::ARRAY_TSVECTOR => "TSVECTOR[]",
+        TypeId::ARRAY_TSQUERY => "TSQUERY[]",
+
+        TypeId::POINT => "POINT",
+        TypeId::LSEG => "LSEG",
+        TypeId::PATH => "PATH",
+        TypeId::POLYGON => "POLYGON",
+        TypeId::LINE => "LINE",
+
+        TypeId::ARRAY_POINT => "POINT[]",
+        TypeId::ARRAY_LSEG => "LSEG[]",
+        TypeId::ARRAY_PATH => "PATH[]",
+        TypeId::ARRAY_POLYGON => "POLYGON[]",
+        TypeId::ARRAY_LINE => "LINE[]",
+
+        TypeId::JSON => "JSON",
+        TypeId::JSONB => "JSONB",
+        TypeId::JSONPATH => "JSONPATH",
+        TypeId::ARRAY_JSONPATH => "JSONPATH[]",
+
+        TypeId::XML => "XML",
+        TypeId::ARRAY_XML => "XML[]",
+
+        TypeId::RECORD => "RECORD",
+        TypeId::ARRAY_RECORD => "RECORD[]",
+
+        _ => {
+            return None;
+        }
+    })
+}
+
+fn is_array(id: TypeId) -> bool {
+    matches!(try_resolve_type_name(id.0), Some(name) if name.ends_with("[]"))
+}
+
+fn utf8(buf: &[u8]) -> crate::Result<&str> {
+    std::str::from_utf8(buf).map_err(|err| protocol_err!("invalid UTF-8 in value: {}", err).into())
+}
+
+fn unsupported(name: &str) -> crate::Error {
+    protocol_err!(
+        "a value of {} can not be bound without prepared statements; \
+         bind its text instead and cast the parameter, e.g., `$1::{}`",
+        name,
+        name
+    )
+    .into()
+}
+
+#[cfg(test)]
+fn literals(arguments: PgArguments) -> Vec<String> {
+    arguments_as_literals(&arguments).unwrap()
+}
+
+#[test]
+fn test_literal_escapes_strings() {
+    use crate::arguments::Arguments;
+
+    let mut arguments = PgArguments::default();
+    arguments.add("it's");
+    arguments.add("C:\\Temp");
+    arguments.add("'; DROP TABLE users; --");
+    arguments.add(None::<String>);
+
+    assert_eq!(
+        literals(arguments),
+        vec![
+            "('it''s'::TEXT)",
+            "(E'C:\\\\Temp'::TEXT)",
+            "('''; DROP TABLE users; --'::TEXT)",
+            "(NULL::TEXT)",
+        ]
+    );
+}
+
+#[test]
+fn test_literal_numbers() {
+    use crate::arguments::Arguments;
+
+    let mut arguments = PgArguments::default();
+    arguments.add(true);
+    arguments.add(-5_i16);
+    arguments.add(i32::MIN);
+    arguments.add(i64::MAX);
+    arguments.add(b'a' as i8);
+    arguments.add(0.1_f32);
+    arguments.add(-2.5_f64);
+    arguments.add(std::f64::INFINITY);
+
+    assert_eq!(
+        literals(arguments),
+        vec![
+            "('t'::BOOL)",
+            "('-5'::INT2)",
+            "('-2147483648'::INT4)",
+            "('9223372036854775807'::INT8)",
+            "('a'::\"char\")",
+            "('0.1'::FLOAT4)",
+            "('-2.5'::FLOAT8)",
+            "('Infinity'::FLOAT8)",
+        ]
+    );
+}
+
+#[test]
+fn test_literal_arrays() {
+    use crate::arguments::Arguments;
+
+    let mut arguments = PgArguments::default();
+    arguments.add(vec![1_i32, 2, 3]);
+    arguments.add(vec!["a\"b", "c\\d", "it's"]);
+    arguments.add(Vec::<i64>::new());
+    arguments.add(vec![&b"\x01\xff"[..]]);
+    arguments.add(vec![b'a' as i8]);
+
+    assert_eq!(
+        literals(arguments),
+        vec![
+            "('{\"1\",\"2\",\"3\"}'::INT4[])",
+            "(E'{\"a\\\\\"b\",\"c\\\\\\\\d\",\"it''s\"}'::TEXT[])",
+            "('{}'::INT8[])",
+            "(E'{\"\\\\\\\\x01ff\"}'::BYTEA[])",
+            "('{\"a\"}'::\"char\"[])",
+        ]
+    );
+}
+
+#[test]
+fn test_write_numeric() {
+    let text = |bytes: &[u8]| {
+        let mut text = String::new();
+        write_numeric(&mut text, bytes).unwrap();
+        text
+    };
+
+    // 12345.678; digits 1, 2345, 6780 with a weight of 1 and a scale of 3
+    assert_eq!(
+        text(&[0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1A, 0x7C]),
+        "12345.678"
+    );
+
+    // -0.00001; the digit 1000 with a weight of -2 and a scale of 5
+    assert_eq!(
+        text(&[0, 1, 0xFF, 0xFE, 0x40, 0, 0, 5, 0x03, 0xE8]),
+        "-0.00001"
+    );
+
+    // 10000; the digit 1 with a weight of 1
+    assert_eq!(text(&[0, 1, 0, 1, 0, 0, 0, 0, 0, 1]), "10000");
+}
+
+#[test]
+fn test_write_date_and_time() {
+    let date = |days: i64| {
+        let mut text = String::new();
+        let era = write_date(&mut text, days);
+        text.push_str(era);
+        text
+    };
+
+    assert_eq!(date(0), "2000-01-01");
+    assert_eq!(date(-1), "1999-12-31");
+    assert_eq!(date(59), "2000-02-29");
+    assert_eq!(date(7_305), "2020-01-01");
+    assert_eq!(date(-730_119), "0001-01-01");
+    assert_eq!(date(-730_120), "0001-12-31 BC");
+
+    let mut text = String::new();
+    write_time(&mut text, 45_296_000_001);
+
+    assert_eq!(text, "12:34:56.000001");
+}
@@ -2,30 +2,44 @@
 
 pub use arguments::PgArguments;
 pub use buffer::PgRawBuffer;
-pub use connection::PgConnection;
-pub use cursor::PgCursor;
+pub use cancel::PgCancelToken;
+pub use connection::{PgConnection, PgQueryLog};
+pub use copy::PgCopyIn;
+pub use cursor::{PgCursor, PgResultSet};
 pub use database::Postgres;
-pub use error::PgError;
+pub use error::{PgError, PgNotice};
+pub use in_list::query_with_in;
 pub use listen::{PgListener, PgNotification};
+pub use named::PgNamedArguments;
+pub use options::{PgConnectOptions, PgSslMode};
+pub use query_builder::{PgQueryBuilder, PgSeparated};
 pub use row::PgRow;
 pub use type_info::PgTypeInfo;
-pub use value::{PgData, PgValue};
+pub use value::{PgData, PgRawValue, PgValue};
 
 mod arguments;
 mod buffer;
+mod cancel;
 mod connection;
+mod copy;
 mod cursor;
 mod database;
 mod error;
 mod executor;
+mod in_list;
 mod listen;
+mod literal;
+mod named;
+mod options;
 mod protocol;
+mod query_builder;
 mod row;
 mod sasl;
 mod stream;
 mod tls;
 mod type_info;
 pub mod types;
+mod unnest;
 mod value;
 
 /// An alias for [`Pool`][crate::pool::Pool], specialized for **Postgres**.
@@ -33,5 +47,6 @@ mod value;
 pub type PgPool = crate::pool::Pool<PgConnection>;
 
 make_query_as!(PgQueryAs, Postgres, PgRow);
+make_query_scalar!(PgQueryScalar, PgQueryAs, Postgres, PgRow);
 impl_map_row_for_row!(Postgres, PgRow);
 impl_from_row_for_tuples!(Postgres, PgRow);
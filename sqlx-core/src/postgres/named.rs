@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::arguments::Arguments;
+use crate::encode::Encode;
+use crate::postgres::{PgArguments, Postgres};
+use crate::types::Type;
+
+// Binds a value to the arguments of the rewritten query
+type BindValue<'a> = Box<dyn FnOnce(&mut PgArguments) + Send + 'a>;
+
+/// Arguments for a query with named parameters; e.g., `@id`.
+///
+/// [`into_query`](#method.into_query) replaces each named parameter in a query with a
+/// positional parameter (`$1`, `$2`, ...) and returns the rewritten query along with the
+/// arguments bound in the matching order. A name that is referenced more than once is bound
+/// only once.
+///
+/// ```rust,ignore
+/// let mut arguments = PgNamedArguments::new();
+/// arguments.add("id", 10_i32);
+///
+/// let (sql, arguments) =
+///     arguments.into_query("SELECT * FROM users WHERE id = @id OR manager_id = @id");
+///
+/// let rows = sqlx::query(&sql).bind_all(arguments).fetch(&mut conn);
+/// ```
+///
+/// String literals, quoted identifiers, comments and dollar-quoted strings are left untouched.
+/// A name that was not added is also left as-is as `@` is the absolute value operator in
+/// Postgres.
+#[derive(Default)]
+pub struct PgNamedArguments<'a> {
+    values: HashMap<String, BindValue<'a>>,
+}
+
+impl<'a> PgNamedArguments<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value for the parameter of the given name, replacing any previous value.
+    pub fn add<T>(&mut self, name: impl Into<String>, value: T)
+    where
+        T: Type<Postgres> + Encode<Postgres> + Send + 'a,
+    {
+        self.values.insert(
+            name.into(),
+            Box::new(move |arguments: &mut PgArguments| arguments.add(value)),
+        );
+    }
+
+    /// Replace the named parameters in the query with positional parameters.
+    ///
+    /// Values that are not referenced by the query are not bound.
+    pub fn into_query(mut self, query: &str) -> (String, PgArguments) {
+        let bytes = query.as_bytes();
+
+        let mut arguments = PgArguments::default();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+
+        let mut rewritten = String::with_capacity(query.len());
+        let mut copied = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if let Some(end) = skip_quoted(query, i) {
+                i = end;
+                continue;
+            }
+
+            match bytes[i] {
+                // @@ is the text search match operator
+                b'@' if bytes.get(i + 1) == Some(&b'@') => {
+                    i += 2;
+                }
+
+                b'@' if matches!(
+                    bytes.get(i + 1),
+                    Some(&b) if b.is_ascii_alphabetic() || b == b'_'
+                ) =>
+                {
+                    let start = i;
+
+                    i += 1;
+
+                    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_')
+                    {
+                        i += 1;
+                    }
+
+                    let name = &query[start + 1..i];
+
+                    let position = match positions.get(name) {
+                        Some(&position) => Some(position),
+
+                        None => self.values.remove(name).map(|add| {
+                            add(&mut arguments);
+
+                            let position = positions.len() + 1;
+                            positions.insert(name, position);
+
+                            position
+                        }),
+                    };
+
+                    if let Some(position) = position {
+                        rewritten.push_str(&query[copied..start]);
+                        rewritten.push_str(&format!("${}", position));
+                        copied = i;
+                    }
+                }
+
+                _ => {
+                    i += 1;
+                }
+            }
+        }
+
+        rewritten.push_str(&query[copied..]);
+
+        (rewritten, arguments)
+    }
+}
+
+// Whether a byte can be part of an unquoted identifier; a `$` after one of these is part of
+// the identifier (e.g., `a$1`) rather than a parameter or a dollar-quoted string
+pub(super) fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+// Returns the end of the string literal, quoted identifier, comment or dollar-quoted string
+// that starts at `i`, if any
+pub(super) fn skip_quoted(query: &str, i: usize) -> Option<usize> {
+    let bytes = query.as_bytes();
+    let find = |from: usize, pat: &str| query[from..].find(pat).map(|i| from + i);
+
+    match bytes[i] {
+        quote @ b'\'' | quote @ b'"' => {
+            // Backslash escapes are only recognized in E'' strings
+            let escapes = quote == b'\'' && i > 0 && (bytes[i - 1] == b'E' || bytes[i - 1] == b'e');
+
+            let mut end = i + 1;
+
+            while end < bytes.len() && bytes[end] != quote {
+                end += if escapes && bytes[end] == b'\\' { 2 } else { 1 };
+            }
+
+            // A doubled quote is read as two adjacent strings
+            Some((end + 1).min(bytes.len()))
+        }
+
+        b'-' if bytes.get(i + 1) == Some(&b'-') => Some(find(i, "\n").unwrap_or(bytes.len())),
+
+        b'/' if bytes.get(i + 1) == Some(&b'*') => {
+            Some(find(i + 2, "*/").map_or(bytes.len(), |end| end + 2))
+        }
+
+        // A `$` within an identifier (e.g., `a$1`) does not start a string
+        b'$' if i > 0 && is_ident(bytes[i - 1]) => None,
+
+        b'$' => {
+            // A dollar-quoted string; $tag$ ... $tag$
+            let tag_end = bytes[i + 1..]
+                .iter()
+                .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                .map(|len| i + 1 + len)
+                .filter(|&end| bytes[end] == b'$')?;
+
+            let tag = &query[i..=tag_end];
+
+            Some(find(tag_end + 1, tag).map_or(bytes.len(), |close| close + tag.len()))
+        }
+
+        _ => None,
+    }
+}
+
+#[test]
+fn test_into_query() {
+    let mut arguments = PgNamedArguments::new();
+    arguments.add("id", 1_i32);
+    arguments.add("name", "Alice");
+    arguments.add("unused", 2_i32);
+
+    let (sql, arguments) = arguments.into_query(
+        "SELECT * FROM users WHERE (id = @id OR manager_id = @id) AND name = @name::text \
+         AND title <> '@id' AND tsv @@ 'a' AND @@id > 0 -- @name",
+    );
+
+    assert_eq!(
+        sql,
+        "SELECT * FROM users WHERE (id = $1 OR manager_id = $1) AND name = $2::text \
+         AND title <> '@id' AND tsv @@ 'a' AND @@id > 0 -- @name"
+    );
+
+    assert_eq!(arguments.types.len(), 2);
+}
+
+#[test]
+fn test_into_query_leaves_unknown_names() {
+    let (sql, arguments) = PgNamedArguments::new().into_query("SELECT @x FROM t");
+
+    assert_eq!(sql, "SELECT @x FROM t");
+    assert!(arguments.types.is_empty());
+}
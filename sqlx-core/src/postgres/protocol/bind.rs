@@ -1,6 +1,7 @@
 use super::Write;
 use crate::io::BufMut;
 use crate::postgres::protocol::{StatementId, TypeFormat};
+use crate::postgres::PgRawBuffer;
 use byteorder::{ByteOrder, NetworkEndian};
 
 pub(crate) struct Bind<'a> {
@@ -18,7 +19,7 @@ pub(crate) struct Bind<'a> {
     pub(crate) formats: &'a [TypeFormat],
 
     pub(crate) values_len: i16,
-    pub(crate) values: &'a [u8],
+    pub(crate) values: &'a PgRawBuffer,
 
     /// The result-column format codes. Each must presently be zero (text) or one (binary).
     ///
@@ -29,8 +30,10 @@ pub(crate) struct Bind<'a> {
     pub(crate) result_formats: &'a [TypeFormat],
 }
 
-impl Write for Bind<'_> {
-    fn write(&self, buf: &mut Vec<u8>) {
+impl Bind<'_> {
+    // Write everything up to the values; the length of the message is computed up front from
+    // the encoded length of the values so they can be written out separately
+    pub(crate) fn write_head(&self, buf: &mut Vec<u8>) {
         buf.push(b'B');
 
         let pos = buf.len();
@@ -48,16 +51,25 @@ impl Write for Bind<'_> {
 
         buf.put_i16::<NetworkEndian>(self.values_len);
 
-        buf.extend_from_slice(self.values);
+        // Write-back the len to the beginning of this frame
+        let len = (buf.len() - pos) + self.values.encoded_len() + 2 + 2 * self.result_formats.len();
+        NetworkEndian::write_i32(&mut buf[pos..], len as i32);
+    }
 
+    // Write everything after the values
+    pub(crate) fn write_tail(&self, buf: &mut Vec<u8>) {
         buf.put_i16::<NetworkEndian>(self.result_formats.len() as i16);
 
         for &format in self.result_formats {
             buf.put_i16::<NetworkEndian>(format as i16);
         }
+    }
+}
 
-        // Write-back the len to the beginning of this frame
-        let len = buf.len() - pos;
-        NetworkEndian::write_i32(&mut buf[pos..], len as i32);
+impl Write for Bind<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        self.write_head(buf);
+        self.values.write_to(buf);
+        self.write_tail(buf);
     }
 }
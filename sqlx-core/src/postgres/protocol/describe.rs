@@ -50,7 +50,7 @@ mod test {
     #[test]
     fn it_writes_describe_statement() {
         let mut buf = Vec::new();
-        let m = Describe::Statement(StatementId(1));
+        let m = Describe::Statement(StatementId::Id(1));
 
         m.write(&mut buf);
 
@@ -0,0 +1,13 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+pub struct Flush;
+
+impl Write for Flush {
+    #[inline]
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'H');
+        buf.put_i32::<NetworkEndian>(4);
+    }
+}
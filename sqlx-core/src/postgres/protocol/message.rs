@@ -9,6 +9,7 @@ pub enum Message {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyInResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -37,6 +38,7 @@ impl TryFrom<u8> for Message {
             b'R' => Message::Authentication,
             b'K' => Message::BackendKeyData,
             b'C' => Message::CommandComplete,
+            b'G' => Message::CopyInResponse,
             b'A' => Message::NotificationResponse,
             b'1' => Message::ParseComplete,
             b'2' => Message::BindComplete,
@@ -0,0 +1,35 @@
+use crate::io::Buf;
+use crate::postgres::database::Postgres;
+
+#[derive(Debug)]
+pub(crate) struct ParameterStatus<'c> {
+    /// The name of the run-time parameter being reported.
+    pub(crate) name: &'c str,
+
+    /// The current value of the parameter.
+    pub(crate) value: &'c str,
+}
+
+impl<'c> ParameterStatus<'c> {
+    pub(crate) fn read(mut buf: &'c [u8]) -> crate::Result<Self> {
+        let name = buf.get_str_nul()?;
+        let value = buf.get_str_nul()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParameterStatus;
+
+    const PARAMETER_STATUS: &[u8] = b"TimeZone\0Europe/Berlin\0";
+
+    #[test]
+    fn it_decodes_parameter_status() {
+        let message = ParameterStatus::read(PARAMETER_STATUS).unwrap();
+
+        assert_eq!(message.name, "TimeZone");
+        assert_eq!(message.value, "Europe/Berlin");
+    }
+}
@@ -22,6 +22,19 @@ impl Severity {
             _ => false,
         }
     }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Severity::Panic => "PANIC",
+            Severity::Fatal => "FATAL",
+            Severity::Error => "ERROR",
+            Severity::Warning => "WARNING",
+            Severity::Notice => "NOTICE",
+            Severity::Debug => "DEBUG",
+            Severity::Info => "INFO",
+            Severity::Log => "LOG",
+        }
+    }
 }
 
 impl FromStr for Severity {
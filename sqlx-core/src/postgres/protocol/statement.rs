@@ -1,17 +1,68 @@
+use std::fmt::Write as _;
 use std::io::Write as _;
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
 
 use crate::io::BufMut;
 use crate::postgres::protocol::Write;
 
-#[derive(Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
-pub struct StatementId(pub u32);
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum StatementId {
+    // Named `__sqlx_statement_<id>`; 0 selects the unnamed statement
+    Id(u32),
+
+    // Named by a prefix and a hash of the SQL of the statement so the name is the same on
+    // every connection; see [PgConnectOptions::statement_name_prefix]
+    Named(Arc<str>),
+}
+
+impl StatementId {
+    // The first 8 bytes of the SHA-256 of the SQL, in hex, after the prefix
+    pub(crate) fn named(prefix: &str, query: &str) -> Self {
+        let hash = Sha256::digest(query.as_bytes());
+        let mut name = String::with_capacity(prefix.len() + 16);
+
+        name.push_str(prefix);
+
+        for byte in &hash[..8] {
+            let _ = write!(name, "{:02x}", byte);
+        }
+
+        StatementId::Named(name.into())
+    }
+}
 
 impl Write for StatementId {
     fn write(&self, buf: &mut Vec<u8>) {
-        if self.0 != 0 {
-            let _ = write!(buf, "__sqlx_statement_{}\0", self.0);
-        } else {
-            buf.put_str_nul("");
+        match self {
+            StatementId::Id(0) => buf.put_str_nul(""),
+
+            StatementId::Id(id) => {
+                let _ = write!(buf, "__sqlx_statement_{}\0", id);
+            }
+
+            StatementId::Named(name) => buf.put_str_nul(name),
         }
     }
 }
+
+#[test]
+fn test_write_statement_id() {
+    let mut buf = Vec::new();
+
+    StatementId::Id(0).write(&mut buf);
+    StatementId::Id(12).write(&mut buf);
+
+    assert_eq!(buf, b"\0__sqlx_statement_12\0");
+
+    // SHA-256 of "SELECT 1" is e004ebd5b5532a4b85984a62f8ad48a81aa3460c1ca07701f386135d72cdecf5
+    let id = StatementId::named("app_", "SELECT 1");
+    let mut buf = Vec::new();
+
+    id.write(&mut buf);
+
+    assert_eq!(buf, b"app_e004ebd5b5532a4b\0");
+    assert!(id == StatementId::named("app_", "SELECT 1"));
+    assert!(id != StatementId::named("app_", "SELECT 2"));
+}
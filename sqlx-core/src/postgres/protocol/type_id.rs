@@ -24,11 +24,17 @@ impl TypeId {
 
     pub(crate) const OID: TypeId = TypeId(26);
 
+    // One-dimensional arrays with a lower bound of zero, as used in the system catalogs
+    pub(crate) const INT2VECTOR: TypeId = TypeId(22);
+    pub(crate) const OIDVECTOR: TypeId = TypeId(30);
+
     pub(crate) const FLOAT4: TypeId = TypeId(700);
     pub(crate) const FLOAT8: TypeId = TypeId(701);
 
     pub(crate) const NUMERIC: TypeId = TypeId(1700);
 
+    pub(crate) const MONEY: TypeId = TypeId(790);
+
     pub(crate) const TEXT: TypeId = TypeId(25);
     pub(crate) const VARCHAR: TypeId = TypeId(1043);
     pub(crate) const BPCHAR: TypeId = TypeId(1042);
@@ -37,8 +43,10 @@ impl TypeId {
 
     pub(crate) const DATE: TypeId = TypeId(1082);
     pub(crate) const TIME: TypeId = TypeId(1083);
+    pub(crate) const TIMETZ: TypeId = TypeId(1266);
     pub(crate) const TIMESTAMP: TypeId = TypeId(1114);
     pub(crate) const TIMESTAMPTZ: TypeId = TypeId(1184);
+    pub(crate) const INTERVAL: TypeId = TypeId(1186);
 
     pub(crate) const BYTEA: TypeId = TypeId(17);
 
@@ -47,6 +55,31 @@ impl TypeId {
     pub(crate) const CIDR: TypeId = TypeId(650);
     pub(crate) const INET: TypeId = TypeId(869);
 
+    pub(crate) const MACADDR: TypeId = TypeId(829);
+    pub(crate) const MACADDR8: TypeId = TypeId(774);
+
+    pub(crate) const PG_LSN: TypeId = TypeId(3220);
+
+    pub(crate) const TID: TypeId = TypeId(27);
+
+    pub(crate) const ACLITEM: TypeId = TypeId(1033);
+
+    pub(crate) const TSVECTOR: TypeId = TypeId(3614);
+    pub(crate) const TSQUERY: TypeId = TypeId(3615);
+
+    // Object identifier aliases; an OID that is displayed as the name of the object
+
+    pub(crate) const REGPROC: TypeId = TypeId(24);
+    pub(crate) const REGPROCEDURE: TypeId = TypeId(2202);
+    pub(crate) const REGOPER: TypeId = TypeId(2203);
+    pub(crate) const REGOPERATOR: TypeId = TypeId(2204);
+    pub(crate) const REGCLASS: TypeId = TypeId(2205);
+    pub(crate) const REGTYPE: TypeId = TypeId(2206);
+    pub(crate) const REGCONFIG: TypeId = TypeId(3734);
+    pub(crate) const REGDICTIONARY: TypeId = TypeId(3769);
+    pub(crate) const REGNAMESPACE: TypeId = TypeId(4089);
+    pub(crate) const REGROLE: TypeId = TypeId(4096);
+
     // Arrays
 
     pub(crate) const ARRAY_BOOL: TypeId = TypeId(1000);
@@ -69,10 +102,14 @@ impl TypeId {
 
     pub(crate) const ARRAY_NUMERIC: TypeId = TypeId(1231);
 
+    pub(crate) const ARRAY_MONEY: TypeId = TypeId(791);
+
     pub(crate) const ARRAY_DATE: TypeId = TypeId(1182);
     pub(crate) const ARRAY_TIME: TypeId = TypeId(1183);
+    pub(crate) const ARRAY_TIMETZ: TypeId = TypeId(1270);
     pub(crate) const ARRAY_TIMESTAMP: TypeId = TypeId(1115);
     pub(crate) const ARRAY_TIMESTAMPTZ: TypeId = TypeId(1185);
+    pub(crate) const ARRAY_INTERVAL: TypeId = TypeId(1187);
 
     pub(crate) const ARRAY_BYTEA: TypeId = TypeId(1001);
 
@@ -81,10 +118,45 @@ impl TypeId {
     pub(crate) const ARRAY_CIDR: TypeId = TypeId(651);
     pub(crate) const ARRAY_INET: TypeId = TypeId(1041);
 
+    pub(crate) const ARRAY_MACADDR: TypeId = TypeId(1040);
+    pub(crate) const ARRAY_MACADDR8: TypeId = TypeId(775);
+
+    pub(crate) const ARRAY_PG_LSN: TypeId = TypeId(3221);
+
+    pub(crate) const ARRAY_TID: TypeId = TypeId(1010);
+
+    pub(crate) const ARRAY_ACLITEM: TypeId = TypeId(1034);
+
+    pub(crate) const ARRAY_TSVECTOR: TypeId = TypeId(3643);
+    pub(crate) const ARRAY_TSQUERY: TypeId = TypeId(3645);
+
+    // Geometric types
+
+    pub(crate) const POINT: TypeId = TypeId(600);
+    pub(crate) const LSEG: TypeId = TypeId(601);
+    pub(crate) const PATH: TypeId = TypeId(602);
+    pub(crate) const POLYGON: TypeId = TypeId(604);
+    pub(crate) const LINE: TypeId = TypeId(628);
+
+    pub(crate) const ARRAY_POINT: TypeId = TypeId(1017);
+    pub(crate) const ARRAY_LSEG: TypeId = TypeId(1018);
+    pub(crate) const ARRAY_PATH: TypeId = TypeId(1019);
+    pub(crate) const ARRAY_POLYGON: TypeId = TypeId(1027);
+    pub(crate) const ARRAY_LINE: TypeId = TypeId(629);
+
     // JSON
 
     pub(crate) const JSON: TypeId = TypeId(114);
     pub(crate) const JSONB: TypeId = TypeId(3802);
+    pub(crate) const JSONPATH: TypeId = TypeId(4072);
+
+    pub(crate) const ARRAY_JSONPATH: TypeId = TypeId(4073);
+
+    // XML
+
+    pub(crate) const XML: TypeId = TypeId(142);
+
+    pub(crate) const ARRAY_XML: TypeId = TypeId(143);
 
     // Records
 
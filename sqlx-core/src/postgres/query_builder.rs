@@ -0,0 +1,295 @@
+use std::fmt::{Display, Write};
+
+use crate::arguments::Arguments;
+use crate::encode::Encode;
+use crate::postgres::{PgArguments, Postgres};
+use crate::query::{query, Query};
+use crate::query_as::{query_as, QueryAs};
+use crate::types::Type;
+
+/// A builder of a query whose SQL is assembled at runtime; e.g., with a filter for each of
+/// the options that were chosen by a user.
+///
+/// Fragments of SQL are appended to the query with [`push`](#method.push) and values are
+/// bound with [`push_bind`](#method.push_bind), which appends the next positional parameter
+/// (`$1`, `$2`, ...) in place of the value.
+///
+/// ```rust,ignore
+/// let mut builder = PgQueryBuilder::new("SELECT * FROM users WHERE true");
+///
+/// if let Some(name) = name {
+///     builder.push(" AND name = ").push_bind(name);
+/// }
+///
+/// if let Some(team_id) = team_id {
+///     builder.push(" AND team_id = ").push_bind(team_id);
+/// }
+///
+/// let users = builder.build().fetch_all(&mut conn).await?;
+/// ```
+///
+/// Only SQL that is written in the program should be pushed with `push`; the fragments are not
+/// escaped in any way. Any value that comes from outside the program, such as the input of a
+/// user, must be bound with `push_bind` instead.
+pub struct PgQueryBuilder {
+    query: String,
+    arguments: Option<PgArguments>,
+}
+
+impl PgQueryBuilder {
+    /// Start a query with the given SQL; e.g., `SELECT * FROM users`.
+    pub fn new(init: impl Into<String>) -> Self {
+        Self {
+            query: init.into(),
+            arguments: Some(PgArguments::default()),
+        }
+    }
+
+    /// Append a fragment of SQL to the query, as-is.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        self.assert_not_built();
+
+        write!(self.query, "{}", sql).expect("writing to a String can not fail");
+
+        self
+    }
+
+    /// Bind a value and append the positional parameter of the value to the query; e.g., `$1`.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres> + Encode<Postgres>,
+    {
+        self.assert_not_built();
+
+        let arguments = self.arguments.as_mut().unwrap();
+        arguments.add(value);
+
+        let position = arguments.types.len();
+
+        self.push(format_args!("${}", position))
+    }
+
+    /// Start a list of items that are separated by `separator`; e.g., the conditions of a
+    /// `WHERE` clause separated by `" AND "`.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = PgQueryBuilder::new("UPDATE users SET ");
+    /// let mut assignments = builder.separated(", ");
+    ///
+    /// assignments.push("name = ").push_bind_unseparated(name);
+    /// assignments.push("email = ").push_bind_unseparated(email);
+    ///
+    /// builder.push(" WHERE id = ").push_bind(id);
+    /// ```
+    pub fn separated(&mut self, separator: &'static str) -> PgSeparated<'_> {
+        PgSeparated {
+            builder: self,
+            separator,
+            is_first: true,
+        }
+    }
+
+    /// Append a `VALUES` clause with a row for each of `rows`; e.g., for an `INSERT` of many
+    /// rows at once. The values of each row are pushed by `push_row`, separated by commas.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = PgQueryBuilder::new("INSERT INTO users (name, email) ");
+    ///
+    /// builder.push_values(&users, |mut row, user| {
+    ///     row.push_bind(&user.name).push_bind(&user.email);
+    /// });
+    ///
+    /// // INSERT INTO users (name, email) VALUES ($1, $2), ($3, $4), ...
+    /// builder.build().execute(&mut conn).await?;
+    /// ```
+    ///
+    /// Postgres accepts at most 65535 parameters in a query, which limits the number of rows
+    /// that can be inserted at once. An empty `rows` results in an invalid query.
+    pub fn push_values<I, F>(&mut self, rows: I, mut push_row: F) -> &mut Self
+    where
+        I: IntoIterator,
+        F: FnMut(PgSeparated<'_>, I::Item),
+    {
+        self.push("VALUES ");
+
+        for (index, row) in rows.into_iter().enumerate() {
+            if index > 0 {
+                self.push(", ");
+            }
+
+            self.push("(");
+            push_row(self.separated(", "), row);
+            self.push(")");
+        }
+
+        self
+    }
+
+    /// The SQL of the query as it has been built so far.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Take the arguments that were bound and return the query, ready to be executed.
+    ///
+    /// # Panics
+    /// If the query was already built; call [`reset`](#method.reset) to build another query
+    /// with this builder.
+    pub fn build(&mut self) -> Query<'_, Postgres> {
+        self.assert_not_built();
+
+        let arguments = self.arguments.take().unwrap();
+
+        query(&self.query).bind_all(arguments)
+    }
+
+    /// Take the arguments that were bound and return the query, mapping each row to `O` with
+    /// [`FromRow`]; see [`query_as`].
+    ///
+    /// # Panics
+    /// If the query was already built; call [`reset`](#method.reset) to build another query
+    /// with this builder.
+    ///
+    /// [`FromRow`]: crate::row::FromRow
+    /// [`query_as`]: crate::query_as::query_as
+    pub fn build_query_as<O>(&mut self) -> QueryAs<'_, Postgres, O> {
+        self.assert_not_built();
+
+        let mut query = query_as(&self.query);
+        query.arguments = self.arguments.take().unwrap();
+
+        query
+    }
+
+    /// Clear the SQL and arguments of the query so that this builder can be used to build
+    /// another query, keeping the memory that was allocated for the SQL.
+    pub fn reset(&mut self) -> &mut Self {
+        self.query.clear();
+        self.arguments = Some(PgArguments::default());
+
+        self
+    }
+
+    fn assert_not_built(&self) {
+        assert!(
+            self.arguments.is_some(),
+            "the query was already built; call `reset` to build another query"
+        );
+    }
+}
+
+/// A list of items of a [`PgQueryBuilder`] that are separated by a separator; returned by
+/// [`PgQueryBuilder::separated`].
+///
+/// [`PgQueryBuilder`]: struct.PgQueryBuilder.html
+/// [`PgQueryBuilder::separated`]: struct.PgQueryBuilder.html#method.separated
+pub struct PgSeparated<'a> {
+    builder: &'a mut PgQueryBuilder,
+    separator: &'static str,
+    is_first: bool,
+}
+
+impl PgSeparated<'_> {
+    /// Append the separator (unless this is the first item) and a fragment of SQL.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        self.push_separator();
+        self.builder.push(sql);
+
+        self
+    }
+
+    /// Append a fragment of SQL to the current item, without a separator.
+    pub fn push_unseparated(&mut self, sql: impl Display) -> &mut Self {
+        self.builder.push(sql);
+
+        self
+    }
+
+    /// Append the separator (unless this is the first item) and bind a value.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres> + Encode<Postgres>,
+    {
+        self.push_separator();
+        self.builder.push_bind(value);
+
+        self
+    }
+
+    /// Bind a value as part of the current item, without a separator; e.g., after
+    /// `push("name = ")`.
+    pub fn push_bind_unseparated<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres> + Encode<Postgres>,
+    {
+        self.builder.push_bind(value);
+
+        self
+    }
+
+    fn push_separator(&mut self) {
+        if !self.is_first {
+            self.builder.push(self.separator);
+        }
+
+        self.is_first = false;
+    }
+}
+
+#[test]
+fn test_push_bind() {
+    let mut builder = PgQueryBuilder::new("SELECT * FROM users WHERE true");
+
+    builder.push(" AND name = ").push_bind("alice");
+    builder.push(" AND team_id = ").push_bind(10_i32);
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT * FROM users WHERE true AND name = $1 AND team_id = $2"
+    );
+
+    let query = builder.build();
+
+    assert_eq!(query.arguments.types.len(), 2);
+}
+
+#[test]
+fn test_separated() {
+    let mut builder = PgQueryBuilder::new("SELECT * FROM users WHERE ");
+
+    let mut conditions = builder.separated(" AND ");
+    conditions.push("name = ").push_bind_unseparated("alice");
+    conditions.push("active");
+
+    builder.push(" AND team_id IN (");
+    builder.separated(", ").push_bind(1_i32).push_bind(2_i32);
+    builder.push(")");
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT * FROM users WHERE name = $1 AND active AND team_id IN ($2, $3)"
+    );
+}
+
+#[test]
+fn test_push_values() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+
+    builder.push_values(vec![(1_i32, "alice"), (2, "bob")], |mut row, (id, name)| {
+        row.push_bind(id).push_bind(name);
+    });
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO users (id, name) VALUES ($1, $2), ($3, $4)"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_build_twice() {
+    let mut builder = PgQueryBuilder::new("SELECT 1");
+
+    let _ = builder.build();
+    let _ = builder.build();
+}
@@ -4,13 +4,14 @@ use std::sync::Arc;
 use crate::postgres::protocol::{DataRow, TypeFormat};
 use crate::postgres::value::PgValue;
 use crate::postgres::{PgTypeInfo, Postgres};
-use crate::row::{ColumnIndex, Row};
+use crate::row::{CaseInsensitive, ColumnIndex, Row};
 
 // A statement has 0 or more columns being returned from the database
 // For Postgres, each column has an OID and a format (binary or text)
 // For simple (unprepared) queries, format will always be text
 // For prepared queries, format will _almost_ always be binary
 pub(crate) struct Column {
+    pub(crate) name: Option<Box<str>>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: TypeFormat,
 }
@@ -32,6 +33,9 @@ pub struct PgRow<'c> {
     // shared reference to the statement this row is coming from
     // allows us to get the column information on demand
     pub(super) statement: Arc<Statement>,
+
+    // offset from UTC of the time zone of the session when the row was received
+    pub(super) time_zone_offset: Option<i32>,
 }
 
 impl crate::row::private_row::Sealed for PgRow<'_> {}
@@ -44,6 +48,10 @@ impl<'c> Row<'c> for PgRow<'c> {
         self.data.len()
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.statement.columns.get(index)?.name.as_deref()
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<PgValue<'c>>
     where
@@ -58,7 +66,7 @@ impl<'c> Row<'c> for PgRow<'c> {
             (TypeFormat::Text, Some(buf)) => PgValue::utf8(column.type_info.clone(), buf)?,
         };
 
-        Ok(value)
+        Ok(value.with_time_zone_offset(self.time_zone_offset))
     }
 }
 
@@ -83,3 +91,77 @@ impl<'c> ColumnIndex<'c, PgRow<'c>> for str {
             .map(|&index| index as usize)
     }
 }
+
+impl<'c> ColumnIndex<'c, PgRow<'c>> for CaseInsensitive<'_> {
+    fn index(&self, row: &PgRow<'c>) -> crate::Result<usize> {
+        self.find(&row.statement.names)
+    }
+}
+
+#[cfg(feature = "bench")]
+fn bench_row_buffer() -> Vec<u8> {
+    // 8 non-NULL INT4 values
+    let mut buffer = 8_u16.to_be_bytes().to_vec();
+
+    for value in 0..8_i32 {
+        buffer.extend_from_slice(&4_i32.to_be_bytes());
+        buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    buffer
+}
+
+#[cfg(feature = "bench")]
+fn bench_row_statement() -> Arc<Statement> {
+    use crate::postgres::protocol::TypeId;
+
+    Arc::new(Statement {
+        names: HashMap::new(),
+        columns: (0..8)
+            .map(|_| Column {
+                name: None,
+                type_info: PgTypeInfo::new(TypeId::INT4, "INT4"),
+                format: TypeFormat::Binary,
+            })
+            .collect(),
+    })
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_try_get(b: &mut test::Bencher) {
+    let buffer = bench_row_buffer();
+    let mut values = Vec::with_capacity(8);
+    let row = PgRow {
+        data: DataRow::read(&buffer, &mut values).unwrap(),
+        statement: bench_row_statement(),
+        time_zone_offset: None,
+    };
+
+    b.iter(|| {
+        for index in 0..8 {
+            assert_eq!(row.try_get::<i32, _>(index).unwrap(), index as i32);
+        }
+    });
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_try_get_unchecked(b: &mut test::Bencher) {
+    let buffer = bench_row_buffer();
+    let mut values = Vec::with_capacity(8);
+    let row = PgRow {
+        data: DataRow::read(&buffer, &mut values).unwrap(),
+        statement: bench_row_statement(),
+        time_zone_offset: None,
+    };
+
+    b.iter(|| {
+        for index in 0..8 {
+            assert_eq!(
+                row.try_get_unchecked::<i32, _>(index).unwrap(),
+                index as i32
+            );
+        }
+    });
+}
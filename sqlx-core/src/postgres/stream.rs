@@ -1,34 +1,58 @@
 use std::convert::TryInto;
 use std::net::Shutdown;
+use std::time::Instant;
 
 use byteorder::NetworkEndian;
 use futures_channel::mpsc::UnboundedSender;
 
 use crate::io::{Buf, BufStream, MaybeTlsStream};
-use crate::postgres::protocol::{Message, NotificationResponse, Response, Write};
-use crate::postgres::PgError;
+use crate::postgres::protocol::{
+    Message, NotificationResponse, ParameterStatus, Response, Severity, Write,
+};
+use crate::postgres::{PgConnectOptions, PgError, PgNotice};
 
-use crate::url::Url;
 use futures_util::SinkExt;
 
+// Hook invoked with each notice (a non-error response) from postgres
+type NoticeHook = Box<dyn Fn(&PgNotice) + Send + Sync>;
+
 pub struct PgStream {
     pub(super) stream: BufStream<MaybeTlsStream>,
     pub(super) notifications: Option<UnboundedSender<NotificationResponse<'static>>>,
 
+    // Hook invoked with each notice (a non-error response) from postgres
+    pub(super) on_notice: Option<NoticeHook>,
+
+    // Offset from UTC, in seconds, of the `TimeZone` of the session
+    // This is `None` if the time zone is not a fixed offset (e.g., America/New_York)
+    pub(super) time_zone_offset: Option<i32>,
+
     // Most recently received message
     // Is referenced by our buffered stream
     // Is initialized to ReadyForQuery/0 at the start
     pub(super) message: (Message, u32),
+
+    // When the most recently received message arrived; that is, when the data it ends in
+    // was read from the connection
+    pub(super) received_at: Instant,
+
+    // If the connection can not be used any further; e.g., after an I/O error or a FATAL error
+    pub(super) is_broken: bool,
 }
 
 impl PgStream {
-    pub(super) async fn new(url: &Url) -> crate::Result<Self> {
-        let stream = MaybeTlsStream::connect(&url, 5432).await?;
+    pub(super) async fn new(options: &PgConnectOptions) -> crate::Result<Self> {
+        let stream = MaybeTlsStream::connect_to(&options.host, options.port).await?;
 
         Ok(Self {
             notifications: None,
+            on_notice: None,
+            // the session starts in UTC; see [startup]
+            time_zone_offset: Some(0),
             stream: BufStream::new(stream),
             message: (Message::ReadyForQuery, 0),
+            received_at: Instant::now(),
+            is_broken: false,
         })
     }
 
@@ -46,10 +70,30 @@ impl PgStream {
 
     #[inline]
     pub(super) async fn flush(&mut self) -> crate::Result<()> {
-        Ok(self.stream.flush().await?)
+        if let Err(error) = self.stream.flush().await {
+            self.is_broken = true;
+
+            return Err(error.into());
+        }
+
+        Ok(())
     }
 
     pub(super) async fn read(&mut self) -> crate::Result<Message> {
+        let message = self.read_message().await;
+
+        // After an I/O error or a message that can not be understood, the position of the next
+        // message in the stream is not known. An error reported by postgres (an ErrorResponse)
+        // is a message like any other and only breaks the connection if it is FATAL; see
+        // [PgStream::receive]
+        if let Err(crate::Error::Io(_)) | Err(crate::Error::Protocol(_)) = message {
+            self.is_broken = true;
+        }
+
+        message
+    }
+
+    async fn read_message(&mut self) -> crate::Result<Message> {
         // https://www.postgresql.org/docs/12/protocol-overview.html#PROTOCOL-MESSAGE-CONCEPTS
 
         // All communication is through a stream of messages. The first byte of a message
@@ -62,6 +106,8 @@ impl PgStream {
             self.stream.consume(self.message.1 as usize);
         }
 
+        let mut is_buffered = self.stream.available() > 4;
+
         let mut header = self.stream.peek(4 + 1).await?;
 
         let type_ = header.get_u8()?.try_into()?;
@@ -70,10 +116,17 @@ impl PgStream {
         self.message = (type_, length);
         self.stream.consume(4 + 1);
 
+        is_buffered &= self.stream.available() >= length as usize;
+
         // Wait until there is enough data in the stream. We then return without actually
         // inspecting the data. This is then looked at later through the [buffer] function
         let _ = self.stream.peek(length as usize).await?;
 
+        // A message that was already read along with a previous one arrived with it
+        if !is_buffered {
+            self.received_at = Instant::now();
+        }
+
         Ok(type_)
     }
 
@@ -86,12 +139,31 @@ impl PgStream {
                     let response = Response::read(self.stream.buffer())?;
 
                     if response.severity.is_error() {
+                        // The server closes the connection after a FATAL or PANIC error
+                        if let Severity::Fatal | Severity::Panic = response.severity {
+                            self.is_broken = true;
+                        }
+
                         // This is an error, bubble up as one immediately
                         return Err(crate::Error::Database(Box::new(PgError(response))));
                     }
 
-                    // TODO: Provide some way of receiving these non-critical
-                    //       notices from postgres
+                    if let Some(hook) = &self.on_notice {
+                        hook(&PgNotice(response));
+                    }
+
+                    continue;
+                }
+
+                Message::ParameterStatus => {
+                    // Postgres reports the value of some parameters on startup and after
+                    // each change; we only track the time zone
+                    let status = ParameterStatus::read(self.stream.buffer())?;
+
+                    if status.name == "TimeZone" {
+                        self.time_zone_offset = parse_time_zone_offset(status.value);
+                    }
+
                     continue;
                 }
 
@@ -120,3 +192,79 @@ impl PgStream {
         &self.stream.buffer()[..(self.message.1 as usize)]
     }
 }
+
+// Parse the offset from UTC, in seconds, of the value of the `TimeZone` parameter
+// Only time zones with a fixed offset are understood; e.g., UTC, Etc/GMT-5, or the
+// POSIX-style <+05:30>-05:30 that is reported after `SET TIME ZONE INTERVAL '+05:30'`
+fn parse_time_zone_offset(tz: &str) -> Option<i32> {
+    let utc = [
+        "UTC",
+        "UCT",
+        "GMT",
+        "Z",
+        "Zulu",
+        "Universal",
+        "Greenwich",
+        "Etc/UTC",
+        "Etc/UCT",
+        "Etc/GMT",
+        "Etc/Zulu",
+        "Etc/Universal",
+        "Etc/Greenwich",
+    ];
+
+    if utc.iter().any(|name| name.eq_ignore_ascii_case(tz)) {
+        return Some(0);
+    }
+
+    // The sign of a POSIX offset is the opposite of ISO 8601; Etc/GMT+5 is 5 hours behind UTC
+    let posix = if let Some(posix) = tz.strip_prefix("Etc/GMT") {
+        posix
+    } else if tz.starts_with('<') {
+        &tz[tz.find('>')? + 1..]
+    } else {
+        return None;
+    };
+
+    let (sign, hms) = match posix.as_bytes().first()? {
+        b'+' => (-1, &posix[1..]),
+        b'-' => (1, &posix[1..]),
+        _ => (-1, posix),
+    };
+
+    let parts: Vec<&str> = hms.split(':').collect();
+
+    if parts.len() > 3 {
+        return None;
+    }
+
+    let mut seconds = 0;
+
+    for (part, unit) in parts.iter().zip(&[3600, 60, 1]) {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            // anything else (e.g., a rule for daylight saving time) is not a fixed offset
+            return None;
+        }
+
+        seconds += part.parse::<i32>().ok()? * unit;
+    }
+
+    Some(sign * seconds)
+}
+
+#[test]
+fn test_parse_time_zone_offset() {
+    assert_eq!(parse_time_zone_offset("UTC"), Some(0));
+    assert_eq!(parse_time_zone_offset("etc/utc"), Some(0));
+    assert_eq!(parse_time_zone_offset("Etc/GMT-5"), Some(5 * 3600));
+    assert_eq!(parse_time_zone_offset("Etc/GMT+10"), Some(-10 * 3600));
+    assert_eq!(
+        parse_time_zone_offset("<+05:30>-05:30"),
+        Some(5 * 3600 + 30 * 60)
+    );
+    assert_eq!(parse_time_zone_offset("<-07>+07"), Some(-7 * 3600));
+
+    assert_eq!(parse_time_zone_offset("America/New_York"), None);
+    assert_eq!(parse_time_zone_offset("<+01>-01<+02>,M3.5.0,M10.5.0"), None);
+    assert_eq!(parse_time_zone_offset("<+01>"), None);
+}
@@ -1,4 +1,5 @@
-use crate::postgres::protocol::TypeId;
+use crate::postgres::protocol::{TypeFormat, TypeId};
+use crate::postgres::types::{resolve_type_format, try_resolve_type_name, try_resolve_type_oid};
 use crate::types::TypeInfo;
 use std::borrow::Borrow;
 use std::fmt;
@@ -11,6 +12,12 @@ use std::sync::Arc;
 pub struct PgTypeInfo {
     pub(crate) id: Option<TypeId>,
     pub(crate) name: SharedStr,
+
+    // The format values of this type are transferred in; most types use binary
+    pub(crate) format: TypeFormat,
+
+    // If a bind parameter of this type is replaced by the DEFAULT keyword in the query
+    pub(crate) is_default: bool,
 }
 
 impl PgTypeInfo {
@@ -18,6 +25,8 @@ impl PgTypeInfo {
         Self {
             id: Some(id),
             name: name.into(),
+            format: resolve_type_format(id),
+            is_default: false,
         }
     }
 
@@ -29,9 +38,56 @@ impl PgTypeInfo {
         Self {
             id: None,
             name: SharedStr::Static(name),
+            format: TypeFormat::Binary,
+            is_default: false,
+        }
+    }
+
+    // Type information of [PgDefault]
+    // The parameter is sent as an (unused) NULL of TEXT
+    pub(crate) fn default_keyword() -> Self {
+        Self {
+            is_default: true,
+            ..Self::new(TypeId::TEXT, "DEFAULT")
+        }
+    }
+
+    /// Returns the OID of this type, if it is known.
+    ///
+    /// The OID of a type created with [with_name](#method.with_name) is not known
+    /// until it has been fetched from Postgres.
+    pub fn oid(&self) -> Option<u32> {
+        self.id.map(|id| id.0)
+    }
+
+    /// Declare that values of this type are sent to Postgres in the text format.
+    ///
+    /// Values are transferred in the binary format by default. A `Type` implementation for
+    /// a type that only has a text representation should return its type information
+    /// through this so that bind parameters are flagged correctly and `Encode` can write
+    /// the text form of the value.
+    pub fn with_text_format(self) -> Self {
+        Self {
+            format: TypeFormat::Text,
+            ..self
         }
     }
 
+    // Used by `#[derive(Type)]` to resolve `#[sqlx(compatible = "..")]`
+    #[doc(hidden)]
+    pub fn __resolve_builtin_oid(name: &str) -> Option<u32> {
+        try_resolve_type_oid(name)
+    }
+
+    // Used by `#[derive(Type)]` with the OID that was resolved for `#[sqlx(compatible = "..")]`
+    #[doc(hidden)]
+    pub fn __with_builtin_oid(oid: u32) -> Self {
+        let id = TypeId(oid);
+        let name = try_resolve_type_name(oid).expect("(bug) OID of a type that is not builtin");
+
+        Self::new(id, name)
+    }
+
     #[doc(hidden)]
     pub fn type_feature_gate(&self) -> Option<&'static str> {
         match self.id? {
@@ -70,20 +126,27 @@ impl TypeInfo for PgTypeInfo {
                 | (TypeId::ARRAY_CIDR, TypeId::ARRAY_INET)
                 | (TypeId::ARRAY_INET, TypeId::ARRAY_CIDR) => true,
 
+                (TypeId::INT2VECTOR, TypeId::ARRAY_INT2)
+                | (TypeId::ARRAY_INT2, TypeId::INT2VECTOR)
+                | (TypeId::OIDVECTOR, TypeId::ARRAY_OID)
+                | (TypeId::ARRAY_OID, TypeId::OIDVECTOR) => true,
+
                 // the following text-like types are compatible
                 (TypeId::VARCHAR, other)
                 | (TypeId::TEXT, other)
                 | (TypeId::BPCHAR, other)
                 | (TypeId::NAME, other)
                 | (TypeId::UNKNOWN, other)
-                    if match other {
+                | (TypeId::ACLITEM, other)
+                    if matches!(
+                        other,
                         TypeId::VARCHAR
-                        | TypeId::TEXT
-                        | TypeId::BPCHAR
-                        | TypeId::NAME
-                        | TypeId::UNKNOWN => true,
-                        _ => false,
-                    } =>
+                            | TypeId::TEXT
+                            | TypeId::BPCHAR
+                            | TypeId::NAME
+                            | TypeId::UNKNOWN
+                            | TypeId::ACLITEM
+                    ) =>
                 {
                     true
                 }
@@ -93,13 +156,34 @@ impl TypeInfo for PgTypeInfo {
                 | (TypeId::ARRAY_TEXT, other)
                 | (TypeId::ARRAY_BPCHAR, other)
                 | (TypeId::ARRAY_NAME, other)
-                    if match other {
+                | (TypeId::ARRAY_ACLITEM, other)
+                    if matches!(
+                        other,
                         TypeId::ARRAY_VARCHAR
-                        | TypeId::ARRAY_TEXT
-                        | TypeId::ARRAY_BPCHAR
-                        | TypeId::ARRAY_NAME => true,
-                        _ => false,
-                    } =>
+                            | TypeId::ARRAY_TEXT
+                            | TypeId::ARRAY_BPCHAR
+                            | TypeId::ARRAY_NAME
+                            | TypeId::ARRAY_ACLITEM
+                    ) =>
+                {
+                    true
+                }
+
+                // OID <=> the object identifier aliases; e.g., REGCLASS
+                (TypeId::OID, other) | (other, TypeId::OID)
+                    if matches!(
+                        other,
+                        TypeId::REGPROC
+                            | TypeId::REGPROCEDURE
+                            | TypeId::REGOPER
+                            | TypeId::REGOPERATOR
+                            | TypeId::REGCLASS
+                            | TypeId::REGTYPE
+                            | TypeId::REGCONFIG
+                            | TypeId::REGDICTIONARY
+                            | TypeId::REGNAMESPACE
+                            | TypeId::REGROLE
+                    ) =>
                 {
                     true
                 }
@@ -132,6 +216,15 @@ impl TypeInfo for PgTypeInfo {
 
         false
     }
+
+    fn same_type(&self, other: &Self) -> bool {
+        match (self.id, other.id) {
+            (Some(self_id), Some(other_id)) => self_id.0 == other_id.0,
+
+            // Without an OID on both sides the types can only be compared by name
+            _ => !self.name.is_empty() && self.name.eq_ignore_ascii_case(&other.name),
+        }
+    }
 }
 
 /// Copy of `Cow` but for strings; clones guaranteed to be cheap.
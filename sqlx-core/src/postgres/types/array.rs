@@ -4,9 +4,39 @@ use crate::database::Database;
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::types::raw::{PgArrayDecoder, PgArrayEncoder};
-use crate::postgres::{PgRawBuffer, PgValue, Postgres};
+use crate::postgres::{PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 
+/// A type whose values can be bound and decoded as the elements of a Postgres array; e.g., as
+/// a `Vec<T>` or a `&[T]`.
+///
+/// This is implemented by `#[derive(sqlx::Type)]` for a struct of a composite type, with the
+/// array type named by a `_` before the name of the composite type (as Postgres names it);
+/// e.g., `_inventory_item` for `inventory_item`. The built-in types implement `Type` for their
+/// arrays directly.
+pub trait PgHasArrayType {
+    /// Returns the type information of an array of this type.
+    fn array_type_info() -> PgTypeInfo;
+}
+
+impl<T> Type<Postgres> for [T]
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
+impl<T> Type<Postgres> for Vec<T>
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
 impl<T> Encode<Postgres> for [T]
 where
     T: Encode<Postgres>,
@@ -21,6 +51,12 @@ where
 
         encoder.finish();
     }
+
+    fn size_hint(&self) -> usize {
+        // header (ndim, dataoffset, elemtype, dimensions, lower_bnds) and
+        // the length (int) and data of each element
+        20 + self.iter().map(|item| 4 + item.size_hint()).sum::<usize>()
+    }
 }
 
 impl<T> Encode<Postgres> for Vec<T>
@@ -31,8 +67,14 @@ where
     fn encode(&self, buf: &mut PgRawBuffer) {
         self.as_slice().encode(buf)
     }
+
+    fn size_hint(&self) -> usize {
+        self.as_slice().size_hint()
+    }
 }
 
+// An array is decoded into a `Vec` of its elements in order, whatever its lower bound;
+// e.g., '[2:4]={a,b,c}' decodes as vec!["a", "b", "c"]
 impl<'de, T> Decode<'de, Postgres> for Vec<T>
 where
     T: 'de,
@@ -45,6 +87,78 @@ where
     }
 }
 
+// Fixed-size arrays are encoded as a slice; e.g., `&["a", "b"]` can be bound to a TEXT[]
+// without collecting it into a `Vec` first. They are decoded in place and must have exactly
+// as many elements as the Postgres array
+
+macro_rules! impl_fixed_size_array {
+    ($($len:literal)+) => {
+        $(
+            impl<T> Type<Postgres> for [T; $len]
+            where
+                [T]: Type<Postgres>,
+            {
+                #[inline]
+                fn type_info() -> crate::postgres::PgTypeInfo {
+                    <[T] as Type<Postgres>>::type_info()
+                }
+            }
+
+            impl<T> Encode<Postgres> for [T; $len]
+            where
+                T: Encode<Postgres>,
+                T: Type<Postgres>,
+            {
+                fn encode(&self, buf: &mut PgRawBuffer) {
+                    self[..].encode(buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    self[..].size_hint()
+                }
+            }
+
+            impl<'de, T> Decode<'de, Postgres> for [T; $len]
+            where
+                T: 'de,
+                T: for<'arr> Decode<'arr, Postgres>,
+                T: Default,
+                [T]: Type<Postgres>,
+                T: Type<Postgres>,
+            {
+                fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+                    let mut array = <[T; $len]>::default();
+                    let mut len = 0;
+
+                    for element in PgArrayDecoder::<T>::new(value)? {
+                        let element = element?;
+
+                        if let Some(slot) = array.get_mut(len) {
+                            *slot = element;
+                        }
+
+                        len += 1;
+                    }
+
+                    if len != $len {
+                        return Err(decode_err!(
+                            "expected an array of {} elements but received {} elements",
+                            $len,
+                            len
+                        ));
+                    }
+
+                    Ok(array)
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_size_array!(
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
+
 impl<T, DB> Type<DB> for Vec<Option<T>>
 where
     DB: Database,
@@ -75,10 +75,11 @@ impl TryFrom<BigDecimal> for PgNumeric {
 
         let mut digits = Vec::with_capacity(digits_len);
 
-        if let Some(first) = base_10.get(..offset) {
-            if offset != 0 {
-                digits.push(base_10_to_10000(first));
-            }
+        if offset != 0 {
+            // there may be fewer significant digits than fit in the first digit; e.g., 0.001
+            let first = &base_10[..cmp::min(offset, base_10.len())];
+
+            digits.push(base_10_to_10000(first) * 10i16.pow((offset - first.len()) as u32));
         }
 
         if let Some(rest) = base_10.get(offset..) {
@@ -115,11 +116,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
                 weight,
                 ..
             } => (digits, sign, weight),
-            PgNumeric::NotANumber => {
-                return Err(crate::Error::Decode(
-                    "BigDecimal does not support NaN values".into(),
-                ))
-            }
+            PgNumeric::NotANumber => return Err(not_a_number()),
         };
 
         let sign = match sign {
@@ -165,10 +162,22 @@ impl Encode<Postgres> for BigDecimal {
     }
 }
 
+// BigDecimal has no equivalent of NaN
+fn not_a_number() -> crate::Error {
+    crate::Error::Decode(
+        "BigDecimal does not support NaN values; decode as PgNumeric instead".into(),
+    )
+}
+
+/// ### Note
+///
+/// `BigDecimal` cannot represent `'NaN'::numeric`; decoding it returns an error. Decode into
+/// `PgNumeric` to receive columns which may contain `NaN`.
 impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: PgValue) -> crate::Result<Self> {
         match value.try_get()? {
             PgData::Binary(binary) => PgNumeric::from_bytes(binary)?.try_into(),
+            PgData::Text("NaN") => Err(not_a_number()),
             PgData::Text(text) => text
                 .parse::<BigDecimal>()
                 .map_err(|e| crate::Error::Decode(e.into())),
@@ -299,4 +308,40 @@ fn test_bigdecimal_to_pgnumeric() {
             digits: vec![1234]
         }
     );
+
+    let short_decimal: BigDecimal = "-0.001".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(short_decimal).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            scale: 3,
+            weight: -1,
+            digits: vec![10]
+        }
+    );
+
+    let exponent: BigDecimal = "1e5".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(exponent).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 0,
+            weight: 1,
+            digits: vec![10]
+        }
+    );
+}
+
+#[test]
+fn test_decode_nan() {
+    // NUMERIC 'NaN' has no digits and the NaN sign
+    let buf = [0, 0, 0, 0, 0xC0, 0, 0, 0];
+
+    let err = <BigDecimal as Decode<Postgres>>::decode(PgValue::from_bytes(&buf)).unwrap_err();
+    assert!(err.to_string().contains("NaN"));
+
+    let err = <BigDecimal as Decode<Postgres>>::decode(PgValue::from_str("NaN")).unwrap_err();
+    assert!(err.to_string().contains("NaN"));
+
+    assert_eq!(PgNumeric::from_bytes(&buf).unwrap(), PgNumeric::NotANumber);
 }
@@ -2,7 +2,10 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::runtime::AsyncRead;
 use crate::types::Type;
+use std::fmt::{self, Debug};
+use std::sync::Mutex;
 
 impl Type<Postgres> for [u8] {
     fn type_info() -> PgTypeInfo {
@@ -18,7 +21,7 @@ impl Type<Postgres> for [&'_ [u8]] {
 
 impl Type<Postgres> for Vec<&'_ [u8]> {
     fn type_info() -> PgTypeInfo {
-        <&'_ [u8] as Type<Postgres>>::type_info()
+        <[&'_ [u8]] as Type<Postgres>>::type_info()
     }
 }
 
@@ -38,18 +41,64 @@ impl Encode<Postgres> for Vec<u8> {
     fn encode(&self, buf: &mut PgRawBuffer) {
         <[u8] as Encode<Postgres>>::encode(self, buf);
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
+/// In the text format, the BYTEA value may be in either the hex format (the default) or the
+/// escape format of `bytea_output`.
 impl<'de> Decode<'de, Postgres> for Vec<u8> {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
         match value.try_get()? {
             PgData::Binary(buf) => Ok(buf.to_vec()),
-            PgData::Text(s) => {
-                // BYTEA is formatted as \x followed by hex characters
-                hex::decode(&s[2..]).map_err(crate::Error::decode)
+            PgData::Text(s) => decode_text(s),
+        }
+    }
+}
+
+// https://www.postgresql.org/docs/12/datatype-binary.html
+fn decode_text(s: &str) -> crate::Result<Vec<u8>> {
+    // The hex format is \x followed by two hex characters for each byte
+    if let Some(hex) = s.strip_prefix("\\x") {
+        return hex::decode(hex).map_err(crate::Error::decode);
+    }
+
+    // The escape format is the bytes as-is with \\ for a backslash and \ooo, in octal, for a
+    // byte which is not printable
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            rest = tail;
+
+            continue;
+        }
+
+        match tail {
+            [b'\\', tail @ ..] => {
+                bytes.push(b'\\');
+                rest = tail;
+            }
+
+            [a @ b'0'..=b'3', b @ b'0'..=b'7', c @ b'0'..=b'7', tail @ ..] => {
+                bytes.push(((a - b'0') << 6) | ((b - b'0') << 3) | (c - b'0'));
+                rest = tail;
+            }
+
+            _ => {
+                return Err(decode_err!(
+                    "invalid escape sequence in BYTEA value {:?}",
+                    s
+                ));
             }
         }
     }
+
+    Ok(bytes)
 }
 
 impl<'de> Decode<'de, Postgres> for &'de [u8] {
@@ -64,3 +113,123 @@ impl<'de> Decode<'de, Postgres> for &'de [u8] {
         }
     }
 }
+
+#[cfg(feature = "bytes")]
+impl Type<Postgres> for bytes::Bytes {
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+}
+
+/// The data of `Bytes` is not copied into the arguments of a query; binding it only clones the
+/// reference to it. The data is copied once, into the write buffer of the connection, as the
+/// query is sent, where a `Vec<u8>` or a `&[u8]` is copied into the arguments as well.
+#[cfg(feature = "bytes")]
+impl Encode<Postgres> for bytes::Bytes {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.push_shared(self.clone());
+    }
+
+    fn size_hint(&self) -> usize {
+        // Nothing is reserved in the arguments for the value
+        0
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, Postgres> for bytes::Bytes {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => Ok(bytes::Bytes::copy_from_slice(buf)),
+            PgData::Text(_) => <Vec<u8> as Decode<Postgres>>::decode(value).map(Into::into),
+        }
+    }
+}
+
+/// A `BYTEA` value that is read from a reader as the query is sent instead of being held in
+/// memory as a whole.
+///
+/// The length of the value must be known up front as it is written before the value itself.
+/// The reader is read in chunks which are flushed to the connection one after another. If the
+/// reader fails or has fewer bytes than the given length, the query is not executed and the
+/// error is returned from it.
+///
+/// The reader is an `async_std::io::Read` or a `tokio::io::AsyncRead` depending on the runtime.
+/// It is only read once; a `PgByteStream` can not be bound to more than one query.
+///
+/// ```rust,ignore
+/// let file = File::open("image.png").await?;
+/// let len = file.metadata().await?.len() as usize;
+///
+/// sqlx::query("INSERT INTO images (data) VALUES ($1)")
+///     .bind(PgByteStream::new(file, len))
+///     .execute(&mut conn)
+///     .await?;
+/// ```
+pub struct PgByteStream {
+    len: usize,
+    reader: Mutex<Option<Box<dyn AsyncRead + Send + Unpin>>>,
+}
+
+impl PgByteStream {
+    /// Creates a value of `len` bytes that is read from `reader`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is larger than `i32::MAX`; the most that Postgres accepts for a value.
+    pub fn new<R>(reader: R, len: usize) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        assert!(
+            len <= i32::MAX as usize,
+            "a streamed BYTEA value can not be larger than i32::MAX bytes"
+        );
+
+        Self {
+            len,
+            reader: Mutex::new(Some(Box::new(reader))),
+        }
+    }
+}
+
+impl Debug for PgByteStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgByteStream")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl Type<Postgres> for PgByteStream {
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgByteStream {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        // A reader that was already taken fails the query when it is sent
+        buf.push_streamed(self.len, self.reader.lock().unwrap().take());
+    }
+
+    fn size_hint(&self) -> usize {
+        // Nothing is reserved in the arguments for the value
+        0
+    }
+}
+
+#[test]
+fn test_decode_text() {
+    assert_eq!(decode_text("\\x").unwrap(), b"");
+    assert_eq!(decode_text("\\xdeadbeef").unwrap(), b"\xde\xad\xbe\xef");
+
+    assert_eq!(decode_text("").unwrap(), b"");
+    assert_eq!(decode_text("abc").unwrap(), b"abc");
+    assert_eq!(decode_text("a\\\\b").unwrap(), b"a\\b");
+    assert_eq!(decode_text("\\000\\001\\377x").unwrap(), b"\x00\x01\xffx");
+
+    assert!(decode_text("\\xabc").is_err());
+    assert!(decode_text("\\9").is_err());
+    assert!(decode_text("abc\\").is_err());
+}
@@ -2,11 +2,14 @@ use std::convert::TryInto;
 use std::mem;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
-use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
 
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
+use crate::postgres::types::time_tz::{decode_time_tz, encode_time_tz, PgTimeTz};
 use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 use crate::Error;
@@ -206,18 +209,42 @@ impl Encode<Postgres> for NaiveDateTime {
 
 impl<'de> Decode<'de, Postgres> for DateTime<Utc> {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
-        let date_time = Decode::<Postgres>::decode(value)?;
-        Ok(DateTime::from_utc(date_time, Utc))
+        decode_timestamptz(value).map(|date_time| date_time.with_timezone(&Utc))
     }
 }
 
 impl<'de> Decode<'de, Postgres> for DateTime<Local> {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
-        let date_time = Decode::<Postgres>::decode(value)?;
-        Ok(Local.from_utc_datetime(&date_time))
+        decode_timestamptz(value).map(|date_time| date_time.with_timezone(&Local))
     }
 }
 
+impl<'de> Decode<'de, Postgres> for DateTime<FixedOffset> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        decode_timestamptz(value)
+    }
+}
+
+// A TIMESTAMPTZ is sent in UTC in the binary format, which we convert to the time zone of the
+// session if it is a fixed offset; otherwise, it is left in UTC
+// In the text format, the value is given in the time zone of the session with its offset
+fn decode_timestamptz(value: PgValue<'_>) -> crate::Result<DateTime<FixedOffset>> {
+    let offset = value
+        .time_zone_offset()
+        .and_then(FixedOffset::east_opt)
+        .unwrap_or_else(|| FixedOffset::east(0));
+
+    if let PgData::Text(s) = value.try_get()? {
+        if let Ok(date_time) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%#z") {
+            return Ok(date_time);
+        }
+    }
+
+    let date_time: NaiveDateTime = Decode::<Postgres>::decode(value)?;
+
+    Ok(offset.from_utc_datetime(&date_time))
+}
+
 impl<Tz: TimeZone> Encode<Postgres> for DateTime<Tz>
 where
     Tz::Offset: Copy,
@@ -231,6 +258,63 @@ where
     }
 }
 
+impl Type<Postgres> for PgTimeTz<NaiveTime, FixedOffset> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TIMETZ, "TIMETZ")
+    }
+}
+
+impl Type<Postgres> for [PgTimeTz<NaiveTime, FixedOffset>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TIMETZ, "TIMETZ[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTimeTz<NaiveTime, FixedOffset>> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTimeTz<NaiveTime, FixedOffset>] as Type<Postgres>>::type_info()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTimeTz<NaiveTime, FixedOffset> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let (micros, offset) = decode_time_tz(value)?;
+
+        let time = if micros >= 0 {
+            NaiveTime::from_num_seconds_from_midnight_opt(
+                (micros / 1_000_000) as u32,
+                (micros % 1_000_000 * 1_000) as u32,
+            )
+        } else {
+            None
+        };
+
+        // Postgres allows 24:00:00 which is out of range for a NaiveTime
+        let time = time
+            .ok_or_else(|| decode_err!("Postgres time out of range for NaiveTime: {:?}", micros))?;
+
+        let offset = FixedOffset::east_opt(offset).ok_or_else(|| {
+            decode_err!("Postgres offset out of range for FixedOffset: {:?}", offset)
+        })?;
+
+        Ok(PgTimeTz { time, offset })
+    }
+}
+
+impl Encode<Postgres> for PgTimeTz<NaiveTime, FixedOffset> {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        let micros = (self.time - NaiveTime::from_hms(0, 0, 0))
+            .num_microseconds()
+            .expect("shouldn't overflow");
+
+        encode_time_tz(buf, micros, self.offset.local_minus_utc());
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + mem::size_of::<i32>()
+    }
+}
+
 fn postgres_epoch() -> DateTime<Utc> {
     Utc.ymd(2000, 1, 1).and_hms(0, 0, 0)
 }
@@ -278,6 +362,34 @@ fn test_decode_time() {
     assert_eq!(time, NaiveTime::from_hms_micro(12, 53, 5, 125305),);
 }
 
+#[test]
+fn test_encode_time_tz() {
+    let mut buf = PgRawBuffer::default();
+
+    let value = PgTimeTz {
+        time: NaiveTime::from_hms(12, 34, 56),
+        offset: FixedOffset::east(2 * 3600),
+    };
+
+    Encode::<Postgres>::encode(&value, &mut buf);
+
+    // the zone is sent as seconds west of UTC
+    let mut expected = (1_000_000i64 * (12 * 3600 + 34 * 60 + 56))
+        .to_be_bytes()
+        .to_vec();
+    expected.extend_from_slice(&(-7200i32).to_be_bytes());
+
+    assert_eq!(&**buf, &*expected);
+
+    let decoded: PgTimeTz<NaiveTime, FixedOffset> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoded, value);
+
+    let decoded: PgTimeTz<NaiveTime, FixedOffset> =
+        Decode::<Postgres>::decode(PgValue::from_str("12:34:56+02")).unwrap();
+    assert_eq!(decoded, value);
+}
+
 #[test]
 fn test_encode_datetime() {
     let mut buf = PgRawBuffer::default();
@@ -318,6 +430,31 @@ fn test_decode_datetime() {
     assert_eq!(date.to_string(), "2019-12-11 11:01:05");
 }
 
+#[test]
+fn test_decode_datetime_with_offset() {
+    // 2019-12-11 11:01:05 UTC
+    let buf = 629_377_265_000_000i64.to_be_bytes();
+
+    let date: DateTime<FixedOffset> = Decode::<Postgres>::decode(
+        PgValue::from_bytes(&buf).with_time_zone_offset(Some(5 * 3600 + 30 * 60)),
+    )
+    .unwrap();
+    assert_eq!(date.to_rfc3339(), "2019-12-11T16:31:05+05:30");
+
+    // an unknown time zone falls back to UTC
+    let date: DateTime<FixedOffset> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&buf).with_time_zone_offset(None)).unwrap();
+    assert_eq!(date.to_rfc3339(), "2019-12-11T11:01:05+00:00");
+
+    let date: DateTime<FixedOffset> =
+        Decode::<Postgres>::decode(PgValue::from_str("2019-12-11 16:31:05+05:30")).unwrap();
+    assert_eq!(date.to_rfc3339(), "2019-12-11T16:31:05+05:30");
+
+    let date: DateTime<Utc> =
+        Decode::<Postgres>::decode(PgValue::from_str("2019-12-11 06:01:05-05")).unwrap();
+    assert_eq!(date.to_rfc3339(), "2019-12-11T11:01:05+00:00");
+}
+
 #[test]
 fn test_encode_date() {
     let mut buf = PgRawBuffer::default();
@@ -0,0 +1,45 @@
+use crate::encode::{Encode, IsNull};
+use crate::postgres::{PgRawBuffer, PgTypeInfo, Postgres};
+use crate::types::Type;
+
+/// Binds the SQL `DEFAULT` keyword in place of a value.
+///
+/// Every reference to a parameter bound to `PgDefault` is replaced by `DEFAULT` in the text of
+/// the query before it is prepared. This is only valid where Postgres accepts the keyword
+/// itself, e.g., as an item of `VALUES` in an `INSERT` or as the value of a column in the `SET`
+/// of an `UPDATE`; it cannot be used as a general expression or compared against.
+///
+/// As the query is changed, a statement is prepared (and cached) for each distinct pattern of
+/// `PgDefault` arguments.
+///
+/// ```rust,ignore
+/// use sqlx::postgres::types::PgDefault;
+///
+/// sqlx::query("INSERT INTO users (name, created_at) VALUES ($1, $2)")
+///     .bind("Alice")
+///     .bind(PgDefault)
+///     .execute(&mut conn)
+///     .await?;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PgDefault;
+
+impl Type<Postgres> for PgDefault {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::default_keyword()
+    }
+}
+
+impl Encode<Postgres> for PgDefault {
+    fn encode(&self, _buf: &mut PgRawBuffer) {}
+
+    fn encode_nullable(&self, _buf: &mut PgRawBuffer) -> IsNull {
+        // The parameter itself is still sent (as an unused NULL) so that the positions
+        // of any following parameters do not change
+        IsNull::Yes
+    }
+
+    fn size_hint(&self) -> usize {
+        0
+    }
+}
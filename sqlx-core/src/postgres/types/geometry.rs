@@ -0,0 +1,245 @@
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `POINT`; a point on a plane.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A Postgres `LINE`; an infinite line on a plane, represented by the linear equation
+/// `Ax + By + C = 0` where `A` and `B` are not both zero.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// A Postgres `LSEG`; a finite line segment between two points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PgLSeg {
+    pub start: PgPoint,
+    pub end: PgPoint,
+}
+
+/// A Postgres `PATH`; a list of connected points.
+///
+/// An open path has distinct first and last points; a closed path connects the last point back
+/// to the first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+/// A Postgres `POLYGON`; a closed path of points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+macro_rules! impl_type {
+    ($ty:ty, $id:ident, $name:literal, $array_id:ident, $array_name:literal) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$id, $name)
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$array_id, $array_name)
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+    };
+}
+
+impl_type!(PgPoint, POINT, "POINT", ARRAY_POINT, "POINT[]");
+impl_type!(PgLine, LINE, "LINE", ARRAY_LINE, "LINE[]");
+impl_type!(PgLSeg, LSEG, "LSEG", ARRAY_LSEG, "LSEG[]");
+impl_type!(PgPath, PATH, "PATH", ARRAY_PATH, "PATH[]");
+impl_type!(PgPolygon, POLYGON, "POLYGON", ARRAY_POLYGON, "POLYGON[]");
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/geo_ops.c
+// point_recv, line_recv, lseg_recv, path_recv and poly_recv
+
+impl<'de> Decode<'de, Postgres> for PgPoint {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => get_point(&mut buf),
+
+            PgData::Text(s) => match *parse_numbers(s, "POINT")? {
+                [x, y] => Ok(PgPoint { x, y }),
+                _ => Err(decode_err!("invalid value {:?} for POINT", s)),
+            },
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLine {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => Ok(PgLine {
+                a: get_f64(&mut buf)?,
+                b: get_f64(&mut buf)?,
+                c: get_f64(&mut buf)?,
+            }),
+
+            // e.g., {1,-1,0}
+            PgData::Text(s) => match *parse_numbers(s, "LINE")? {
+                [a, b, c] => Ok(PgLine { a, b, c }),
+                _ => Err(decode_err!("invalid value {:?} for LINE", s)),
+            },
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLSeg {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => Ok(PgLSeg {
+                start: get_point(&mut buf)?,
+                end: get_point(&mut buf)?,
+            }),
+
+            // e.g., [(0,0),(1,1)]
+            PgData::Text(s) => match *parse_points(s, "LSEG")? {
+                [start, end] => Ok(PgLSeg { start, end }),
+                _ => Err(decode_err!("invalid value {:?} for LSEG", s)),
+            },
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPath {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let closed = buf.get_u8()? != 0;
+                let points = get_points(&mut buf, "PATH")?;
+
+                Ok(PgPath { closed, points })
+            }
+
+            // An open path is enclosed in brackets and a closed path in parentheses;
+            // e.g., [(0,0),(1,1)] or ((0,0),(1,1),(1,0))
+            PgData::Text(s) => Ok(PgPath {
+                closed: !s.starts_with('['),
+                points: parse_points(s, "PATH")?,
+            }),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPolygon {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => Ok(PgPolygon {
+                points: get_points(&mut buf, "POLYGON")?,
+            }),
+
+            // e.g., ((0,0),(1,1),(1,0))
+            PgData::Text(s) => Ok(PgPolygon {
+                points: parse_points(s, "POLYGON")?,
+            }),
+        }
+    }
+}
+
+fn get_f64(buf: &mut &[u8]) -> crate::Result<f64> {
+    Ok(f64::from_bits(buf.get_u64::<BigEndian>()?))
+}
+
+fn get_point(buf: &mut &[u8]) -> crate::Result<PgPoint> {
+    Ok(PgPoint {
+        x: get_f64(buf)?,
+        y: get_f64(buf)?,
+    })
+}
+
+// The number of points followed by each point
+fn get_points(buf: &mut &[u8], name: &str) -> crate::Result<Vec<PgPoint>> {
+    let len = buf.get_i32::<BigEndian>()?;
+
+    // Check the length before allocating; each point is two 8-byte floats
+    if len < 0 || buf.len() != len as usize * 16 {
+        return Err(decode_err!(
+            "invalid number of points in {}: {} for {} bytes",
+            name,
+            len,
+            buf.len()
+        ));
+    }
+
+    (0..len).map(|_| get_point(buf)).collect()
+}
+
+// Reads all numbers from the text form of a geometric type; the delimiters do not matter
+fn parse_numbers(s: &str, name: &str) -> crate::Result<Vec<f64>> {
+    s.split(&['(', ')', '[', ']', '{', '}', ','][..])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| decode_err!("invalid value {:?} for {}", s, name))
+}
+
+fn parse_points(s: &str, name: &str) -> crate::Result<Vec<PgPoint>> {
+    let numbers = parse_numbers(s, name)?;
+
+    if numbers.len() % 2 != 0 {
+        return Err(decode_err!("invalid value {:?} for {}", s, name));
+    }
+
+    Ok(numbers
+        .chunks(2)
+        .map(|xy| PgPoint { x: xy[0], y: xy[1] })
+        .collect())
+}
+
+#[test]
+fn test_get_points() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&2_i32.to_be_bytes());
+
+    for n in &[1.0_f64, 2.0, 3.5, -4.0] {
+        buf.extend_from_slice(&n.to_be_bytes());
+    }
+
+    assert_eq!(
+        get_points(&mut &*buf, "PATH").unwrap(),
+        vec![PgPoint { x: 1.0, y: 2.0 }, PgPoint { x: 3.5, y: -4.0 }]
+    );
+
+    assert!(get_points(&mut &buf[..buf.len() - 1], "PATH").is_err());
+    assert!(get_points(&mut &(-1_i32).to_be_bytes()[..], "PATH").is_err());
+}
+
+#[test]
+fn test_parse_points() {
+    assert_eq!(
+        parse_points("[(0,0),(1.5,-2)]", "LSEG").unwrap(),
+        vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.5, y: -2.0 }]
+    );
+
+    assert_eq!(
+        parse_numbers("{1,-1,0}", "LINE").unwrap(),
+        vec![1.0, -1.0, 0.0]
+    );
+
+    assert!(parse_points("((0,0),(1))", "PATH").is_err());
+    assert!(parse_numbers("(a,b)", "POINT").is_err());
+}
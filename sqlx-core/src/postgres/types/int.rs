@@ -37,11 +37,25 @@ impl<'de> Decode<'de, Postgres> for i8 {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
         match value.try_get()? {
             PgData::Binary(mut buf) => buf.read_i8().map_err(Error::decode),
-            PgData::Text(s) => Ok(s.as_bytes()[0] as i8),
+            PgData::Text(s) => decode_char(s),
         }
     }
 }
 
+// The text form of a "char" is the byte itself, except that NUL is sent as the empty string
+// and Postgres 14+ escapes bytes outside of ASCII in octal (e.g., `\377`)
+fn decode_char(s: &str) -> crate::Result<i8> {
+    match s.as_bytes() {
+        [] => Ok(0),
+
+        [b'\\', _, _, _] => u8::from_str_radix(&s[1..], 8)
+            .map(|byte| byte as i8)
+            .map_err(|_| decode_err!("invalid \"char\" value: {:?}", s)),
+
+        [byte, ..] => Ok(*byte as i8),
+    }
+}
+
 impl Type<Postgres> for i16 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::INT2, "INT2")
@@ -172,3 +186,11 @@ impl<'de> Decode<'de, Postgres> for i64 {
         }
     }
 }
+
+#[test]
+fn test_decode_char_text() {
+    assert_eq!(decode_char("r").unwrap(), b'r' as i8);
+    assert_eq!(decode_char("").unwrap(), 0);
+    assert_eq!(decode_char("\\377").unwrap(), -1);
+    assert_eq!(decode_char("\\").unwrap(), b'\\' as i8);
+}
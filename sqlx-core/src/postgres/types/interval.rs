@@ -0,0 +1,430 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+impl Type<Postgres> for Duration {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl Type<Postgres> for [Duration] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl Type<Postgres> for Vec<Duration> {
+    fn type_info() -> PgTypeInfo {
+        <[Duration] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/timestamp.c
+// interval_send / interval_recv
+
+// The binary format is the microseconds, then the days and then the months of the interval
+// A `Duration` of more than `i64::MAX` microseconds (about 292,471 years) does not fit and is
+// saturated to the largest INTERVAL of microseconds
+impl Encode<Postgres> for Duration {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        let micros = i64::try_from(self.as_micros()).unwrap_or(i64::MAX);
+
+        buf.extend_from_slice(&micros.to_be_bytes());
+        buf.extend_from_slice(&0_i32.to_be_bytes());
+        buf.extend_from_slice(&0_i32.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Duration {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let micros = buf.get_i64::<BigEndian>()?;
+                let days = buf.get_i32::<BigEndian>()?;
+                let months = buf.get_i32::<BigEndian>()?;
+
+                if months != 0 || days != 0 {
+                    return Err(decode_err!(
+                        "INTERVAL with {} months and {} days can not be decoded as a Duration",
+                        months,
+                        days
+                    ));
+                }
+
+                if micros < 0 {
+                    return Err(crate::Error::Decode(
+                        "negative INTERVAL can not be decoded as a Duration".into(),
+                    ));
+                }
+
+                Ok(Duration::from_micros(micros as u64))
+            }
+
+            PgData::Text(s) => parse_duration(s)
+                .ok_or_else(|| decode_err!("INTERVAL {:?} can not be decoded as a Duration", s)),
+        }
+    }
+}
+
+// An interval without months or days is output as only its time; e.g., `26:03:00.5`
+// Anything else (days, months, a sign, or a different `IntervalStyle`) is not representable
+fn parse_duration(s: &str) -> Option<Duration> {
+    let mut parts = s.splitn(3, ':');
+
+    let hours = parts.next()?;
+    let minutes = parts.next()?;
+    let seconds = parts.next()?;
+
+    let (seconds, fraction) = match seconds.find('.') {
+        Some(index) => (&seconds[..index], &seconds[index + 1..]),
+        None => (seconds, ""),
+    };
+
+    // [parse] would also accept a sign
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+
+    if hours.is_empty()
+        || minutes.len() != 2
+        || seconds.len() != 2
+        || fraction.len() > 6
+        || !is_digits(hours)
+        || !is_digits(minutes)
+        || !is_digits(seconds)
+        || !is_digits(fraction)
+    {
+        return None;
+    }
+
+    let hours: u64 = hours.parse().ok()?;
+    let minutes: u64 = minutes.parse().unwrap();
+    let seconds: u64 = seconds.parse().unwrap();
+
+    // Pad the fraction to microseconds; e.g., `5` is 500000
+    let micros = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(6)
+        .fold(0, |micros, b| micros * 10 + u32::from(b - b'0'));
+
+    let seconds = hours
+        .checked_mul(3600)?
+        .checked_add(minutes * 60 + seconds)?;
+
+    Some(Duration::new(seconds, micros * 1000))
+}
+
+/// A Postgres `INTERVAL`; a number of months, days and microseconds.
+///
+/// Postgres keeps the three apart as the length of a month or a day depends on the date the
+/// interval is added to; e.g., a day across a change to daylight saving time. Any interval can
+/// be decoded as a `PgInterval`, unlike a `Duration`.
+///
+/// ```rust,ignore
+/// let (interval,): (PgInterval,) = sqlx::query_as("SELECT '1 mon 2 days 03:00:00'::interval")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// assert_eq!(interval.months(), 1);
+/// assert_eq!(interval.days(), 2);
+/// assert_eq!(interval.microseconds(), 3 * 60 * 60 * 1_000_000);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgInterval {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl PgInterval {
+    /// Creates an interval of `months`, `days` and `microseconds`.
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Returns the months of the interval; a year is 12 months.
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the days of the interval.
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the microseconds of the interval; its hours, minutes and seconds.
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// Converts the interval to a `Duration`, with each day as 24 hours.
+    ///
+    /// Returns `None` if the interval has months, as a month has no fixed length, or if it is
+    /// negative.
+    pub fn to_std(&self) -> Option<Duration> {
+        if self.months != 0 {
+            return None;
+        }
+
+        let micros = i64::from(self.days)
+            .checked_mul(MICROS_PER_DAY)?
+            .checked_add(self.microseconds)?;
+
+        u64::try_from(micros).ok().map(Duration::from_micros)
+    }
+}
+
+/// An interval of only the microseconds of the `Duration`; any nanoseconds are truncated.
+///
+/// Returns an error if the `Duration` is more than `i64::MAX` microseconds (about 292,471
+/// years), which an `INTERVAL` can not hold.
+impl TryFrom<Duration> for PgInterval {
+    type Error = crate::Error;
+
+    fn try_from(duration: Duration) -> crate::Result<Self> {
+        let micros = i64::try_from(duration.as_micros()).map_err(|_| {
+            crate::Error::Decode(
+                format!("Duration out of range for an INTERVAL: {:?}", duration).into(),
+            )
+        })?;
+
+        Ok(PgInterval::new(0, 0, micros))
+    }
+}
+
+impl Type<Postgres> for PgInterval {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl Type<Postgres> for [PgInterval] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgInterval> {
+    fn type_info() -> PgTypeInfo {
+        <[PgInterval] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgInterval {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&self.microseconds.to_be_bytes());
+        buf.extend_from_slice(&self.days.to_be_bytes());
+        buf.extend_from_slice(&self.months.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgInterval {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let microseconds = buf.get_i64::<BigEndian>()?;
+                let days = buf.get_i32::<BigEndian>()?;
+                let months = buf.get_i32::<BigEndian>()?;
+
+                Ok(PgInterval::new(months, days, microseconds))
+            }
+
+            PgData::Text(s) => {
+                parse_interval(s).ok_or_else(|| decode_err!("invalid value {:?} for INTERVAL", s))
+            }
+        }
+    }
+}
+
+// The `postgres` IntervalStyle (the default); e.g., `1 year 2 mons -3 days +04:05:06.5`
+fn parse_interval(s: &str) -> Option<PgInterval> {
+    let mut interval = PgInterval::default();
+    let mut tokens = s.split_whitespace().peekable();
+
+    tokens.peek()?;
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            let (is_negative, time) = match token.as_bytes()[0] {
+                b'-' => (true, &token[1..]),
+                b'+' => (false, &token[1..]),
+                _ => (false, token),
+            };
+
+            let micros = i64::try_from(parse_duration(time)?.as_micros()).ok()?;
+
+            interval.microseconds = if is_negative { -micros } else { micros };
+
+            continue;
+        }
+
+        let value: i32 = token.parse().ok()?;
+
+        match tokens.next()? {
+            "year" | "years" => {
+                interval.months = interval.months.checked_add(value.checked_mul(12)?)?;
+            }
+
+            "mon" | "mons" => {
+                interval.months = interval.months.checked_add(value)?;
+            }
+
+            "day" | "days" => {
+                interval.days = value;
+            }
+
+            _ => {
+                return None;
+            }
+        }
+    }
+
+    Some(interval)
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("00:00:00"), Some(Duration::from_secs(0)));
+    assert_eq!(
+        parse_duration("26:03:01.5"),
+        Some(Duration::from_millis(((26 * 60 + 3) * 60 + 1) * 1000 + 500))
+    );
+    assert_eq!(
+        parse_duration("00:00:00.000001"),
+        Some(Duration::from_micros(1))
+    );
+
+    assert_eq!(parse_duration("1 day 02:00:00"), None);
+    assert_eq!(parse_duration("1 mon"), None);
+    assert_eq!(parse_duration("-00:00:01"), None);
+    assert_eq!(parse_duration("PT1H"), None);
+    assert_eq!(parse_duration("00:00:00.0000001"), None);
+}
+
+#[test]
+fn test_encode_duration() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&Duration::new(90, 1_500), &mut buf);
+
+    let mut expected = 90_000_001_i64.to_be_bytes().to_vec();
+    expected.extend_from_slice(&[0; 8]);
+
+    assert_eq!(&**buf, &*expected);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&Duration::from_secs(u64::MAX), &mut buf);
+
+    let mut expected = i64::MAX.to_be_bytes().to_vec();
+    expected.extend_from_slice(&[0; 8]);
+
+    assert_eq!(&**buf, &*expected);
+}
+
+#[test]
+fn test_decode_duration() {
+    let mut buf = 90_000_001_i64.to_be_bytes().to_vec();
+    buf.extend_from_slice(&[0; 8]);
+
+    let duration: Duration = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(duration, Duration::from_micros(90_000_001));
+
+    // 1 day
+    buf[11] = 1;
+    assert!(<Duration as Decode<Postgres>>::decode(PgValue::from_bytes(&buf)).is_err());
+
+    // -1 microsecond
+    let mut buf = (-1_i64).to_be_bytes().to_vec();
+    buf.extend_from_slice(&[0; 8]);
+    assert!(<Duration as Decode<Postgres>>::decode(PgValue::from_bytes(&buf)).is_err());
+}
+
+#[test]
+fn test_interval_from_duration() {
+    assert_eq!(
+        PgInterval::try_from(Duration::new(90, 1_500)).unwrap(),
+        PgInterval::new(0, 0, 90_000_001)
+    );
+
+    assert!(PgInterval::try_from(Duration::from_secs(u64::MAX)).is_err());
+}
+
+#[test]
+fn test_interval_accessors() {
+    let interval = PgInterval::new(14, 3, 5_000_001);
+
+    assert_eq!(interval.months(), 14);
+    assert_eq!(interval.days(), 3);
+    assert_eq!(interval.microseconds(), 5_000_001);
+    assert_eq!(interval.to_std(), None);
+
+    let interval = PgInterval::new(0, 2, 1_500_000);
+
+    assert_eq!(
+        interval.to_std(),
+        Some(Duration::from_secs(2 * 24 * 60 * 60) + Duration::from_millis(1_500))
+    );
+
+    assert_eq!(PgInterval::new(0, 0, -1).to_std(), None);
+    assert_eq!(
+        PgInterval::new(0, 1, -1).to_std(),
+        Some(Duration::from_micros(86_399_999_999))
+    );
+}
+
+#[test]
+fn test_parse_interval() {
+    assert_eq!(parse_interval("00:00:00"), Some(PgInterval::default()));
+    assert_eq!(
+        parse_interval("1 year 2 mons 3 days 04:05:06.5"),
+        Some(PgInterval::new(14, 3, 14_706_500_000))
+    );
+    assert_eq!(
+        parse_interval("-1 years +2 mons -1 days -00:00:01"),
+        Some(PgInterval::new(-10, -1, -1_000_000))
+    );
+    assert_eq!(parse_interval("1 day"), Some(PgInterval::new(0, 1, 0)));
+
+    assert_eq!(parse_interval(""), None);
+    assert_eq!(parse_interval("@ 1 hour"), None);
+    assert_eq!(parse_interval("1 fortnight"), None);
+    assert_eq!(parse_interval("P1D"), None);
+}
+
+#[test]
+fn test_encode_decode_interval() {
+    let interval = PgInterval::new(-2, 1, 90_000_001);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&interval, &mut buf);
+
+    let mut expected = 90_000_001_i64.to_be_bytes().to_vec();
+    expected.extend_from_slice(&1_i32.to_be_bytes());
+    expected.extend_from_slice(&(-2_i32).to_be_bytes());
+
+    assert_eq!(&**buf, &*expected);
+
+    let decoded: PgInterval = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoded, interval);
+}
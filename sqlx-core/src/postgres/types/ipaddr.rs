@@ -0,0 +1,212 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::value::PgValue;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+// Maybe not used, but defining to follow Rust's libstd/net/sys
+#[cfg(target_os = "redox")]
+const AF_INET: u8 = 1;
+#[cfg(not(any(windows, target_os = "redox")))]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+pub(super) const PGSQL_AF_INET: u8 = AF_INET;
+pub(super) const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+pub(super) const INET_TYPE: u8 = 0;
+pub(super) const CIDR_TYPE: u8 = 1;
+
+// A host address is an `INET` with a netmask of all of its bits; /32 or /128
+
+macro_rules! impl_ip_addr_type {
+    ($($ty:ty),*) => {
+        $(
+            impl Type<Postgres> for $ty {
+                fn type_info() -> PgTypeInfo {
+                    PgTypeInfo::new(TypeId::INET, "INET")
+                }
+            }
+
+            impl Type<Postgres> for [$ty] {
+                fn type_info() -> PgTypeInfo {
+                    PgTypeInfo::new(TypeId::ARRAY_INET, "INET[]")
+                }
+            }
+
+            impl Type<Postgres> for Vec<$ty> {
+                fn type_info() -> PgTypeInfo {
+                    <[$ty] as Type<Postgres>>::type_info()
+                }
+            }
+        )*
+    };
+}
+
+impl_ip_addr_type!(IpAddr, Ipv4Addr, Ipv6Addr);
+
+impl Encode<Postgres> for IpAddr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        match self {
+            IpAddr::V4(ip) => {
+                buf.push(PGSQL_AF_INET);
+                buf.push(32);
+                buf.push(INET_TYPE);
+                buf.push(4);
+                buf.extend_from_slice(&ip.octets());
+            }
+
+            IpAddr::V6(ip) => {
+                buf.push(PGSQL_AF_INET6);
+                buf.push(128);
+                buf.push(INET_TYPE);
+                buf.push(16);
+                buf.extend_from_slice(&ip.octets());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
+    }
+}
+
+impl Encode<Postgres> for Ipv4Addr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        IpAddr::V4(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Encode<Postgres> for Ipv6Addr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        IpAddr::V6(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        20
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for IpAddr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => decode(buf),
+            PgData::Text(s) => parse(s),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv4Addr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match <IpAddr as Decode<'de, Postgres>>::decode(value)? {
+            IpAddr::V4(ip) => Ok(ip),
+            IpAddr::V6(ip) => Err(decode_err!("expected an IPv4 address, got {}", ip)),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv6Addr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match <IpAddr as Decode<'de, Postgres>>::decode(value)? {
+            IpAddr::V6(ip) => Ok(ip),
+            IpAddr::V4(ip) => Err(decode_err!("expected an IPv6 address, got {}", ip)),
+        }
+    }
+}
+
+fn decode(bytes: &[u8]) -> crate::Result<IpAddr> {
+    if bytes.len() < 8 {
+        return Err(Error::Decode("Input too short".into()));
+    }
+
+    let af = bytes[0];
+    let prefix = bytes[1];
+    let net_type = bytes[2];
+    let len = bytes[3];
+
+    if net_type == INET_TYPE || net_type == CIDR_TYPE {
+        if af == PGSQL_AF_INET && bytes.len() == 8 && len == 4 {
+            let ip = Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+
+            return host_address(IpAddr::V4(ip), prefix);
+        }
+
+        if af == PGSQL_AF_INET6 && bytes.len() == 20 && len == 16 {
+            let mut octets = [0_u8; 16];
+            octets.copy_from_slice(&bytes[4..]);
+
+            return host_address(IpAddr::V6(Ipv6Addr::from(octets)), prefix);
+        }
+    }
+
+    Err(Error::Decode("Invalid input".into()))
+}
+
+// The text form of a host address leaves out the netmask, except for a CIDR
+fn parse(s: &str) -> crate::Result<IpAddr> {
+    let (ip, prefix) = match s.find('/') {
+        Some(slash) => {
+            let prefix = s[slash + 1..].parse().map_err(Error::decode)?;
+
+            (&s[..slash], Some(prefix))
+        }
+
+        None => (s, None),
+    };
+
+    let ip: IpAddr = ip.parse().map_err(Error::decode)?;
+
+    match prefix {
+        Some(prefix) => host_address(ip, prefix),
+        None => Ok(ip),
+    }
+}
+
+fn host_address(ip: IpAddr, prefix: u8) -> crate::Result<IpAddr> {
+    let bits = if ip.is_ipv4() { 32 } else { 128 };
+
+    if prefix != bits {
+        return Err(decode_err!(
+            "expected a host address, got the network {}/{}; use `IpNetwork` (with the \
+             `ipnetwork` feature) to decode networks",
+            ip,
+            prefix
+        ));
+    }
+
+    Ok(ip)
+}
+
+#[test]
+fn test_decode_ip_addr() {
+    assert_eq!(
+        decode(&[PGSQL_AF_INET, 32, INET_TYPE, 4, 127, 0, 0, 1]).unwrap(),
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    );
+
+    assert!(decode(&[PGSQL_AF_INET, 8, INET_TYPE, 4, 10, 0, 0, 0]).is_err());
+
+    assert_eq!(parse("::1").unwrap(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_eq!(
+        parse("10.1.2.3/32").unwrap(),
+        "10.1.2.3".parse::<IpAddr>().unwrap()
+    );
+    assert!(parse("10.0.0.0/8").is_err());
+
+    let mut buf = PgRawBuffer::default();
+    Ipv6Addr::LOCALHOST.encode(&mut buf);
+
+    assert_eq!(decode(&buf).unwrap(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+}
@@ -5,25 +5,12 @@ use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
+use crate::postgres::types::ipaddr::{CIDR_TYPE, INET_TYPE, PGSQL_AF_INET, PGSQL_AF_INET6};
 use crate::postgres::value::PgValue;
 use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, Postgres};
 use crate::types::Type;
 use crate::Error;
 
-#[cfg(windows)]
-const AF_INET: u8 = 2;
-// Maybe not used, but defining to follow Rust's libstd/net/sys
-#[cfg(redox)]
-const AF_INET: u8 = 1;
-#[cfg(not(any(windows, redox)))]
-const AF_INET: u8 = libc::AF_INET as u8;
-
-const PGSQL_AF_INET: u8 = AF_INET;
-const PGSQL_AF_INET6: u8 = AF_INET + 1;
-
-const INET_TYPE: u8 = 0;
-const CIDR_TYPE: u8 = 1;
-
 impl Type<Postgres> for IpNetwork {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::INET, "INET")
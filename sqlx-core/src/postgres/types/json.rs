@@ -1,10 +1,10 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::io::{Buf, BufMut};
+use crate::io::BufMut;
 use crate::postgres::protocol::TypeId;
-use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::postgres::types::json_bytes::decode_json_bytes;
+use crate::postgres::{PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::{Json, Type};
-use crate::value::RawValue;
 use serde::{Deserialize, Serialize};
 use serde_json::value::RawValue as JsonRawValue;
 use serde_json::Value as JsonValue;
@@ -76,23 +76,8 @@ where
     T: Deserialize<'de>,
 {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
-        (match value.try_get()? {
-            PgData::Text(s) => serde_json::from_str(s),
-            PgData::Binary(mut buf) => {
-                if value.type_info().as_ref().and_then(|info| info.id) == Some(TypeId::JSONB) {
-                    let version = buf.get_u8()?;
-
-                    assert_eq!(
-                        version, 1,
-                        "unsupported JSONB format version {}; please open an issue",
-                        version
-                    );
-                }
-
-                serde_json::from_slice(buf)
-            }
-        })
-        .map(Json)
-        .map_err(crate::Error::decode)
+        serde_json::from_slice(decode_json_bytes(value)?)
+            .map(Json)
+            .map_err(crate::Error::decode)
     }
 }
@@ -0,0 +1,81 @@
+use crate::decode::Decode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::value::RawValue;
+
+/// The JSON text of a `JSON` or `JSONB` value, as it was received; e.g., to parse a large value
+/// with a streaming parser rather than decoding all of it into a `serde_json::Value` at once.
+///
+/// ```rust,ignore
+/// let mut cursor = conn.fetch("SELECT document FROM reports");
+///
+/// while let Some(row) = cursor.next().await? {
+///     let document: PgJsonBytes = row.get(0);
+///
+///     // `&[u8]` implements `std::io::Read`
+///     let mut reader = document.0;
+/// }
+/// ```
+///
+/// The version byte in front of a `JSONB` value is stripped; the bytes are always the UTF-8 JSON
+/// text of the value. This type can only be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgJsonBytes<'a>(pub &'a [u8]);
+
+impl Type<Postgres> for PgJsonBytes<'_> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::JSONB, "JSONB")
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgJsonBytes<'de> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        decode_json_bytes(value).map(PgJsonBytes)
+    }
+}
+
+// The JSON text of a JSON or JSONB value
+pub(crate) fn decode_json_bytes<'de>(value: PgValue<'de>) -> crate::Result<&'de [u8]> {
+    match value.try_get()? {
+        PgData::Text(s) => Ok(s.as_bytes()),
+
+        PgData::Binary(mut buf) => {
+            // JSONB is prefixed by a version byte, followed by the JSON text of the
+            // value; this is the same whether the value is an object, an array (such as
+            // the result of `jsonb_agg`), or a scalar
+            if value.type_info().as_ref().and_then(|info| info.id) == Some(TypeId::JSONB) {
+                let version = buf.get_u8()?;
+
+                if version != 1 {
+                    return Err(decode_err!(
+                        "unsupported JSONB format version {}; please open an issue",
+                        version
+                    ));
+                }
+            }
+
+            Ok(buf)
+        }
+    }
+}
+
+#[test]
+fn test_decode_json_bytes() {
+    let jsonb = PgTypeInfo::new(TypeId::JSONB, "JSONB");
+
+    let value = PgValue::bytes(jsonb.clone(), b"\x01{\"a\": 1}");
+    let bytes: PgJsonBytes = Decode::<Postgres>::decode(value).unwrap();
+    assert_eq!(bytes.0, b"{\"a\": 1}");
+
+    let value = PgValue::bytes(PgTypeInfo::new(TypeId::JSON, "JSON"), b"[1, 2]");
+    let bytes: PgJsonBytes = Decode::<Postgres>::decode(value).unwrap();
+    assert_eq!(bytes.0, b"[1, 2]");
+
+    let value = PgValue::bytes(jsonb.clone(), b"\x02{}");
+    assert!(<PgJsonBytes as Decode<Postgres>>::decode(value).is_err());
+
+    let value = PgValue::bytes(jsonb, b"");
+    assert!(<PgJsonBytes as Decode<Postgres>>::decode(value).is_err());
+}
@@ -0,0 +1,98 @@
+use std::fmt::{self, Display};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// https://github.com/postgres/postgres/blob/master/src/include/utils/jsonpath.h
+const JSONPATH_VERSION: u8 = 1;
+
+/// A Postgres `JSONPATH`; an SQL/JSON path expression, e.g., for `jsonb_path_query`.
+///
+/// The path is sent to Postgres in the binary format of `JSONPATH`: a version byte followed by
+/// the text of the path, which Postgres parses as it would a text literal. Postgres normalizes
+/// the paths it receives so a decoded path may be formatted differently from the one that was
+/// sent; e.g., `$.a[*] ? (@ > 2)` is returned as `$."a"[*]?(@ > 2)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgJsonPath(pub String);
+
+impl Display for PgJsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgJsonPath {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::JSONPATH, "JSONPATH")
+    }
+}
+
+impl Type<Postgres> for [PgJsonPath] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_JSONPATH, "JSONPATH[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgJsonPath> {
+    fn type_info() -> PgTypeInfo {
+        <[PgJsonPath] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/jsonpath.c
+// jsonpath_send / jsonpath_recv
+
+impl Encode<Postgres> for PgJsonPath {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        // The binary format is a version byte followed by the text of the path
+        buf.put_u8(JSONPATH_VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgJsonPath {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => decode(buf),
+            PgData::Text(s) => Ok(PgJsonPath(s.to_owned())),
+        }
+    }
+}
+
+fn decode(mut buf: &[u8]) -> crate::Result<PgJsonPath> {
+    let version = buf.get_u8()?;
+
+    if version != JSONPATH_VERSION {
+        return Err(decode_err!(
+            "unsupported JSONPATH format version {}; please open an issue",
+            version
+        ));
+    }
+
+    let path = std::str::from_utf8(buf).map_err(Error::decode)?;
+
+    Ok(PgJsonPath(path.to_owned()))
+}
+
+#[test]
+fn test_encode_decode_json_path() {
+    let path = PgJsonPath(r#"$."a"[*]?(@ > 2)"#.to_owned());
+
+    let mut buf = PgRawBuffer::default();
+    path.encode(&mut buf);
+
+    assert_eq!(&**buf, &b"\x01$.\"a\"[*]?(@ > 2)"[..]);
+    assert_eq!(decode(&buf).unwrap(), path);
+
+    assert!(decode(b"").is_err());
+    assert!(decode(b"\x02$").is_err());
+}
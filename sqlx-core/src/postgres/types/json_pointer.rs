@@ -0,0 +1,141 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::encode::Encode;
+use crate::postgres::{PgRawBuffer, PgTypeInfo, Postgres};
+use crate::types::Type;
+
+#[cfg(feature = "json")]
+use crate::{query::Query, query_as::QueryAs, types::Json};
+
+/// A JSON Pointer (RFC 6901); e.g., `/address/city`, which is bound as the TEXT[] path of
+/// `jsonb_set` and the other functions and operators that take a path; e.g., `{address,city}`.
+///
+/// An element of an array is selected by its index; e.g., `/tags/0`. The `~0` and `~1`
+/// escapes of a pointer are unescaped to `~` and `/`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgJsonPointer(pub Vec<String>);
+
+impl Display for PgJsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            write!(f, "/{}", token.replace('~', "~0").replace('/', "~1"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PgJsonPointer {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        parse_pointer(s).ok_or_else(|| decode_err!("invalid JSON Pointer {:?}", s))
+    }
+}
+
+// The pointer of the whole document is empty; otherwise each token is preceded by a `/`
+fn parse_pointer(s: &str) -> Option<PgJsonPointer> {
+    if s.is_empty() {
+        return Some(PgJsonPointer::default());
+    }
+
+    if !s.starts_with('/') {
+        return None;
+    }
+
+    s[1..]
+        .split('/')
+        .map(unescape_token)
+        .collect::<Option<_>>()
+        .map(PgJsonPointer)
+}
+
+fn unescape_token(token: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '~' => match chars.next()? {
+                '0' => unescaped.push('~'),
+                '1' => unescaped.push('/'),
+                _ => return None,
+            },
+
+            c => unescaped.push(c),
+        }
+    }
+
+    Some(unescaped)
+}
+
+impl Type<Postgres> for PgJsonPointer {
+    fn type_info() -> PgTypeInfo {
+        <Vec<String> as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgJsonPointer {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        Encode::<Postgres>::encode(&self.0, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        Encode::<Postgres>::size_hint(&self.0)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'q> Query<'q, Postgres> {
+    /// Bind the path and the new value of a partial update with `jsonb_set`; the path is
+    /// bound as TEXT[] and then the value as JSONB.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("UPDATE users SET profile = jsonb_set(profile, $1, $2) WHERE id = $3")
+    ///     .bind_jsonb_set("/address/city".parse()?, "Paris")
+    ///     .bind(id)
+    ///     .execute(&mut conn)
+    ///     .await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn bind_jsonb_set<T>(self, pointer: PgJsonPointer, value: T) -> Self
+    where
+        Json<T>: Encode<Postgres>,
+    {
+        self.bind(pointer).bind(Json(value))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'q, O> QueryAs<'q, Postgres, O> {
+    /// Bind the path and the new value of a partial update with `jsonb_set`.
+    ///
+    /// See [`Query::bind_jsonb_set`](crate::query::Query::bind_jsonb_set).
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn bind_jsonb_set<T>(self, pointer: PgJsonPointer, value: T) -> Self
+    where
+        Json<T>: Encode<Postgres>,
+    {
+        self.bind(pointer).bind(Json(value))
+    }
+}
+
+#[test]
+fn test_parse_pointer() {
+    let pointer: PgJsonPointer = "/address/city".parse().unwrap();
+    assert_eq!(pointer.0, vec!["address", "city"]);
+
+    let pointer: PgJsonPointer = "/a~1b/m~0n/0/".parse().unwrap();
+    assert_eq!(pointer.0, vec!["a/b", "m~n", "0", ""]);
+    assert_eq!(pointer.to_string(), "/a~1b/m~0n/0/");
+
+    assert_eq!(
+        "".parse::<PgJsonPointer>().unwrap(),
+        PgJsonPointer::default()
+    );
+
+    assert!("address".parse::<PgJsonPointer>().is_err());
+    assert!("/a~2".parse::<PgJsonPointer>().is_err());
+    assert!("/a~".parse::<PgJsonPointer>().is_err());
+}
@@ -0,0 +1,179 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `MACADDR`; a 6-byte (EUI-48) MAC address.
+///
+/// This is displayed and parsed in the canonical form of Postgres; e.g., `08:00:2b:01:02:03`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgMacAddress(pub [u8; 6]);
+
+/// A Postgres `MACADDR8`; an 8-byte (EUI-64) MAC address.
+///
+/// This is displayed and parsed in the canonical form of Postgres; e.g.,
+/// `08:00:2b:01:02:03:04:05`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgMacAddress8(pub [u8; 8]);
+
+macro_rules! impl_mac_address {
+    ($ty:ident, $len:literal, $id:ident, $name:literal, $array_id:ident, $array_name:literal) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$id, $name)
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$array_id, $array_name)
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+
+        // The binary format is the bytes of the address as-is
+        impl Encode<Postgres> for $ty {
+            fn encode(&self, buf: &mut PgRawBuffer) {
+                buf.extend_from_slice(&self.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                $len
+            }
+        }
+
+        impl<'de> Decode<'de, Postgres> for $ty {
+            fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+                let mut address = [0; $len];
+
+                match value.try_get()? {
+                    PgData::Binary(buf) if buf.len() == $len => {
+                        address.copy_from_slice(buf);
+                    }
+
+                    PgData::Binary(buf) => {
+                        return Err(decode_err!(
+                            "invalid length of {} value: {} bytes",
+                            $name,
+                            buf.len()
+                        ));
+                    }
+
+                    PgData::Text(s) => {
+                        if !parse_address(s, &mut address) {
+                            return Err(decode_err!("invalid value {:?} for {}", s, $name));
+                        }
+                    }
+                }
+
+                Ok($ty(address))
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                display_address(&self.0, f)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = crate::Error;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                let mut address = [0; $len];
+
+                if parse_address(s, &mut address) {
+                    Ok($ty(address))
+                } else {
+                    Err(decode_err!("invalid value {:?} for {}", s, $name))
+                }
+            }
+        }
+    };
+}
+
+impl_mac_address!(
+    PgMacAddress,
+    6,
+    MACADDR,
+    "MACADDR",
+    ARRAY_MACADDR,
+    "MACADDR[]"
+);
+
+impl_mac_address!(
+    PgMacAddress8,
+    8,
+    MACADDR8,
+    "MACADDR8",
+    ARRAY_MACADDR8,
+    "MACADDR8[]"
+);
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/mac.c
+// macaddr_out / macaddr8_out
+
+fn display_address(address: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, byte) in address.iter().enumerate() {
+        if i > 0 {
+            f.write_str(":")?;
+        }
+
+        write!(f, "{:02x}", byte)?;
+    }
+
+    Ok(())
+}
+
+// Pairs of hex digits separated by `:`; e.g., 08:00:2b:01:02:03
+fn parse_address(s: &str, address: &mut [u8]) -> bool {
+    let mut parts = s.split(':');
+
+    for byte in address.iter_mut() {
+        match parts.next() {
+            // [from_str_radix] would also accept a sign
+            Some(part) if part.len() == 2 && part.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                *byte = u8::from_str_radix(part, 16).unwrap();
+            }
+
+            _ => return false,
+        }
+    }
+
+    parts.next().is_none()
+}
+
+#[test]
+fn test_parse_address() {
+    assert_eq!(
+        "08:00:2b:01:02:03".parse::<PgMacAddress>().unwrap(),
+        PgMacAddress([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03])
+    );
+
+    assert_eq!(
+        "08:00:2B:01:02:03:04:05".parse::<PgMacAddress8>().unwrap(),
+        PgMacAddress8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05])
+    );
+
+    assert!("08:00:2b:01:02".parse::<PgMacAddress>().is_err());
+    assert!("08:00:2b:01:02:03:04".parse::<PgMacAddress>().is_err());
+    assert!("08:00:2b:01:02:3".parse::<PgMacAddress>().is_err());
+    assert!("08:00:2b:01:02:+3".parse::<PgMacAddress>().is_err());
+}
+
+#[test]
+fn test_display_address() {
+    assert_eq!(
+        PgMacAddress([0x08, 0x00, 0x2b, 0x01, 0x02, 0xff]).to_string(),
+        "08:00:2b:01:02:ff"
+    );
+}
@@ -5,13 +5,56 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | BOOL                                                 |
+//! | `i8`                                  | "CHAR"                                               |
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u32`                                 | OID, REGCLASS, REGTYPE, REGPROC, ... (see below)     |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, NAME                         |
+//! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, NAME, UNKNOWN                |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | [`PgByteStream`]                      | BYTEA (encode only; see its documentation)           |
+//! | `IpAddr`, `Ipv4Addr`, `Ipv6Addr`      | INET, CIDR (host addresses only; encoded as INET)    |
+//! | `std::time::Duration`                 | INTERVAL (without months or days; see below)         |
+//! | [`PgInterval`]                        | INTERVAL                                             |
+//! | [`PgMacAddress`]                      | MACADDR                                              |
+//! | [`PgMacAddress8`]                     | MACADDR8                                             |
+//! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgLsn`]                             | PG_LSN                                               |
+//! | [`PgTid`]                             | TID                                                  |
+//! | [`PgTsVector`]                        | TSVECTOR                                             |
+//! | [`PgTsQuery`]                         | TSQUERY                                              |
+//! | [`PgJsonPath`]                        | JSONPATH                                             |
+//! | [`PgJsonBytes`]                       | JSON, JSONB (the JSON text as-is; decode only)       |
+//! | [`PgJsonPointer`]                     | TEXT[] (a path of `jsonb_set`; encode only)          |
+//! | [`PgXml`]                             | XML                                                  |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgLine`]                            | LINE                                                 |
+//! | [`PgLSeg`]                            | LSEG                                                 |
+//! | [`PgPath`]                            | PATH                                                 |
+//! | [`PgPolygon`]                         | POLYGON                                              |
+//! | [`PgDefault`]                         | The `DEFAULT` keyword (see its documentation)        |
+//!
+//! The object identifier aliases (REGCLASS, REGTYPE, REGPROC, REGPROCEDURE, REGOPER,
+//! REGOPERATOR, REGCONFIG, REGDICTIONARY, REGNAMESPACE and REGROLE) are decoded as the `u32`
+//! OID of the object. To get the name of the object instead, cast the value to TEXT in the
+//! query; e.g., `SELECT 'pg_class'::regclass::text`. Note that an unprepared query returns
+//! these as names, which can not be decoded as a `u32`.
+//!
+//! A `Duration` is bound as an INTERVAL of only microseconds; any nanoseconds are truncated.
+//! A `Duration` of more than `i64::MAX` microseconds (about 292,471 years) is saturated to it;
+//! use `PgInterval::try_from` to check that it fits first.
+//! An INTERVAL with a month or day component can't be decoded as a `Duration`, as the length
+//! of a month or day depends on the date it is added to, and neither can a negative INTERVAL.
+//! Such a value can be decoded as a [`PgInterval`] of its months, days and microseconds, or as a
+//! number of seconds with `EXTRACT(EPOCH FROM ..)`, instead.
+//!
+//! An ACLITEM (e.g., an element of `pg_class.relacl`) can be decoded as a `String` of its text
+//! form, `grantee=privileges/grantor`; e.g., `=r/postgres` grants SELECT to PUBLIC. An
+//! ACLITEM[] can be decoded as a `Vec<String>`.
+//!
+//! The geometric types can only be decoded; they can not yet be bound as parameters.
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -21,9 +64,17 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `chrono::DateTime<Utc>`               | TIMESTAMPTZ                                          |
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
+//! | `chrono::DateTime<FixedOffset>`       | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
+//! | `PgTimeTz<NaiveTime, FixedOffset>`    | TIMETZ                                               |
+//!
+//! A `DateTime<FixedOffset>` is decoded in the `TimeZone` of the session if it is a fixed offset
+//! from UTC; e.g., `UTC`, `Etc/GMT-5`, or after `SET TIME ZONE INTERVAL '+05:30' HOUR TO MINUTE`.
+//! Postgres sends binary `TIMESTAMPTZ` values in UTC and a named time zone such as
+//! `America/New_York` can't be resolved to an offset without a time zone database, so the value
+//! falls back to UTC in that case (as it does inside of arrays and records).
 //!
 //! ### [`time`](https://crates.io/crates/time)
 //!
@@ -35,6 +86,7 @@
 //! | `time::OffsetDateTime`                | TIMESTAMPTZ                                          |
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME                                                 |
+//! | `PgTimeTz<Time, UtcOffset>`           | TIMETZ                                               |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -52,6 +104,17 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`bytes`](https://crates.io/crates/bytes)
+//!
+//! Requires the `bytes` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bytes::Bytes`                        | BYTEA                                                |
+//!
+//! A bound `Bytes` is not copied into the arguments of the query, which avoids holding a
+//! second copy of large values in memory.
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -91,12 +154,47 @@
 //! }
 //! ```
 //!
+//! A type in a schema that is not on the `search_path` can be named with its schema;
+//! e.g., `#[sqlx(rename = "catalog.inventory_item")]`. Like an unquoted name in SQL, the name
+//! is folded to lowercase; a part of it that was created with a quoted, mixed-case name must
+//! be quoted, e.g., `#[sqlx(rename = "catalog.\"InventoryItem\"")]`.
+//!
 //! Anonymous composite types are represented as tuples. Note that anonymous composites may only
 //! be returned and not sent to Postgres (this is a limitation of postgres).
 //!
 //! # Arrays
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
+//! The elements are encoded from where they are; e.g., a `&[&str]` is bound as a TEXT[] without
+//! copying the strings into a `Vec<String>` first.
+//! An array that does not start at one (e.g., `'[2:4]={a,b,c}'`) is decoded into its elements
+//! in order; the lower bound is not kept.
+//!
+//! The INT2VECTOR and OIDVECTOR types of the system catalogs (e.g., `pg_index.indkey`) can be
+//! decoded as a `Vec<i16>` and a `Vec<u32>`, respectively.
+//!
+//! Arrays of a known length can also be decoded into fixed-size arrays, `[T; N]` where
+//! `N <= 32` and `T` implements `Default`. Decoding fails if the length of the array is not `N`.
+//! Fixed-size arrays can be bound as well; e.g., `.bind(&["a", "b"])`.
+//!
+//! Arrays of a composite type derived with `sqlx::Type` are supported as well, including a
+//! composite type with an array field; e.g., a `Vec<InventoryItem>` for an `inventory_item[]`.
+//! See [`PgHasArrayType`].
+//!
+//! With `#[sqlx(check)]`, the types of the fields are checked at compile time against the
+//! attributes of the composite type in the database at `DATABASE_URL`, in order; a field of a
+//! type that does not match its attribute (e.g., an `i32` for a `BIGINT`) is a compile error
+//! that names the field. Nothing is checked if `DATABASE_URL` is not set to a Postgres database.
+//!
+//! ```rust,ignore
+//! #[derive(sqlx::Type)]
+//! #[sqlx(check, rename = "inventory_item")]
+//! struct InventoryItem {
+//!     name: Option<String>,
+//!     supplier_id: Option<i32>,
+//!     price: Option<i64>,
+//! }
+//! ```
 //!
 //! # [Enumerations](https://www.postgresql.org/docs/current/datatype-enum.html)
 //!
@@ -112,6 +210,14 @@
 //! enum Mood { Sad, Ok, Happy }
 //! ```
 //!
+//! With `#[sqlx(create_type)]`, the enum also declares the statement that creates its type from
+//! the labels of its variants; e.g., `Mood::CREATE_TYPE_SQL` is
+//! `CREATE TYPE "mood" AS ENUM ('sad', 'ok', 'happy')`.
+//!
+//! With `#[sqlx(from_str)]`, the enum also implements `FromStr` to parse it from the labels of
+//! its variants; e.g., `"happy".parse::<Mood>()`. This is not derived by default as the enum
+//! may implement `FromStr` itself.
+//!
 //! Rust enumerations may also be defined to be represented as an integer using `repr`.
 //! The following type expects a SQL type of `INTEGER` or `INT4` and will convert to/from the
 //! Rust enumeration.
@@ -122,6 +228,30 @@
 //! enum Mood { Sad = 0, Ok = 1, Happy = 2 }
 //! ```
 //!
+//! An enum whose variants carry data can instead be converted through its own conversions to
+//! and from an integer type with `#[sqlx(via_int = "..")]`; e.g., `Status: From<i32>` and
+//! `i32: From<Status>`. As the conversion into the integer takes the enum by value, the enum
+//! must also implement `Clone` and is cloned each time it is encoded.
+//!
+//! ```rust,ignore
+//! #[derive(Clone, sqlx::Type)]
+//! #[sqlx(via_int = "i32")]
+//! enum Status { Ok, NotFound, Other(i32) }
+//! ```
+//!
+//! # Newtypes
+//!
+//! A newtype is supported through a derive for `Type` with `#[sqlx(transparent)]`; its SQL type
+//! is that of the type it wraps. With `#[sqlx(compatible = "..")]`, its SQL type is instead the
+//! named builtin type (e.g., `text` or `int4[]`), which is resolved at compile time; there is no
+//! lookup of the type in the catalog.
+//!
+//! ```rust,ignore
+//! #[derive(sqlx::Type)]
+//! #[sqlx(transparent, compatible = "text")]
+//! struct Email(String);
+//! ```
+//!
 //! # Nullable
 //!
 //! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
@@ -129,16 +259,48 @@
 //!
 
 use crate::decode::Decode;
-use crate::postgres::protocol::TypeId;
+use crate::postgres::protocol::{TypeFormat, TypeId};
 use crate::postgres::{PgValue, Postgres};
 
 mod array;
 mod bool;
 mod bytes;
+mod default;
 mod float;
+mod geometry;
 mod int;
+mod interval;
+mod ipaddr;
+mod json_bytes;
+mod json_path;
+mod json_pointer;
+mod lsn;
+mod mac_address;
+mod money;
 mod record;
 mod str;
+mod tid;
+mod time_tz;
+mod tsquery;
+mod tsvector;
+mod xml;
+
+pub use self::bytes::PgByteStream;
+pub use array::PgHasArrayType;
+pub use default::PgDefault;
+pub use geometry::{PgLSeg, PgLine, PgPath, PgPoint, PgPolygon};
+pub use interval::PgInterval;
+pub use json_bytes::PgJsonBytes;
+pub use json_path::PgJsonPath;
+pub use json_pointer::PgJsonPointer;
+pub use lsn::PgLsn;
+pub use mac_address::{PgMacAddress, PgMacAddress8};
+pub use money::PgMoney;
+pub use tid::PgTid;
+pub use time_tz::PgTimeTz;
+pub use tsquery::PgTsQuery;
+pub use tsvector::{PgLexeme, PgLexemePosition, PgTsVector, PgTsWeight};
+pub use xml::PgXml;
 
 // internal types used by other types to encode or decode related formats
 #[doc(hidden)]
@@ -191,11 +353,16 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
 
         TypeId::OID => "OID",
 
+        TypeId::INT2VECTOR => "INT2VECTOR",
+        TypeId::OIDVECTOR => "OIDVECTOR",
+
         TypeId::FLOAT4 => "FLOAT4",
         TypeId::FLOAT8 => "FLOAT8",
 
         TypeId::NUMERIC => "NUMERIC",
 
+        TypeId::MONEY => "MONEY",
+
         TypeId::TEXT => "TEXT",
         TypeId::VARCHAR => "VARCHAR",
         TypeId::BPCHAR => "BPCHAR",
@@ -204,8 +371,10 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
 
         TypeId::DATE => "DATE",
         TypeId::TIME => "TIME",
+        TypeId::TIMETZ => "TIMETZ",
         TypeId::TIMESTAMP => "TIMESTAMP",
         TypeId::TIMESTAMPTZ => "TIMESTAMPTZ",
+        TypeId::INTERVAL => "INTERVAL",
 
         TypeId::BYTEA => "BYTEA",
 
@@ -214,6 +383,29 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
         TypeId::CIDR => "CIDR",
         TypeId::INET => "INET",
 
+        TypeId::MACADDR => "MACADDR",
+        TypeId::MACADDR8 => "MACADDR8",
+
+        TypeId::PG_LSN => "PG_LSN",
+
+        TypeId::TID => "TID",
+
+        TypeId::ACLITEM => "ACLITEM",
+
+        TypeId::TSVECTOR => "TSVECTOR",
+        TypeId::TSQUERY => "TSQUERY",
+
+        TypeId::REGPROC => "REGPROC",
+        TypeId::REGPROCEDURE => "REGPROCEDURE",
+        TypeId::REGOPER => "REGOPER",
+        TypeId::REGOPERATOR => "REGOPERATOR",
+        TypeId::REGCLASS => "REGCLASS",
+        TypeId::REGTYPE => "REGTYPE",
+        TypeId::REGCONFIG => "REGCONFIG",
+        TypeId::REGDICTIONARY => "REGDICTIONARY",
+        TypeId::REGNAMESPACE => "REGNAMESPACE",
+        TypeId::REGROLE => "REGROLE",
+
         TypeId::ARRAY_BOOL => "BOOL[]",
 
         TypeId::ARRAY_CHAR => "\"CHAR\"[]",
@@ -234,10 +426,14 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
 
         TypeId::ARRAY_NUMERIC => "NUMERIC[]",
 
+        TypeId::ARRAY_MONEY => "MONEY[]",
+
         TypeId::ARRAY_DATE => "DATE[]",
         TypeId::ARRAY_TIME => "TIME[]",
+        TypeId::ARRAY_TIMETZ => "TIMETZ[]",
         TypeId::ARRAY_TIMESTAMP => "TIMESTAMP[]",
         TypeId::ARRAY_TIMESTAMPTZ => "TIMESTAMPTZ[]",
+        TypeId::ARRAY_INTERVAL => "INTERVAL[]",
 
         TypeId::ARRAY_BYTEA => "BYTEA[]",
 
@@ -246,8 +442,37 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
         TypeId::ARRAY_CIDR => "CIDR[]",
         TypeId::ARRAY_INET => "INET[]",
 
+        TypeId::ARRAY_MACADDR => "MACADDR[]",
+        TypeId::ARRAY_MACADDR8 => "MACADDR8[]",
+
+        TypeId::ARRAY_PG_LSN => "PG_LSN[]",
+
+        TypeId::ARRAY_TID => "TID[]",
+
+        TypeId::ARRAY_ACLITEM => "ACLITEM[]",
+
+        TypeId::ARRAY_TSVECTOR => "TSVECTOR[]",
+        TypeId::ARRAY_TSQUERY => "TSQUERY[]",
+
+        TypeId::POINT => "POINT",
+        TypeId::LSEG => "LSEG",
+        TypeId::PATH => "PATH",
+        TypeId::POLYGON => "POLYGON",
+        TypeId::LINE => "LINE",
+
+        TypeId::ARRAY_POINT => "POINT[]",
+        TypeId::ARRAY_LSEG => "LSEG[]",
+        TypeId::ARRAY_PATH => "PATH[]",
+        TypeId::ARRAY_POLYGON => "POLYGON[]",
+        TypeId::ARRAY_LINE => "LINE[]",
+
         TypeId::JSON => "JSON",
         TypeId::JSONB => "JSONB",
+        TypeId::JSONPATH => "JSONPATH",
+        TypeId::ARRAY_JSONPATH => "JSONPATH[]",
+
+        TypeId::XML => "XML",
+        TypeId::ARRAY_XML => "XML[]",
 
         TypeId::RECORD => "RECORD",
         TypeId::ARRAY_RECORD => "RECORD[]",
@@ -257,3 +482,62 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
         }
     })
 }
+
+// Try to resolve the OID of a builtin type from its name; e.g., `text`, `integer` or `INT4[]`
+pub(crate) fn try_resolve_type_oid(name: &str) -> Option<u32> {
+    let name = name.trim().to_ascii_uppercase();
+
+    let (base, array) = if name.ends_with("[]") {
+        (&name[..name.len() - 2], "[]")
+    } else {
+        (&*name, "")
+    };
+
+    // the SQL standard names of types that Postgres knows by another name
+    let base = match base {
+        "BOOLEAN" => "BOOL",
+        "SMALLINT" => "INT2",
+        "INT" | "INTEGER" => "INT4",
+        "BIGINT" => "INT8",
+        "REAL" => "FLOAT4",
+        "DOUBLE PRECISION" => "FLOAT8",
+        "DECIMAL" => "NUMERIC",
+        "CHARACTER VARYING" => "VARCHAR",
+        "CHAR" | "CHARACTER" => "BPCHAR",
+        "TIME WITH TIME ZONE" => "TIMETZ",
+        "TIME WITHOUT TIME ZONE" => "TIME",
+        "TIMESTAMP WITH TIME ZONE" => "TIMESTAMPTZ",
+        "TIMESTAMP WITHOUT TIME ZONE" => "TIMESTAMP",
+
+        base => base,
+    };
+
+    let name = format!("{}{}", base, array);
+
+    // the OIDs of the builtin types are all below 10000 (`FirstGenbkiObjectId`)
+    (0..10_000).find(|&oid| try_resolve_type_name(oid) == Some(&*name))
+}
+
+// Resolve the format values of a builtin type OID are transferred in; this is the format of its
+// [PgTypeInfo] and the format we ask Postgres to send results of the type in
+// Some types have no binary representation at all and must be requested as text
+pub(crate) fn resolve_type_format(oid: TypeId) -> TypeFormat {
+    match oid {
+        TypeId::ACLITEM | TypeId::ARRAY_ACLITEM => TypeFormat::Text,
+
+        _ => TypeFormat::Binary,
+    }
+}
+
+#[test]
+fn test_resolve_type_oid() {
+    assert_eq!(try_resolve_type_oid("text"), Some(TypeId::TEXT.0));
+    assert_eq!(try_resolve_type_oid("INTEGER"), Some(TypeId::INT4.0));
+    assert_eq!(try_resolve_type_oid("int4[]"), Some(TypeId::ARRAY_INT4.0));
+    assert_eq!(
+        try_resolve_type_oid("timestamp with time zone"),
+        Some(TypeId::TIMESTAMPTZ.0)
+    );
+
+    assert_eq!(try_resolve_type_oid("inventory_item"), None);
+}
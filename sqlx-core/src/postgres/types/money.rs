@@ -0,0 +1,99 @@
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `MONEY` value; the amount in the smallest unit of the currency, e.g., cents.
+///
+/// The number of fractional digits of the amount is given by the `lc_monetary` setting of the
+/// database; for most locales, there are two, so `PgMoney(1234)` is `$12.34`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgMoney(pub i64);
+
+impl Type<Postgres> for PgMoney {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::MONEY, "MONEY")
+    }
+}
+
+impl Type<Postgres> for [PgMoney] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_MONEY, "MONEY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgMoney> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMoney] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/cash.c
+// cash_send / cash_recv
+
+// The binary format is the amount as an INT8
+impl Encode<Postgres> for PgMoney {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&self.0.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgMoney {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => Ok(PgMoney(buf.get_i64::<BigEndian>()?)),
+
+            PgData::Text(s) => {
+                parse_money(s).ok_or_else(|| decode_err!("invalid value {:?} for MONEY", s))
+            }
+        }
+    }
+}
+
+// The text format depends on `lc_monetary`; e.g., `-$1,234.56` or `-1.234,56 €`
+// All of the fractional digits are always output so the digits are the amount in order
+fn parse_money(s: &str) -> Option<PgMoney> {
+    let is_negative = s.contains('-') || s.contains('(');
+
+    let mut amount: i64 = 0;
+    let mut has_digits = false;
+
+    for b in s.bytes().filter(u8::is_ascii_digit) {
+        amount = amount.checked_mul(10)?.checked_sub(i64::from(b - b'0'))?;
+        has_digits = true;
+    }
+
+    if !has_digits {
+        return None;
+    }
+
+    // the amount is accumulated as a negative number to reach `i64::MIN`
+    if is_negative {
+        Some(PgMoney(amount))
+    } else {
+        amount.checked_neg().map(PgMoney)
+    }
+}
+
+#[test]
+fn test_parse_money() {
+    assert_eq!(parse_money("$12.34"), Some(PgMoney(1234)));
+    assert_eq!(parse_money("-$1,234.56"), Some(PgMoney(-123456)));
+    assert_eq!(parse_money("($0.05)"), Some(PgMoney(-5)));
+    assert_eq!(parse_money("1.234,56 €"), Some(PgMoney(123456)));
+    assert_eq!(
+        parse_money("-$92,233,720,368,547,758.08"),
+        Some(PgMoney(i64::MIN))
+    );
+
+    assert_eq!(parse_money("$92,233,720,368,547,758.08"), None);
+    assert_eq!(parse_money("$"), None);
+}
@@ -1,17 +1,22 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
 use crate::postgres::types::raw::sequence::PgSequenceDecoder;
 use crate::postgres::{PgData, PgRawBuffer, PgValue, Postgres};
 use crate::types::Type;
+use crate::value::RawValue;
 use byteorder::BE;
 use std::marker::PhantomData;
+use std::str::SplitAsciiWhitespace;
 
 // https://git.postgresql.org/gitweb/?p=postgresql.git;a=blob;f=src/include/utils/array.h;h=7f7e744cb12bc872f628f90dad99dfdf074eb314;hb=master#l6
 // https://git.postgresql.org/gitweb/?p=postgresql.git;a=blob;f=src/backend/utils/adt/arrayfuncs.c;h=7a4a5aaa86dc1c8cffa2d899c89511dc317d485b;hb=master#l1547
 
 pub(crate) struct PgArrayEncoder<'enc, T> {
     count: usize,
+    has_nulls: bool,
+    flags_index: usize,
     len_start_index: usize,
     buf: &'enc mut PgRawBuffer,
     phantom: PhantomData<T>,
@@ -27,7 +32,8 @@ where
         // ndim
         buf.put_i32::<BE>(1);
 
-        // dataoffset
+        // flags; 1 if the array has NULL elements, which is only known once they are encoded
+        let flags_index = buf.len();
         buf.put_i32::<BE>(0);
 
         // [elemtype] element type OID
@@ -49,6 +55,8 @@ where
 
         Self {
             count: 0,
+            has_nulls: false,
+            flags_index,
             len_start_index,
             buf,
 
@@ -63,14 +71,14 @@ where
 
         // Allocate and encode the element it self
         let el_start = self.buf.len();
+        let el_encoded_start = self.buf.encoded_len();
 
         if let IsNull::Yes = Encode::<Postgres>::encode_nullable(&item, self.buf) {
             self.buf[el_len_index..el_start].copy_from_slice(&(-1_i32).to_be_bytes());
+            self.has_nulls = true;
         } else {
-            let el_end = self.buf.len();
-
             // Now we know the actual length of the encoded element
-            let el_len = el_end - el_start;
+            let el_len = self.buf.encoded_len() - el_encoded_start;
 
             // And we can now go back and update the length
             self.buf[el_len_index..el_start].copy_from_slice(&(el_len as i32).to_be_bytes());
@@ -86,11 +94,18 @@ where
 
         self.buf[self.len_start_index..self.len_start_index + I32_SIZE]
             .copy_from_slice(&size_bytes);
+
+        if self.has_nulls {
+            self.buf[self.flags_index..self.flags_index + I32_SIZE]
+                .copy_from_slice(&1_i32.to_be_bytes());
+        }
     }
 }
 
 pub(crate) struct PgArrayDecoder<'de, T> {
     inner: PgSequenceDecoder<'de>,
+    // the elements of an INT2VECTOR or OIDVECTOR in text, which are separated by spaces
+    words: Option<SplitAsciiWhitespace<'de>>,
     phantom: PhantomData<T>,
 }
 
@@ -100,6 +115,11 @@ where
     T: Type<Postgres>,
 {
     pub(crate) fn new(value: PgValue<'de>) -> crate::Result<Self> {
+        let is_vector = matches!(
+            value.type_info().and_then(|ty| ty.id),
+            Some(TypeId::INT2VECTOR) | Some(TypeId::OIDVECTOR)
+        );
+
         let mut data = value.try_get()?;
 
         let element_oid = match data {
@@ -111,6 +131,7 @@ where
                     // ndim of 0 is an empty array
                     return Ok(Self {
                         inner: PgSequenceDecoder::new(PgData::Binary(&[]), None),
+                        words: None,
                         phantom: PhantomData,
                     });
                 }
@@ -122,9 +143,9 @@ where
                     ));
                 }
 
-                // offset to stored data
-                // this doesn't matter as the data is always at the end of the header
-                let _dataoffset = buf.get_i32::<BE>()?;
+                // flags; whether the array has NULL elements
+                // this doesn't matter as each NULL element is marked by its length
+                let _flags = buf.get_i32::<BE>()?;
 
                 // element type OID
                 let element_oid = buf.get_u32::<BE>()?;
@@ -133,28 +154,57 @@ where
                 let _dimensions = buf.get_i32::<BE>()?;
 
                 // lower boundary of each dimension
-                let lower_bnds = buf.get_i32::<BE>()?;
-
-                if lower_bnds != 1 {
-                    return Err(decode_err!(
-                        "encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported",
-                        lower_bnds
-                    ));
-                }
+                // the elements are decoded in order regardless of where the array starts
+                let _lower_bnds = buf.get_i32::<BE>()?;
 
                 Some(element_oid)
             }
 
-            PgData::Text(_) => None,
+            // the text form of INT2VECTOR and OIDVECTOR is not enclosed in braces;
+            // e.g., 1 2 3
+            PgData::Text(s) if is_vector => {
+                return Ok(Self {
+                    inner: PgSequenceDecoder::new(PgData::Binary(&[]), None),
+                    words: Some(s.split_ascii_whitespace()),
+                    phantom: PhantomData,
+                });
+            }
+
+            PgData::Text(ref mut s) => {
+                // an array that does not start at one is prefixed with its bounds;
+                // e.g., [2:4]={a,b,c}
+                if s.starts_with('[') {
+                    let start = s.find('=').map_or(s.len(), |index| index + 1);
+
+                    if s[..start].matches('[').count() != 1 {
+                        return Err(decode_err!(
+                            "encountered an array with the bounds {}; only one-dimensional arrays are supported",
+                            s[..start].trim_end_matches('=')
+                        ));
+                    }
+
+                    *s = &s[start..];
+                }
+
+                None
+            }
         };
 
         Ok(Self {
             inner: PgSequenceDecoder::new(data, element_oid),
+            words: None,
             phantom: PhantomData,
         })
     }
 
     fn decode(&mut self) -> crate::Result<Option<T>> {
+        if let Some(words) = &mut self.words {
+            return words
+                .next()
+                .map(|word| T::decode(PgValue::from_str(word)))
+                .transpose();
+        }
+
         self.inner.decode()
     }
 }
@@ -176,7 +226,8 @@ where
 mod tests {
     use super::PgArrayDecoder;
     use super::PgArrayEncoder;
-    use crate::postgres::{PgRawBuffer, PgValue};
+    use crate::postgres::protocol::TypeId;
+    use crate::postgres::{PgRawBuffer, PgTypeInfo, PgValue};
 
     const BUF_BINARY_I32: &[u8] = b"\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x02\x00\x00\x00\x04\x00\x00\x00\x03\x00\x00\x00\x04\x00\x00\x00\x04";
 
@@ -194,6 +245,32 @@ mod tests {
         assert_eq!(&**buf, BUF_BINARY_I32);
     }
 
+    #[test]
+    fn it_encodes_nulls() -> crate::Result<()> {
+        let mut buf = PgRawBuffer::default();
+        let mut encoder = PgArrayEncoder::new(&mut buf);
+
+        for val in &[Some(1_i32), None, Some(3)] {
+            encoder.encode(*val);
+        }
+
+        encoder.finish();
+
+        assert_eq!(
+            &**buf,
+            &b"\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x17\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\xff\xff\xff\xff\x00\x00\x00\x04\x00\x00\x00\x03"[..]
+        );
+
+        let mut decoder = PgArrayDecoder::<Option<i32>>::new(PgValue::from_bytes(&buf))?;
+
+        assert_eq!(decoder.decode()?, Some(Some(1)));
+        assert_eq!(decoder.decode()?, Some(None));
+        assert_eq!(decoder.decode()?, Some(Some(3)));
+        assert_eq!(decoder.decode()?, None);
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_text_i32() -> crate::Result<()> {
         let s = "{1,152,-12412}";
@@ -219,6 +296,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_decodes_text_records_with_arrays() -> crate::Result<()> {
+        // SELECT ARRAY[ROW('fuzzy dice', ARRAY['red', 'large size']), ROW('a', ARRAY['b', 'c'])]
+        let s = r#"{"(\"fuzzy dice\",\"{red,\"\"large size\"\"}\")","(a,\"{b,c}\")"}"#;
+        let mut decoder = PgArrayDecoder::<(String, Vec<String>)>::new(PgValue::from_str(s))?;
+
+        assert_eq!(
+            decoder.decode()?,
+            Some((
+                "fuzzy dice".to_owned(),
+                vec!["red".to_owned(), "large size".to_owned()]
+            ))
+        );
+
+        assert_eq!(
+            decoder.decode()?,
+            Some(("a".to_owned(), vec!["b".to_owned(), "c".to_owned()]))
+        );
+
+        assert_eq!(decoder.decode()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_text_with_lower_bound() -> crate::Result<()> {
+        let s = "[2:4]={1,2,3}";
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_str(s))?;
+
+        assert_eq!(decoder.decode()?, Some(1));
+        assert_eq!(decoder.decode()?, Some(2));
+        assert_eq!(decoder.decode()?, Some(3));
+        assert_eq!(decoder.decode()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_text_vector() -> crate::Result<()> {
+        let int2vector = PgTypeInfo::new(TypeId::INT2VECTOR, "INT2VECTOR");
+        let oidvector = PgTypeInfo::new(TypeId::OIDVECTOR, "OIDVECTOR");
+
+        let mut decoder = PgArrayDecoder::<i16>::new(PgValue::utf8(int2vector, b"1 -2 3")?)?;
+
+        assert_eq!(decoder.decode()?, Some(1));
+        assert_eq!(decoder.decode()?, Some(-2));
+        assert_eq!(decoder.decode()?, Some(3));
+        assert_eq!(decoder.decode()?, None);
+
+        let mut decoder = PgArrayDecoder::<u32>::new(PgValue::utf8(oidvector, b"")?)?;
+
+        assert_eq!(decoder.decode()?, None);
+
+        // the text of any other array is enclosed in braces
+        let mut decoder = PgArrayDecoder::<i16>::new(PgValue::from_str("1 -2 3"))?;
+
+        assert!(decoder.decode().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_binary_with_lower_bound() -> crate::Result<()> {
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_bytes(
+            b"\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x02\x00\x00\x00\x04\x00\x00\x00\x05\x00\x00\x00\x04\x00\x00\x00\x06",
+        ))?;
+
+        assert_eq!(decoder.decode()?, Some(5));
+        assert_eq!(decoder.decode()?, Some(6));
+        assert_eq!(decoder.decode()?, None);
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_binary_nulls() -> crate::Result<()> {
         let mut decoder = PgArrayDecoder::<Option<bool>>::new(PgValue::from_bytes(
@@ -233,6 +384,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_decodes_binary_empty() -> crate::Result<()> {
+        // SELECT '{}'::int4[]; no dimensions, no NULL elements and the element type
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_bytes(
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x17",
+        ))?;
+
+        assert!(decoder.decode()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_text_empty() -> crate::Result<()> {
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_str("{}"))?;
+
+        assert!(decoder.decode()?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_binary_i32() -> crate::Result<()> {
         let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_bytes(BUF_BINARY_I32))?;
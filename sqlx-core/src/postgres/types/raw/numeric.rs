@@ -1,4 +1,4 @@
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 
 use byteorder::BigEndian;
 
@@ -83,6 +83,152 @@ impl Type<Postgres> for PgNumeric {
     }
 }
 impl PgNumeric {
+    /// Creates the `NUMERIC` value of `value * 10^-scale`; e.g., `from_scaled(12345, 2)` is
+    /// `123.45`.
+    ///
+    /// This is exact for any `i128`, which makes it suitable for fixed-point values such as
+    /// an amount of money in cents.
+    ///
+    /// ### Panics
+    /// If `scale` overflows `i16`.
+    pub fn from_scaled(value: i128, scale: u32) -> Self {
+        let dscale: i16 = scale
+            .try_into()
+            .expect("PgNumeric scale should not overflow i16");
+
+        let sign = if value < 0 {
+            PgNumericSign::Negative
+        } else {
+            PgNumericSign::Positive
+        };
+
+        // `i128::MIN` wraps back to itself, which is 2^127 as a `u128`
+        let mut magnitude = value.wrapping_abs() as u128;
+
+        // The base-10000 digits are aligned at the decimal point so the least significant
+        // digit is padded with zeros if the scale is not a multiple of 4
+        // The digits are collected least significant first
+        let mut digits = Vec::new();
+        let mut weight = -((scale / 4) as i32);
+
+        let partial = scale % 4;
+
+        if partial != 0 {
+            let modulus = 10u128.pow(partial);
+
+            digits.push(((magnitude % modulus) * 10u128.pow(4 - partial)) as i16);
+            magnitude /= modulus;
+            weight -= 1;
+        }
+
+        while magnitude > 0 {
+            digits.push((magnitude % 10000) as i16);
+            magnitude /= 10000;
+        }
+
+        while let Some(&0) = digits.last() {
+            digits.pop();
+        }
+
+        let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..zeros);
+        digits.reverse();
+
+        // weight is of the most significant digit
+        weight = if digits.is_empty() {
+            0
+        } else {
+            weight + (zeros + digits.len()) as i32 - 1
+        };
+
+        PgNumeric::Number {
+            sign,
+            digits,
+            weight: weight as i16,
+            scale: dscale,
+        }
+    }
+
+    /// Returns this value multiplied by `10^scale`; the inverse of
+    /// [`from_scaled`](#method.from_scaled).
+    ///
+    /// Returns an error if this is `NaN`, if it has more than `scale` fractional digits which
+    /// are not zero, or if the result does not fit in an `i128`.
+    pub fn to_scaled(&self, scale: u32) -> crate::Result<i128> {
+        let (sign, digits, weight) = match self {
+            PgNumeric::Number {
+                sign,
+                digits,
+                weight,
+                ..
+            } => (*sign, digits, *weight),
+
+            PgNumeric::NotANumber => {
+                return Err(Error::Decode(
+                    "NaN can not be represented as a scaled integer".into(),
+                ));
+            }
+        };
+
+        let overflow = || {
+            Error::Decode(
+                format!(
+                    "NUMERIC value does not fit in i128 with a scale of {}",
+                    scale
+                )
+                .into(),
+            )
+        };
+
+        let fractional = || {
+            Error::Decode(format!("NUMERIC value has more than {} fractional digits", scale).into())
+        };
+
+        let mut magnitude: u128 = 0;
+
+        for (i, &digit) in digits.iter().enumerate() {
+            if digit == 0 {
+                continue;
+            }
+
+            // the power of 10 this digit is multiplied by in the result
+            let exp = 4 * (weight as i64 - i as i64) + scale as i64;
+            let digit = digit as u128;
+
+            let term = if exp >= 0 {
+                u32::try_from(exp)
+                    .ok()
+                    .and_then(|exp| 10u128.checked_pow(exp))
+                    .and_then(|pow| digit.checked_mul(pow))
+                    .ok_or_else(overflow)?
+            } else if exp > -4 {
+                let pow = 10u128.pow(-exp as u32);
+
+                // the digits past the scale must be zeros
+                if digit / pow * pow != digit {
+                    return Err(fractional());
+                }
+
+                digit / pow
+            } else {
+                return Err(fractional());
+            };
+
+            magnitude = magnitude.checked_add(term).ok_or_else(overflow)?;
+        }
+
+        match sign {
+            PgNumericSign::Positive => i128::try_from(magnitude).map_err(|_| overflow()),
+
+            // 2^127 is `i128::MIN` once negated
+            PgNumericSign::Negative if magnitude <= 1 << 127 => {
+                Ok((magnitude as i128).wrapping_neg())
+            }
+
+            PgNumericSign::Negative => Err(overflow()),
+        }
+    }
+
     pub(crate) fn from_bytes(mut bytes: &[u8]) -> crate::Result<Self> {
         // https://github.com/postgres/postgres/blob/bcd1c3630095e48bc3b1eb0fc8e8c8a7c851eba1/src/backend/utils/adt/numeric.c#L874
         let num_digits = bytes.get_u16::<BigEndian>()?;
@@ -164,3 +310,71 @@ impl Encode<Postgres> for PgNumeric {
         }
     }
 }
+
+#[test]
+fn test_numeric_from_scaled() {
+    assert_eq!(
+        PgNumeric::from_scaled(12345, 2),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![123, 4500],
+            weight: 0,
+            scale: 2,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from_scaled(-5, 2),
+        PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            digits: vec![500],
+            weight: -1,
+            scale: 2,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from_scaled(1_0000_0000, 0),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![1],
+            weight: 2,
+            scale: 0,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from_scaled(0, 3),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![],
+            weight: 0,
+            scale: 3,
+        }
+    );
+}
+
+#[test]
+fn test_numeric_to_scaled() {
+    for &value in &[0, 1, -1, 12345, -50, i128::MAX, i128::MIN, 10_i128.pow(30)] {
+        for &scale in &[0, 1, 2, 4, 7, 12] {
+            assert_eq!(
+                PgNumeric::from_scaled(value, scale)
+                    .to_scaled(scale)
+                    .unwrap(),
+                value
+            );
+        }
+    }
+
+    // 123.45 is 1234500 with a scale of 4
+    assert_eq!(
+        PgNumeric::from_scaled(12345, 2).to_scaled(4).unwrap(),
+        1234500
+    );
+
+    // 123.45 can not be represented with a scale of 1
+    assert!(PgNumeric::from_scaled(12345, 2).to_scaled(1).is_err());
+    assert!(PgNumeric::from_scaled(i128::MAX, 0).to_scaled(1).is_err());
+    assert!(PgNumeric::NotANumber.to_scaled(0).is_err());
+}
@@ -47,11 +47,12 @@ impl<'a> PgRecordEncoder<'a> {
         self.buf.extend(&[0; 4]);
 
         let start = self.buf.len();
+        let encoded_start = self.buf.encoded_len();
+
         if let IsNull::Yes = value.encode_nullable(self.buf) {
             self.buf[start - 4..start].copy_from_slice(&(-1_i32).to_be_bytes());
         } else {
-            let end = self.buf.len();
-            let size = end - start;
+            let size = self.buf.encoded_len() - encoded_start;
 
             // replaces zeros with actual length
             self.buf[start - 4..start].copy_from_slice(&(size as u32).to_be_bytes());
@@ -64,20 +65,46 @@ impl<'a> PgRecordEncoder<'a> {
     }
 }
 
-pub struct PgRecordDecoder<'de>(PgSequenceDecoder<'de>);
+pub struct PgRecordDecoder<'de> {
+    inner: PgSequenceDecoder<'de>,
+
+    // The number of fields in the record; this is only sent in the binary format
+    len: Option<u32>,
+}
 
 impl<'de> PgRecordDecoder<'de> {
     pub fn new(value: PgValue<'de>) -> crate::Result<Self> {
         let mut data = value.try_get()?;
 
-        match data {
-            PgData::Text(_) => {}
-            PgData::Binary(ref mut buf) => {
-                let _expected_len = buf.get_u32::<BigEndian>()?;
-            }
-        }
+        let len = match data {
+            PgData::Text(_) => None,
+            PgData::Binary(ref mut buf) => Some(buf.get_u32::<BigEndian>()?),
+        };
 
-        Ok(Self(PgSequenceDecoder::new(data, None)))
+        Ok(Self {
+            inner: PgSequenceDecoder::new(data, None),
+            len,
+        })
+    }
+
+    // Ensure that every field of the record has been decoded
+    pub(crate) fn finish(&self) -> crate::Result<()> {
+        let decoded = self.inner.len();
+
+        match self.len {
+            Some(len) if len as usize != decoded => Err(decode_err!(
+                "expected a record of {} fields but received a record of {} fields",
+                decoded,
+                len
+            )),
+
+            None if !self.inner.is_finished() => Err(decode_err!(
+                "expected a record of {} fields but received a record of more fields",
+                decoded
+            )),
+
+            _ => Ok(()),
+        }
     }
 
     #[inline]
@@ -86,9 +113,11 @@ impl<'de> PgRecordDecoder<'de> {
         T: for<'rec> Decode<'rec, Postgres>,
         T: Type<Postgres>,
     {
-        self.0
+        let len = self.inner.len();
+
+        self.inner
             .decode()?
-            .ok_or_else(|| decode_err!("no field `{0}` on {0}-element record", self.0.len()))
+            .ok_or_else(|| decode_err!("no field `{0}` on {0}-element record", len))
     }
 }
 
@@ -131,3 +160,64 @@ fn test_decode_field() {
     let value_decoded: String = decoder.decode().unwrap();
     assert_eq!(value_decoded, value);
 }
+
+#[test]
+fn test_encode_decode_array_field() {
+    let name = "fuzzy dice".to_string();
+    let tags = vec!["red".to_string(), "large size".to_string()];
+
+    let mut buf = PgRawBuffer::default();
+    let mut encoder = PgRecordEncoder::new(&mut buf);
+    encoder.encode(&name);
+    encoder.encode(&tags);
+    encoder.encode(&Vec::<String>::new());
+    encoder.finish();
+
+    let mut decoder = PgRecordDecoder::new(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(decoder.decode::<String>().unwrap(), name);
+    assert_eq!(decoder.decode::<Vec<String>>().unwrap(), tags);
+    assert_eq!(
+        decoder.decode::<Vec<String>>().unwrap(),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn test_decode_text_array_field() {
+    let mut decoder = PgRecordDecoder::new(PgValue::from_str(
+        r#"("fuzzy dice","{red,""large size""}",{})"#,
+    ))
+    .unwrap();
+
+    assert_eq!(decoder.decode::<String>().unwrap(), "fuzzy dice");
+    assert_eq!(
+        decoder.decode::<Vec<String>>().unwrap(),
+        vec!["red".to_string(), "large size".to_string()]
+    );
+    assert_eq!(
+        decoder.decode::<Vec<String>>().unwrap(),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn test_decode_field_count() {
+    let mut buf = PgRawBuffer::default();
+    let mut encoder = PgRecordEncoder::new(&mut buf);
+    encoder.encode(1_i32);
+    encoder.encode("x");
+    encoder.finish();
+
+    let mut decoder = PgRecordDecoder::new(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoder.decode::<i32>().unwrap(), 1);
+    assert!(decoder.finish().is_err());
+    assert_eq!(decoder.decode::<String>().unwrap(), "x");
+    assert!(decoder.finish().is_ok());
+
+    let mut decoder = PgRecordDecoder::new(PgValue::from_str("(1,x)")).unwrap();
+    assert_eq!(decoder.decode::<i32>().unwrap(), 1);
+    assert!(decoder.finish().is_err());
+    assert_eq!(decoder.decode::<String>().unwrap(), "x");
+    assert!(decoder.finish().is_ok());
+}
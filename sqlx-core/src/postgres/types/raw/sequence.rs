@@ -41,6 +41,14 @@ impl<'de> PgSequenceDecoder<'de> {
         self.len
     }
 
+    // If there are no more values in the sequence
+    pub(crate) fn is_finished(&self) -> bool {
+        match self.data {
+            PgData::Binary(buf) => buf.is_empty(),
+            PgData::Text(s) => s.is_empty(),
+        }
+    }
+
     pub(crate) fn decode<T>(&mut self) -> crate::Result<Option<T>>
     where
         T: for<'seq> Decode<'seq, Postgres>,
@@ -97,55 +105,55 @@ impl<'de> PgSequenceDecoder<'de> {
                 let mut in_escape = false;
                 let mut in_maybe_quote_escape = false;
 
-                let end: Option<usize> = 'outer: loop {
-                    let mut iter = s.char_indices().peekable();
-                    while let Some((index, ch)) = iter.next() {
-                        if in_maybe_quote_escape {
-                            if ch == '"' {
-                                // double quote escape
-                                value.push('"');
-                                in_maybe_quote_escape = false;
-                                continue;
-                            } else {
-                                // that was actually a quote
-                                in_quotes = !in_quotes;
-                            }
+                let mut end: Option<usize> = None;
+
+                for (index, ch) in s.char_indices() {
+                    if in_maybe_quote_escape {
+                        if ch == '"' {
+                            // double quote escape
+                            value.push('"');
+                            in_maybe_quote_escape = false;
+                            continue;
+                        } else {
+                            // that was actually a quote
+                            in_quotes = !in_quotes;
+                            in_maybe_quote_escape = false;
                         }
+                    }
 
-                        match ch {
-                            ',' if !in_quotes => break 'outer Some(index),
-
-                            '\\' if !in_escape => {
-                                in_escape = true;
-                            }
+                    match ch {
+                        ',' if !in_quotes => {
+                            end = Some(index);
+                            break;
+                        }
 
-                            _ if in_escape => {
-                                // special escape sequences only matter for string parsing
-                                // we only will ever receive stuff like "\\b" that we translate
-                                // to "\b"
-                                value.push(ch);
+                        '\\' if !in_escape => {
+                            in_escape = true;
+                        }
 
-                                // skip prev_ch assignment for
-                                //an escape sequence resolution
-                                in_escape = false;
-                                continue;
-                            }
+                        _ if in_escape => {
+                            // special escape sequences only matter for string parsing
+                            // we only will ever receive stuff like "\\b" that we translate
+                            // to "\b"
+                            value.push(ch);
 
-                            '"' if in_quotes => {
-                                in_maybe_quote_escape = true;
-                            }
+                            // skip prev_ch assignment for
+                            //an escape sequence resolution
+                            in_escape = false;
+                            continue;
+                        }
 
-                            '"' => {
-                                in_quotes = !in_quotes;
-                            }
+                        '"' if in_quotes => {
+                            in_maybe_quote_escape = true;
+                        }
 
-                            _ => value.push(ch),
+                        '"' => {
+                            in_quotes = !in_quotes;
                         }
-                    }
 
-                    // Reached the end of the string
-                    break None;
-                };
+                        _ => value.push(ch),
+                    }
+                }
 
                 // NOTE: We pass `0` as the type ID because we don't have a reasonable value
                 //       we could use. In TEXT mode, sequences aren't typed.
@@ -41,6 +41,9 @@ macro_rules! impl_pg_record_for_tuple {
 
                 $(let $idx: $T = decoder.decode()?;)+
 
+                // An anonymous record must have exactly as many fields as the tuple
+                decoder.finish()?;
+
                 Ok(($($idx,)+))
             }
         }
@@ -77,3 +77,21 @@ impl<'de> Decode<'de, Postgres> for &'de str {
         }
     }
 }
+
+// An untyped string literal is of the UNKNOWN type on versions of Postgres before 10; since
+// then, it is resolved to TEXT before it is returned
+#[test]
+fn test_decode_unknown() {
+    use crate::types::TypeInfo;
+    use crate::value::RawValue;
+
+    let value = PgValue::utf8(PgTypeInfo::new(TypeId::UNKNOWN, "UNKNOWN"), b"literal").unwrap();
+
+    assert_eq!(value.type_info().unwrap().oid(), Some(705));
+    assert!(value
+        .type_info()
+        .unwrap()
+        .compatible(&<String as Type<Postgres>>::type_info()));
+
+    assert_eq!(String::decode(value).unwrap(), "literal");
+}
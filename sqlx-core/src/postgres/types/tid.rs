@@ -0,0 +1,131 @@
+use std::fmt::{self, Display};
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `TID`; the physical location of a row version within its table, as given by
+/// the `ctid` system column.
+///
+/// This is displayed as the block number and the offset of the tuple within the block, in
+/// parentheses; e.g., `(0,1)`. Note that the location of a row changes when it is updated or
+/// the table is vacuumed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgTid {
+    /// The number of the block (page) of the table.
+    pub block: u32,
+
+    /// The (one-based) number of the tuple within the block.
+    pub offset: u16,
+}
+
+impl Type<Postgres> for PgTid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TID, "TID")
+    }
+}
+
+impl Type<Postgres> for [PgTid] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TID, "TID[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTid> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTid] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/tid.c
+// tidsend / tidrecv
+
+impl Encode<Postgres> for PgTid {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&self.block.to_be_bytes());
+        buf.extend_from_slice(&self.offset.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        6
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTid {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => Ok(PgTid {
+                block: buf.get_u32::<BigEndian>()?,
+                offset: buf.get_u16::<BigEndian>()?,
+            }),
+
+            PgData::Text(s) => {
+                parse_tid(s).ok_or_else(|| decode_err!("invalid value {:?} for TID", s))
+            }
+        }
+    }
+}
+
+impl Display for PgTid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.block, self.offset)
+    }
+}
+
+// The block number and offset in parentheses; e.g., (0,1)
+fn parse_tid(s: &str) -> Option<PgTid> {
+    if !s.starts_with('(') || !s.ends_with(')') || s.len() < 2 {
+        return None;
+    }
+
+    let mut parts = s[1..s.len() - 1].splitn(2, ',');
+
+    // [parse] would also accept a sign
+    let is_digits = |part: &&str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    let block = parts.next().filter(is_digits)?.parse().ok()?;
+    let offset = parts.next().filter(is_digits)?.parse().ok()?;
+
+    Some(PgTid { block, offset })
+}
+
+#[test]
+fn test_parse_tid() {
+    assert_eq!(
+        parse_tid("(0,1)"),
+        Some(PgTid {
+            block: 0,
+            offset: 1
+        })
+    );
+
+    assert_eq!(
+        parse_tid("(4294967295,65535)"),
+        Some(PgTid {
+            block: u32::max_value(),
+            offset: u16::max_value()
+        })
+    );
+
+    assert_eq!(parse_tid("(0,1"), None);
+    assert_eq!(parse_tid("(0)"), None);
+    assert_eq!(parse_tid("(0,+1)"), None);
+    assert_eq!(parse_tid("(0,65536)"), None);
+}
+
+#[test]
+fn test_display_tid() {
+    assert_eq!(
+        PgTid {
+            block: 12,
+            offset: 3
+        }
+        .to_string(),
+        "(12,3)"
+    );
+}
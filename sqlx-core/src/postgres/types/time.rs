@@ -3,12 +3,15 @@ use std::convert::TryInto;
 use std::mem;
 
 use byteorder::BigEndian;
-use time::{date, offset, Date, NumericalDuration, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{
+    date, offset, Date, NumericalDuration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+};
 
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::io::Buf;
 use crate::postgres::protocol::TypeId;
+use crate::postgres::types::time_tz::{decode_time_tz, encode_time_tz, PgTimeTz};
 use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 
@@ -252,6 +255,57 @@ impl Encode<Postgres> for OffsetDateTime {
     }
 }
 
+impl Type<Postgres> for PgTimeTz<Time, UtcOffset> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TIMETZ, "TIMETZ")
+    }
+}
+
+impl Type<Postgres> for [PgTimeTz<Time, UtcOffset>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TIMETZ, "TIMETZ[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTimeTz<Time, UtcOffset>> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTimeTz<Time, UtcOffset>] as Type<Postgres>>::type_info()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTimeTz<Time, UtcOffset> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let (micros, offset) = decode_time_tz(value)?;
+
+        // An offset must be less than a day
+        if offset.abs() >= 86_400 {
+            return Err(decode_err!(
+                "Postgres offset out of range for UtcOffset: {:?}",
+                offset
+            ));
+        }
+
+        Ok(PgTimeTz {
+            time: from_microseconds_since_midnight(micros as u64)?,
+            offset: UtcOffset::seconds(offset),
+        })
+    }
+}
+
+impl Encode<Postgres> for PgTimeTz<Time, UtcOffset> {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        encode_time_tz(
+            buf,
+            microseconds_since_midnight(self.time),
+            self.offset.as_seconds(),
+        );
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + mem::size_of::<i32>()
+    }
+}
+
 #[cfg(test)]
 use time::time;
 
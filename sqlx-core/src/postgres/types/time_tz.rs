@@ -0,0 +1,103 @@
+use byteorder::BigEndian;
+
+use crate::io::Buf;
+use crate::postgres::{PgData, PgRawBuffer, PgValue};
+
+/// A Postgres `TIMETZ` value; a time of day with an offset from UTC.
+///
+/// Postgres keeps the time and the offset as given; the time is not converted to UTC.
+///
+/// With the `chrono` Cargo feature, this is implemented for
+/// `PgTimeTz<chrono::NaiveTime, chrono::FixedOffset>`. With the `time` Cargo feature, this
+/// is implemented for `PgTimeTz<time::Time, time::UtcOffset>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PgTimeTz<Time, Offset> {
+    pub time: Time,
+    pub offset: Offset,
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/date.c
+// timetz_send / timetz_recv
+
+// The time in microseconds since midnight and the zone in seconds. Note that the zone is sent
+// as seconds west of UTC, the opposite of the sign of the offset; +02 is sent as -7200
+#[cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+pub(super) fn encode_time_tz(buf: &mut PgRawBuffer, micros: i64, offset: i32) {
+    buf.extend_from_slice(&micros.to_be_bytes());
+    buf.extend_from_slice(&(-offset).to_be_bytes());
+}
+
+// Returns the time in microseconds since midnight and the offset in seconds east of UTC
+#[cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+pub(super) fn decode_time_tz(value: PgValue<'_>) -> crate::Result<(i64, i32)> {
+    match value.try_get()? {
+        PgData::Binary(mut buf) => {
+            let micros = buf.get_i64::<BigEndian>()?;
+            let zone = buf.get_i32::<BigEndian>()?;
+
+            Ok((micros, -zone))
+        }
+
+        PgData::Text(s) => {
+            parse_time_tz(s).ok_or_else(|| decode_err!("invalid value {:?} for TIMETZ", s))
+        }
+    }
+}
+
+// e.g., 12:34:56.789+02 or 01:02:03-05:30
+#[cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+fn parse_time_tz(s: &str) -> Option<(i64, i32)> {
+    let sign = s.rfind(&['+', '-'][..])?;
+    let (time, offset) = s.split_at(sign);
+
+    let mut parts = time.splitn(3, ':');
+
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+
+    let (seconds, fraction) = match parts.next() {
+        Some(seconds) => match seconds.find('.') {
+            Some(dot) => (&seconds[..dot], &seconds[dot + 1..]),
+            None => (seconds, ""),
+        },
+
+        None => ("0", ""),
+    };
+
+    let seconds: i64 = seconds.parse().ok()?;
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // 0.5 is 500000 microseconds
+    let micros: i64 = format!("{:0<6}", fraction).parse().ok()?;
+
+    let mut offset_seconds = 0;
+
+    for (part, unit) in offset[1..].split(':').zip(&[3600, 60, 1]) {
+        offset_seconds += part.parse::<i32>().ok()? * unit;
+    }
+
+    if offset.starts_with('-') {
+        offset_seconds = -offset_seconds;
+    }
+
+    Some((
+        ((hours * 60 + minutes) * 60 + seconds) * 1_000_000 + micros,
+        offset_seconds,
+    ))
+}
+
+#[test]
+fn test_parse_time_tz() {
+    assert_eq!(parse_time_tz("12:34:56+02"), Some((45_296_000_000, 7200)));
+
+    assert_eq!(
+        parse_time_tz("01:02:03.5-05:30"),
+        Some((3_723_500_000, -19800))
+    );
+
+    assert_eq!(parse_time_tz("00:00:00+00"), Some((0, 0)));
+    assert_eq!(parse_time_tz("12:34:56"), None);
+}
@@ -0,0 +1,471 @@
+use std::convert::TryInto;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::BufMut;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::tsvector::{parse_quoted, read_str_nul, PgTsWeight};
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// https://github.com/postgres/postgres/blob/master/src/include/tsearch/ts_type.h
+const QI_VAL: u8 = 1;
+const QI_OPR: u8 = 2;
+
+const OP_NOT: u8 = 1;
+const OP_AND: u8 = 2;
+const OP_OR: u8 = 3;
+const OP_PHRASE: u8 = 4;
+
+/// A Postgres `TSQUERY`; a full-text search expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgTsQuery {
+    /// A query without any lexemes, such as the result of `to_tsquery('the')` where every word
+    /// is a stop word; it matches nothing.
+    ///
+    /// Postgres only has an empty query as a whole; an operator with an empty operand is not
+    /// a valid `TSQUERY`. Postgres also rejects an empty query that is bound as a parameter,
+    /// as it is sent in the binary format; use `''::tsquery` in the query instead.
+    Empty,
+
+    /// A lexeme to match, such as `'fat':*AB`.
+    Lexeme {
+        word: String,
+
+        /// A bit mask of the weights to match (see [`PgTsWeight::mask`]); `0` matches any weight.
+        weights: u8,
+
+        /// If the lexeme should be matched as a prefix (`:*`).
+        prefix: bool,
+    },
+
+    /// `!query`
+    Not(Box<PgTsQuery>),
+
+    /// `left & right`
+    And(Box<PgTsQuery>, Box<PgTsQuery>),
+
+    /// `left | right`
+    Or(Box<PgTsQuery>, Box<PgTsQuery>),
+
+    /// `left <distance> right`; `<->` is a distance of 1.
+    Phrase(Box<PgTsQuery>, Box<PgTsQuery>, u16),
+}
+
+impl PgTsQuery {
+    /// Creates a query matching a lexeme of any weight.
+    pub fn lexeme(word: impl Into<String>) -> Self {
+        PgTsQuery::Lexeme {
+            word: word.into(),
+            weights: 0,
+            prefix: false,
+        }
+    }
+}
+
+impl PgTsWeight {
+    /// Returns the bit of this weight in the weight mask of a [`PgTsQuery::Lexeme`].
+    pub fn mask(self) -> u8 {
+        match self {
+            PgTsWeight::A => 1 << 3,
+            PgTsWeight::B => 1 << 2,
+            PgTsWeight::C => 1 << 1,
+            PgTsWeight::D => 1,
+        }
+    }
+}
+
+impl Type<Postgres> for PgTsQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSQUERY, "TSQUERY")
+    }
+}
+
+impl Type<Postgres> for [PgTsQuery] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSQUERY, "TSQUERY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsQuery> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsQuery] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/tsquery.c
+// tsquerysend / tsqueryrecv
+
+// The items of a query are sent in prefix order; the right operand of a binary operator is
+// sent _before_ the left operand
+
+impl Encode<Postgres> for PgTsQuery {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.put_i32::<NetworkEndian>(self.len() as i32);
+
+        encode_item(self, buf);
+    }
+}
+
+impl PgTsQuery {
+    // Number of items in the query
+    fn len(&self) -> usize {
+        match self {
+            PgTsQuery::Empty => 0,
+            PgTsQuery::Lexeme { .. } => 1,
+            PgTsQuery::Not(query) => 1 + query.len(),
+            PgTsQuery::And(left, right)
+            | PgTsQuery::Or(left, right)
+            | PgTsQuery::Phrase(left, right, _) => 1 + left.len() + right.len(),
+        }
+    }
+}
+
+fn encode_item(query: &PgTsQuery, buf: &mut Vec<u8>) {
+    match query {
+        PgTsQuery::Empty => {}
+
+        PgTsQuery::Lexeme {
+            word,
+            weights,
+            prefix,
+        } => {
+            buf.put_u8(QI_VAL);
+            buf.put_u8(*weights);
+            buf.put_u8(*prefix as u8);
+            buf.put_str_nul(word);
+        }
+
+        PgTsQuery::Not(query) => {
+            buf.put_u8(QI_OPR);
+            buf.put_u8(OP_NOT);
+
+            encode_item(query, buf);
+        }
+
+        PgTsQuery::And(left, right) | PgTsQuery::Or(left, right) => {
+            buf.put_u8(QI_OPR);
+            buf.put_u8(if let PgTsQuery::And(..) = query {
+                OP_AND
+            } else {
+                OP_OR
+            });
+
+            encode_item(right, buf);
+            encode_item(left, buf);
+        }
+
+        PgTsQuery::Phrase(left, right, distance) => {
+            buf.put_u8(QI_OPR);
+            buf.put_u8(OP_PHRASE);
+            buf.put_u16::<NetworkEndian>(*distance);
+
+            encode_item(right, buf);
+            encode_item(left, buf);
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTsQuery {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => decode(buf),
+            PgData::Text(s) => parse(s),
+        }
+    }
+}
+
+fn decode(mut buf: &[u8]) -> crate::Result<PgTsQuery> {
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+    let len: usize = len
+        .try_into()
+        .map_err(|_| decode_err!("invalid number of items in tsquery: {}", len))?;
+
+    if len == 0 {
+        return Ok(PgTsQuery::Empty);
+    }
+
+    // Each item is at least 2 bytes
+    if len > buf.len() / 2 {
+        return Err(decode_err!(
+            "expected {} items in tsquery; only {} bytes remain",
+            len,
+            buf.len()
+        ));
+    }
+
+    let query = decode_item(&mut buf)?;
+
+    if query.len() != len {
+        return Err(decode_err!(
+            "expected {} items in tsquery; decoded {}",
+            len,
+            query.len()
+        ));
+    }
+
+    Ok(query)
+}
+
+fn decode_item(buf: &mut &[u8]) -> crate::Result<PgTsQuery> {
+    match buf.read_u8().map_err(Error::decode)? {
+        QI_VAL => {
+            let weights = buf.read_u8().map_err(Error::decode)?;
+            let prefix = buf.read_u8().map_err(Error::decode)? != 0;
+            let word = read_str_nul(buf)?;
+
+            Ok(PgTsQuery::Lexeme {
+                word,
+                weights,
+                prefix,
+            })
+        }
+
+        QI_OPR => match buf.read_u8().map_err(Error::decode)? {
+            OP_NOT => Ok(PgTsQuery::Not(Box::new(decode_item(buf)?))),
+
+            OP_AND => {
+                let right = decode_item(buf)?;
+                let left = decode_item(buf)?;
+
+                Ok(PgTsQuery::And(Box::new(left), Box::new(right)))
+            }
+
+            OP_OR => {
+                let right = decode_item(buf)?;
+                let left = decode_item(buf)?;
+
+                Ok(PgTsQuery::Or(Box::new(left), Box::new(right)))
+            }
+
+            OP_PHRASE => {
+                let distance = buf.read_u16::<NetworkEndian>().map_err(Error::decode)?;
+                let right = decode_item(buf)?;
+                let left = decode_item(buf)?;
+
+                Ok(PgTsQuery::Phrase(Box::new(left), Box::new(right), distance))
+            }
+
+            operator => Err(decode_err!("unknown tsquery operator: {}", operator)),
+        },
+
+        ty => Err(decode_err!("unknown tsquery item type: {}", ty)),
+    }
+}
+
+// Parse the text form of a tsquery; e.g., 'fat' & ( 'rat' | !'cat':*A )
+// In order of precedence (from loosest to tightest) the operators are | & <-> !
+fn parse(s: &str) -> crate::Result<PgTsQuery> {
+    // An empty query is output as an empty string
+    if s.trim().is_empty() {
+        return Ok(PgTsQuery::Empty);
+    }
+
+    let mut chars = s.chars().peekable();
+    let query = parse_or(&mut chars)?;
+
+    skip_whitespace(&mut chars);
+
+    if chars.peek().is_some() {
+        return Err(decode_err!("unexpected trailing input in tsquery: {:?}", s));
+    }
+
+    Ok(query)
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_or(chars: &mut Peekable<Chars<'_>>) -> crate::Result<PgTsQuery> {
+    let mut query = parse_and(chars)?;
+
+    loop {
+        skip_whitespace(chars);
+
+        if chars.peek() != Some(&'|') {
+            break Ok(query);
+        }
+
+        chars.next();
+
+        query = PgTsQuery::Or(Box::new(query), Box::new(parse_and(chars)?));
+    }
+}
+
+fn parse_and(chars: &mut Peekable<Chars<'_>>) -> crate::Result<PgTsQuery> {
+    let mut query = parse_phrase(chars)?;
+
+    loop {
+        skip_whitespace(chars);
+
+        if chars.peek() != Some(&'&') {
+            break Ok(query);
+        }
+
+        chars.next();
+
+        query = PgTsQuery::And(Box::new(query), Box::new(parse_phrase(chars)?));
+    }
+}
+
+fn parse_phrase(chars: &mut Peekable<Chars<'_>>) -> crate::Result<PgTsQuery> {
+    let mut query = parse_not(chars)?;
+
+    loop {
+        skip_whitespace(chars);
+
+        if chars.peek() != Some(&'<') {
+            break Ok(query);
+        }
+
+        chars.next();
+
+        let mut distance = String::new();
+
+        for c in chars.by_ref() {
+            if c == '>' {
+                break;
+            }
+
+            distance.push(c);
+        }
+
+        let distance = match &*distance {
+            "-" => 1,
+            distance => distance
+                .parse()
+                .map_err(|_| decode_err!("invalid tsquery phrase distance: {:?}", distance))?,
+        };
+
+        query = PgTsQuery::Phrase(Box::new(query), Box::new(parse_not(chars)?), distance);
+    }
+}
+
+fn parse_not(chars: &mut Peekable<Chars<'_>>) -> crate::Result<PgTsQuery> {
+    skip_whitespace(chars);
+
+    match chars.peek() {
+        Some('!') => {
+            chars.next();
+
+            Ok(PgTsQuery::Not(Box::new(parse_not(chars)?)))
+        }
+
+        Some('(') => {
+            chars.next();
+
+            let query = parse_or(chars)?;
+
+            skip_whitespace(chars);
+
+            if chars.next() != Some(')') {
+                return Err(Error::Decode("expected `)` in tsquery".into()));
+            }
+
+            Ok(query)
+        }
+
+        _ => parse_lexeme(chars),
+    }
+}
+
+fn parse_lexeme(chars: &mut Peekable<Chars<'_>>) -> crate::Result<PgTsQuery> {
+    let word = parse_quoted(chars)?;
+    let mut weights = 0;
+    let mut prefix = false;
+
+    if chars.peek() == Some(&':') {
+        chars.next();
+
+        while let Some(&c) = chars.peek() {
+            if c == '*' {
+                prefix = true;
+            } else if let Some(weight) = PgTsWeight::from_char(c) {
+                weights |= weight.mask();
+            } else {
+                break;
+            }
+
+            chars.next();
+        }
+    }
+
+    Ok(PgTsQuery::Lexeme {
+        word,
+        weights,
+        prefix,
+    })
+}
+
+#[test]
+fn test_decode_tsquery() {
+    // 'fat' & ( 'rat' | !'cat':*A )
+    let buf = b"\0\0\0\x06\x02\x02\x02\x03\x02\x01\x01\x08\x01cat\0\x01\0\0rat\0\x01\0\0fat\0";
+
+    let expected = PgTsQuery::And(
+        Box::new(PgTsQuery::lexeme("fat")),
+        Box::new(PgTsQuery::Or(
+            Box::new(PgTsQuery::lexeme("rat")),
+            Box::new(PgTsQuery::Not(Box::new(PgTsQuery::Lexeme {
+                word: "cat".into(),
+                weights: PgTsWeight::A.mask(),
+                prefix: true,
+            }))),
+        )),
+    );
+
+    assert_eq!(decode(buf).unwrap(), expected);
+    assert_eq!(parse("'fat' & ( 'rat' | !'cat':*A )").unwrap(), expected);
+
+    let mut encoded = PgRawBuffer::default();
+    expected.encode(&mut encoded);
+
+    assert_eq!(&**encoded, &buf[..]);
+}
+
+#[test]
+fn test_decode_empty_tsquery() {
+    let buf = b"\0\0\0\0";
+
+    assert_eq!(decode(buf).unwrap(), PgTsQuery::Empty);
+    assert_eq!(parse("").unwrap(), PgTsQuery::Empty);
+
+    let mut encoded = PgRawBuffer::default();
+    PgTsQuery::Empty.encode(&mut encoded);
+
+    assert_eq!(&**encoded, &buf[..]);
+
+    // more items than the bytes that remain could hold
+    assert!(decode(b"\x7f\xff\xff\xff\x01\0\0a\0").is_err());
+}
+
+#[test]
+fn test_parse_tsquery_precedence() {
+    let query = parse("'a' | 'b' & 'c' <-> !'d' <2> 'e'").unwrap();
+
+    let expected = PgTsQuery::Or(
+        Box::new(PgTsQuery::lexeme("a")),
+        Box::new(PgTsQuery::And(
+            Box::new(PgTsQuery::lexeme("b")),
+            Box::new(PgTsQuery::Phrase(
+                Box::new(PgTsQuery::Phrase(
+                    Box::new(PgTsQuery::lexeme("c")),
+                    Box::new(PgTsQuery::Not(Box::new(PgTsQuery::lexeme("d")))),
+                    1,
+                )),
+                Box::new(PgTsQuery::lexeme("e")),
+                2,
+            )),
+        )),
+    );
+
+    assert_eq!(query, expected);
+}
@@ -0,0 +1,315 @@
+use std::convert::TryInto;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::BufMut;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// https://github.com/postgres/postgres/blob/master/src/include/tsearch/ts_type.h
+const MAX_POSITION: u16 = (1 << 14) - 1;
+
+/// A Postgres `TSVECTOR`; a document prepared for full-text search.
+///
+/// A `tsvector` is a list of distinct lexemes, each with the positions it occurs at in the
+/// document. Postgres sorts the lexemes when receiving a `tsvector`, however, the positions of
+/// each lexeme must be given in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgTsVector {
+    pub lexemes: Vec<PgLexeme>,
+}
+
+/// A lexeme of a [`PgTsVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgLexeme {
+    pub word: String,
+
+    /// The positions of this lexeme in the document; this may be empty.
+    pub positions: Vec<PgLexemePosition>,
+}
+
+/// A position of a lexeme in a document, in the range `1..=16383`, with its weight.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PgLexemePosition {
+    pub position: u16,
+    pub weight: PgTsWeight,
+}
+
+/// The weight of a lexeme position, used to mark lexemes from different parts of a document.
+///
+/// `D` is the default weight and is not shown in the text form of a `tsvector`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PgTsWeight {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl PgTsWeight {
+    fn from_bits(bits: u16) -> Self {
+        match bits & 0b11 {
+            3 => PgTsWeight::A,
+            2 => PgTsWeight::B,
+            1 => PgTsWeight::C,
+            _ => PgTsWeight::D,
+        }
+    }
+
+    fn to_bits(self) -> u16 {
+        match self {
+            PgTsWeight::A => 3,
+            PgTsWeight::B => 2,
+            PgTsWeight::C => 1,
+            PgTsWeight::D => 0,
+        }
+    }
+
+    pub(super) fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            'A' | 'a' => PgTsWeight::A,
+            'B' | 'b' => PgTsWeight::B,
+            'C' | 'c' => PgTsWeight::C,
+            'D' | 'd' => PgTsWeight::D,
+            _ => return None,
+        })
+    }
+}
+
+impl Type<Postgres> for PgTsVector {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSVECTOR, "TSVECTOR")
+    }
+}
+
+impl Type<Postgres> for [PgTsVector] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSVECTOR, "TSVECTOR[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsVector> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsVector] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/tsvector.c
+// tsvectorsend / tsvectorrecv
+
+impl Encode<Postgres> for PgTsVector {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.put_i32::<NetworkEndian>(self.lexemes.len() as i32);
+
+        for lexeme in &self.lexemes {
+            buf.put_str_nul(&lexeme.word);
+            buf.put_u16::<NetworkEndian>(lexeme.positions.len() as u16);
+
+            for position in &lexeme.positions {
+                buf.put_u16::<NetworkEndian>(
+                    (position.weight.to_bits() << 14) | (position.position.min(MAX_POSITION)),
+                );
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self
+            .lexemes
+            .iter()
+            .map(|lexeme| lexeme.word.len() + 3 + lexeme.positions.len() * 2)
+            .sum::<usize>()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTsVector {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => decode(buf),
+            PgData::Text(s) => parse(s),
+        }
+    }
+}
+
+fn decode(mut buf: &[u8]) -> crate::Result<PgTsVector> {
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+    let len: usize = len
+        .try_into()
+        .map_err(|_| decode_err!("invalid number of lexemes in tsvector: {}", len))?;
+
+    // Each lexeme is at least 3 bytes; the capacity is not trusted beyond what remains
+    let mut lexemes = Vec::with_capacity(len.min(buf.len() / 3));
+
+    for _ in 0..len {
+        let word = read_str_nul(&mut buf)?;
+        let num_positions = buf.read_u16::<NetworkEndian>().map_err(Error::decode)?;
+
+        let mut positions = Vec::with_capacity((num_positions as usize).min(buf.len() / 2));
+
+        for _ in 0..num_positions {
+            let bits = buf.read_u16::<NetworkEndian>().map_err(Error::decode)?;
+
+            positions.push(PgLexemePosition {
+                position: bits & MAX_POSITION,
+                weight: PgTsWeight::from_bits(bits >> 14),
+            });
+        }
+
+        lexemes.push(PgLexeme { word, positions });
+    }
+
+    Ok(PgTsVector { lexemes })
+}
+
+// Read a NUL-terminated string, as lexemes are sent in the binary format
+pub(super) fn read_str_nul(buf: &mut &[u8]) -> crate::Result<String> {
+    let end = buf.iter().position(|&b| b == 0).ok_or_else(|| {
+        Error::Decode("unexpected end of input: expected NUL-terminated string".into())
+    })?;
+
+    let s = std::str::from_utf8(&buf[..end]).map_err(Error::decode)?;
+    *buf = &buf[end + 1..];
+
+    Ok(s.to_owned())
+}
+
+// Parse the text form of a tsvector; e.g., 'fat':2 'rat':3,5A
+fn parse(s: &str) -> crate::Result<PgTsVector> {
+    let mut chars = s.chars().peekable();
+    let mut lexemes = Vec::new();
+
+    loop {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let word = parse_quoted(&mut chars)?;
+        let mut positions = Vec::new();
+
+        if chars.peek() == Some(&':') {
+            chars.next();
+
+            loop {
+                let mut position = String::new();
+
+                while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                    position.push(*c);
+                    chars.next();
+                }
+
+                let position: u16 = position
+                    .parse()
+                    .map_err(|_| decode_err!("invalid lexeme position in tsvector: {:?}", s))?;
+
+                let weight = match chars.peek().and_then(|&c| PgTsWeight::from_char(c)) {
+                    Some(weight) => {
+                        chars.next();
+                        weight
+                    }
+
+                    None => PgTsWeight::D,
+                };
+
+                positions.push(PgLexemePosition { position, weight });
+
+                if chars.peek() == Some(&',') {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        lexemes.push(PgLexeme { word, positions });
+    }
+
+    Ok(PgTsVector { lexemes })
+}
+
+// Parse a lexeme in single quotes as output by Postgres; quotes and backslashes are escaped
+pub(super) fn parse_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> crate::Result<String> {
+    if chars.next() != Some('\'') {
+        return Err(Error::Decode("expected a quoted lexeme".into()));
+    }
+
+    let mut word = String::new();
+
+    loop {
+        match chars.next() {
+            Some('\'') if chars.peek() == Some(&'\'') => {
+                chars.next();
+                word.push('\'');
+            }
+
+            Some('\'') => break,
+
+            Some('\\') => match chars.next() {
+                Some(c) => word.push(c),
+                None => return Err(Error::Decode("unexpected end of lexeme".into())),
+            },
+
+            Some(c) => word.push(c),
+
+            None => return Err(Error::Decode("unexpected end of lexeme".into())),
+        }
+    }
+
+    Ok(word)
+}
+
+#[test]
+fn test_decode_tsvector() {
+    let buf = b"\0\0\0\x02fat\0\0\x01\0\x02rat\0\0\x02\0\x03\xc0\x05";
+
+    let expected = PgTsVector {
+        lexemes: vec![
+            PgLexeme {
+                word: "fat".into(),
+                positions: vec![PgLexemePosition {
+                    position: 2,
+                    weight: PgTsWeight::D,
+                }],
+            },
+            PgLexeme {
+                word: "rat".into(),
+                positions: vec![
+                    PgLexemePosition {
+                        position: 3,
+                        weight: PgTsWeight::D,
+                    },
+                    PgLexemePosition {
+                        position: 5,
+                        weight: PgTsWeight::A,
+                    },
+                ],
+            },
+        ],
+    };
+
+    assert_eq!(decode(buf).unwrap(), expected);
+    assert_eq!(parse("'fat':2 'rat':3,5A").unwrap(), expected);
+
+    let mut encoded = PgRawBuffer::default();
+    expected.encode(&mut encoded);
+
+    assert_eq!(&**encoded, &buf[..]);
+}
+
+#[test]
+fn test_parse_tsvector_escapes() {
+    let vector = parse(r"'it''s' 'back\\slash'").unwrap();
+
+    assert_eq!(vector.lexemes[0].word, "it's");
+    assert_eq!(vector.lexemes[1].word, r"back\slash");
+    assert!(vector.lexemes[1].positions.is_empty());
+}
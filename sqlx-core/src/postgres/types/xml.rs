@@ -0,0 +1,65 @@
+use std::fmt::{self, Display};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// A Postgres `XML` value; an XML document or content fragment, as text.
+///
+/// Postgres checks that the value is well-formed XML when receiving it but otherwise keeps
+/// the text as-is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgXml(pub String);
+
+impl Display for PgXml {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgXml {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::XML, "XML")
+    }
+}
+
+impl Type<Postgres> for [PgXml] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_XML, "XML[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgXml> {
+    fn type_info() -> PgTypeInfo {
+        <[PgXml] as Type<Postgres>>::type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/xml.c
+// xml_send / xml_recv
+
+impl Encode<Postgres> for PgXml {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        // The binary format is the text of the value (in the client encoding, always UTF-8)
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgXml {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => std::str::from_utf8(buf)
+                .map(|s| PgXml(s.to_owned()))
+                .map_err(Error::decode),
+
+            PgData::Text(s) => Ok(PgXml(s.to_owned())),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// Collect the fields of a slice of records into parallel arrays, one for each field.
+///
+/// Postgres can expand arrays into rows with `UNNEST`, so a batch of records can be inserted in
+/// a single statement (and a single round-trip) by binding an array for each column. Each field
+/// is cloned into a `Vec`; a nested field can be given as a path, e.g., `price.amount`.
+///
+/// ```rust,ignore
+/// struct Product {
+///     name: String,
+///     price: i64,
+/// }
+///
+/// let products: Vec<Product> = load_products();
+/// let (names, prices) = sqlx::unnest_columns!(products => name, price);
+///
+/// sqlx::query("INSERT INTO products (name, price) SELECT * FROM UNNEST($1::text[], $2::int8[])")
+///     .bind(names)
+///     .bind(prices)
+///     .execute(&mut conn)
+///     .await?;
+/// ```
+#[macro_export]
+macro_rules! unnest_columns {
+    ($records:expr => $($($field:ident).+),+ $(,)?) => {{
+        let records = &$records[..];
+
+        ($(
+            records
+                .iter()
+                .map(|record| ::std::clone::Clone::clone(&record.$($field).+))
+                .collect::<::std::vec::Vec<_>>(),
+        )+)
+    }};
+}
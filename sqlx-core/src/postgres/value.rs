@@ -1,5 +1,8 @@
+use crate::decode::Decode;
 use crate::error::UnexpectedNullError;
+use crate::postgres::protocol::TypeId;
 use crate::postgres::{PgTypeInfo, Postgres};
+use crate::types::{Type, TypeInfo};
 use crate::value::RawValue;
 use std::str::from_utf8;
 
@@ -13,6 +16,9 @@ pub enum PgData<'c> {
 pub struct PgValue<'c> {
     type_info: Option<PgTypeInfo>,
     data: Option<PgData<'c>>,
+
+    // Offset from UTC, in seconds, of the time zone of the session the value was received in
+    time_zone_offset: Option<i32>,
 }
 
 impl<'c> PgValue<'c> {
@@ -32,10 +38,25 @@ impl<'c> PgValue<'c> {
         self.data
     }
 
+    /// Gets the offset from UTC, in seconds, of the `TimeZone` of the session; or, `None` if
+    /// the time zone is unknown or is not a fixed offset.
+    #[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+    pub(crate) fn time_zone_offset(&self) -> Option<i32> {
+        self.time_zone_offset
+    }
+
+    pub(crate) fn with_time_zone_offset(self, time_zone_offset: Option<i32>) -> Self {
+        Self {
+            time_zone_offset,
+            ..self
+        }
+    }
+
     pub(crate) fn null() -> Self {
         Self {
             type_info: None,
             data: None,
+            time_zone_offset: None,
         }
     }
 
@@ -43,6 +64,7 @@ impl<'c> PgValue<'c> {
         Self {
             type_info: Some(type_info),
             data: Some(PgData::Binary(buf)),
+            time_zone_offset: None,
         }
     }
 
@@ -50,6 +72,7 @@ impl<'c> PgValue<'c> {
         Ok(Self {
             type_info: Some(type_info),
             data: Some(PgData::Text(from_utf8(&buf).map_err(crate::Error::decode)?)),
+            time_zone_offset: None,
         })
     }
 
@@ -58,6 +81,7 @@ impl<'c> PgValue<'c> {
         Self {
             type_info: None,
             data: Some(PgData::Binary(buf)),
+            time_zone_offset: None,
         }
     }
 
@@ -65,6 +89,7 @@ impl<'c> PgValue<'c> {
         Self {
             type_info: None,
             data: Some(PgData::Text(s)),
+            time_zone_offset: None,
         }
     }
 }
@@ -80,3 +105,80 @@ impl<'c> RawValue<'c> for PgValue<'c> {
         }
     }
 }
+
+/// An owned copy of a value of a row, which can be decoded after the row is gone.
+///
+/// This is the type of the values collected by a `#[sqlx(rest)]` field of a derived
+/// `FromRow`; e.g., `HashMap<String, PgRawValue>`. Decoding a `PgRawValue` from a row
+/// never fails; a `NULL` is kept as a `PgRawValue` that [is_null](#method.is_null).
+#[derive(Debug, Clone)]
+pub struct PgRawValue {
+    type_info: Option<PgTypeInfo>,
+    data: Option<PgRawData>,
+    time_zone_offset: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+enum PgRawData {
+    Binary(Box<[u8]>),
+    Text(Box<str>),
+}
+
+impl PgRawValue {
+    /// Returns the type of this value; or, `None` if this is a `NULL` value.
+    pub fn type_info(&self) -> Option<&PgTypeInfo> {
+        self.data.as_ref().and(self.type_info.as_ref())
+    }
+
+    /// Returns `true` if this is a `NULL` value.
+    pub fn is_null(&self) -> bool {
+        self.data.is_none()
+    }
+
+    /// Decode this value, as with [`Row::try_get`](crate::row::Row::try_get).
+    pub fn try_decode<'a, T>(&'a self) -> crate::Result<T>
+    where
+        T: Type<Postgres> + Decode<'a, Postgres>,
+    {
+        if let Some(expected_ty) = self.type_info() {
+            if !expected_ty.compatible(&T::type_info()) {
+                return Err(crate::Error::mismatched_types::<Postgres, T>(
+                    expected_ty.clone(),
+                ));
+            }
+        }
+
+        T::decode(self.as_value())
+    }
+
+    fn as_value(&self) -> PgValue<'_> {
+        PgValue {
+            type_info: self.type_info.clone(),
+            data: self.data.as_ref().map(|data| match data {
+                PgRawData::Binary(buf) => PgData::Binary(buf),
+                PgRawData::Text(s) => PgData::Text(s),
+            }),
+            time_zone_offset: self.time_zone_offset,
+        }
+    }
+}
+
+// Any value may be read as a `PgRawValue` so it claims no type in particular
+impl Type<Postgres> for PgRawValue {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::UNKNOWN, "UNKNOWN")
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgRawValue {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        Ok(Self {
+            type_info: value.type_info,
+            data: value.data.map(|data| match data {
+                PgData::Binary(buf) => PgRawData::Binary(buf.into()),
+                PgData::Text(s) => PgRawData::Text(s.into()),
+            }),
+            time_zone_offset: value.time_zone_offset,
+        })
+    }
+}
@@ -1,9 +1,14 @@
+use std::collections::HashMap;
+use std::io;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use async_stream::try_stream;
 use futures_core::Stream;
 use futures_util::future::ready;
 use futures_util::TryFutureExt;
+use once_cell::sync::Lazy;
 
 use crate::arguments::Arguments;
 use crate::cursor::{Cursor, HasCursor};
@@ -11,6 +16,9 @@ use crate::database::Database;
 use crate::encode::Encode;
 use crate::executor::{Execute, Executor, RefExecutor};
 use crate::row::HasRow;
+use crate::runtime::fs;
+#[cfg(feature = "json")]
+use crate::types::Json;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters. Returned by [`query`][crate::query::query].
@@ -21,6 +29,7 @@ where
 {
     pub(crate) query: &'q str,
     pub(crate) arguments: DB::Arguments,
+    pub(crate) prefetch: usize,
     database: PhantomData<DB>,
 }
 
@@ -37,8 +46,8 @@ pub struct Map<'q, DB, F>
 where
     DB: Database,
 {
-    query: Query<'q, DB>,
-    mapper: F,
+    pub(crate) query: Query<'q, DB>,
+    pub(crate) mapper: F,
 }
 
 // necessary because we can't have a blanket impl for `Query<'q, DB>`
@@ -51,6 +60,11 @@ where
     fn into_parts(self) -> (&'q str, Option<DB::Arguments>) {
         (self.query, Some(self.arguments))
     }
+
+    #[inline]
+    fn prefetch(&self) -> usize {
+        self.prefetch
+    }
 }
 
 impl<'q, DB> Query<'q, DB>
@@ -74,11 +88,68 @@ where
         self
     }
 
-    #[doc(hidden)]
+    /// Bind a value for use with this SQL query as a parameter of the SQL type of `T`, rather
+    /// than the SQL type of the value.
+    ///
+    /// The value is converted into a `T` which is then bound; e.g., an `i32` may be bound as
+    /// an `INT8` (`i64`). This is useful to declare the type of a parameter that is only used
+    /// in a polymorphic context, where the database could not otherwise determine it.
+    ///
+    /// ```rust,ignore
+    /// // `pg_typeof($1)` is `bigint`
+    /// sqlx::query("SELECT pg_typeof($1)::text")
+    ///     .bind_as::<i64, _>(1_i32)
+    /// ```
+    pub fn bind_as<T, V>(self, value: V) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+        V: Into<T>,
+    {
+        self.bind(value.into())
+    }
+
+    /// Bind an optional value for use with this SQL query; `None` is bound as a `NULL`.
+    ///
+    /// The parameter is always of the SQL type of `T`, even for `None`, and `T` can be named
+    /// where `.bind(None)` would not compile without a type annotation. This is useful for an
+    /// optional filter where the database could not otherwise determine the type of the
+    /// parameter.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("SELECT * FROM users WHERE ($1 IS NULL OR team_id = $1)")
+    ///     .bind_opt::<i32>(team_id)
+    /// ```
+    pub fn bind_opt<T>(self, value: Option<T>) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        self.bind(value)
+    }
+
+    /// Bind a value for use with this SQL query as JSON; the same as `.bind(Json(value))`.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("INSERT INTO events (payload) VALUES ($1)")
+    ///     .bind_json(&event)
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn bind_json<T>(self, value: T) -> Self
+    where
+        Json<T>: Type<DB>,
+        Json<T>: Encode<DB>,
+    {
+        self.bind(Json(value))
+    }
+
+    /// Replace the arguments of this query with arguments that were built separately.
     pub fn bind_all(self, arguments: DB::Arguments) -> Query<'q, DB> {
         Query {
             query: self.query,
             arguments,
+            prefetch: self.prefetch,
             database: PhantomData,
         }
     }
@@ -246,6 +317,58 @@ where
     Query {
         database: PhantomData,
         arguments: Default::default(),
+        prefetch: 0,
         query: sql,
     }
 }
+
+/// Construct a raw SQL query from the contents of a file, which is read at runtime.
+///
+/// Unlike `query_file!`, this does not check the query at compile time and needs no access to
+/// the database to build. A relative path is resolved against the current working directory.
+///
+/// The contents of each file are read once and kept for the lifetime of the program; later
+/// calls with the same path do not read the file again.
+///
+/// ```rust,ignore
+/// let accounts = sqlx::query_from_file("queries/accounts.sql")
+///     .await?
+///     .bind(user_id)
+///     .fetch_all(&mut conn)
+///     .await?;
+/// ```
+///
+/// # Errors
+///  * [`Io`] if the file could not be read; e.g., it does not exist.
+///
+/// [`Io`]: crate::Error::Io
+pub async fn query_from_file<DB>(path: impl AsRef<Path>) -> crate::Result<Query<'static, DB>>
+where
+    DB: Database,
+{
+    Ok(query(read_query_file(path.as_ref()).await?))
+}
+
+// path -> contents of the query file
+// The contents are leaked so that a query of them is not tied to the lifetime of the cache
+static QUERY_FILES: Lazy<Mutex<HashMap<PathBuf, &'static str>>> = Lazy::new(Default::default);
+
+async fn read_query_file(path: &Path) -> crate::Result<&'static str> {
+    if let Some(sql) = QUERY_FILES.lock().unwrap().get(path) {
+        return Ok(*sql);
+    }
+
+    let sql = fs::read_to_string(path).await.map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("failed to read query file {}: {}", path.display(), err),
+        )
+    })?;
+
+    let mut files = QUERY_FILES.lock().unwrap();
+
+    // another task may have read the same file in the meantime
+    Ok(*files
+        .entry(path.to_owned())
+        .or_insert_with(|| Box::leak(sql.into_boxed_str())))
+}
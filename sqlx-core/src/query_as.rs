@@ -4,6 +4,8 @@ use crate::arguments::Arguments;
 use crate::database::Database;
 use crate::encode::Encode;
 use crate::executor::Execute;
+#[cfg(feature = "json")]
+use crate::types::Json;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters, mapped to a concrete type
@@ -15,7 +17,8 @@ where
     DB: Database,
 {
     query: &'q str,
-    arguments: <DB as Database>::Arguments,
+    pub(crate) arguments: <DB as Database>::Arguments,
+    pub(crate) prefetch: usize,
     database: PhantomData<DB>,
     output: PhantomData<O>,
 }
@@ -34,6 +37,45 @@ where
         self.arguments.add(value);
         self
     }
+
+    /// Bind a value for use with this SQL query as a parameter of the SQL type of `T`.
+    ///
+    /// See [`Query::bind_as`](crate::query::Query::bind_as).
+    #[inline]
+    pub fn bind_as<T, V>(self, value: V) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+        V: Into<T>,
+    {
+        self.bind(value.into())
+    }
+
+    /// Bind an optional value for use with this SQL query; `None` is bound as a `NULL`.
+    ///
+    /// See [`Query::bind_opt`](crate::query::Query::bind_opt).
+    #[inline]
+    pub fn bind_opt<T>(self, value: Option<T>) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        self.bind(value)
+    }
+
+    /// Bind a value for use with this SQL query as JSON.
+    ///
+    /// See [`Query::bind_json`](crate::query::Query::bind_json).
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    #[inline]
+    pub fn bind_json<T>(self, value: T) -> Self
+    where
+        Json<T>: Type<DB>,
+        Json<T>: Encode<DB>,
+    {
+        self.bind(Json(value))
+    }
 }
 
 impl<'q, DB, O: Send> Execute<'q, DB> for QueryAs<'q, DB, O>
@@ -44,6 +86,11 @@ where
     fn into_parts(self) -> (&'q str, Option<<DB as Database>::Arguments>) {
         (self.query, Some(self.arguments))
     }
+
+    #[inline]
+    fn prefetch(&self) -> usize {
+        self.prefetch
+    }
 }
 
 /// Construct a raw SQL query that is mapped to a concrete type
@@ -57,6 +104,7 @@ where
     QueryAs {
         query: sql,
         arguments: Default::default(),
+        prefetch: 0,
         database: PhantomData,
         output: PhantomData,
     }
@@ -82,6 +130,8 @@ macro_rules! make_query_as {
                 O: 'e + Send + Unpin + for<'c> crate::row::FromRow<'c, $row<'c>>,
                 'q: 'e;
 
+            /// Execute the query and decode every row returned; a query that returns no rows
+            /// results in an empty `Vec`.
             fn fetch_all<'e, E>(
                 self,
                 executor: E,
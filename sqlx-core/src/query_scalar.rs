@@ -0,0 +1,216 @@
+use crate::database::Database;
+use crate::encode::Encode;
+use crate::executor::Execute;
+use crate::query_as::{query_as, QueryAs};
+#[cfg(feature = "json")]
+use crate::types::Json;
+use crate::types::Type;
+
+/// Raw SQL query with bind parameters, mapped to the first column of each row. Returned
+/// by [`query_scalar`](fn.query_scalar.html).
+#[must_use = "query must be executed to affect database"]
+pub struct QueryScalar<'q, DB, O>
+where
+    DB: Database,
+{
+    pub(crate) inner: QueryAs<'q, DB, (O,)>,
+}
+
+impl<'q, DB, O> QueryScalar<'q, DB, O>
+where
+    DB: Database,
+{
+    /// Bind a value for use with this SQL query.
+    #[inline]
+    pub fn bind<T>(self, value: T) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        QueryScalar {
+            inner: self.inner.bind(value),
+        }
+    }
+
+    /// Bind a value for use with this SQL query as a parameter of the SQL type of `T`.
+    ///
+    /// See [`Query::bind_as`](crate::query::Query::bind_as).
+    #[inline]
+    pub fn bind_as<T, V>(self, value: V) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+        V: Into<T>,
+    {
+        QueryScalar {
+            inner: self.inner.bind_as::<T, V>(value),
+        }
+    }
+
+    /// Bind a value for use with this SQL query as JSON.
+    ///
+    /// See [`Query::bind_json`](crate::query::Query::bind_json).
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    #[inline]
+    pub fn bind_json<T>(self, value: T) -> Self
+    where
+        Json<T>: Type<DB>,
+        Json<T>: Encode<DB>,
+    {
+        QueryScalar {
+            inner: self.inner.bind_json(value),
+        }
+    }
+}
+
+impl<'q, DB, O: Send> Execute<'q, DB> for QueryScalar<'q, DB, O>
+where
+    DB: Database,
+{
+    #[inline]
+    fn into_parts(self) -> (&'q str, Option<<DB as Database>::Arguments>) {
+        self.inner.into_parts()
+    }
+
+    #[inline]
+    fn prefetch(&self) -> usize {
+        self.inner.prefetch()
+    }
+}
+
+/// Construct a raw SQL query that is mapped to the value of the first column of each row.
+///
+/// Returns [`QueryScalar`].
+///
+/// ```rust,ignore
+/// let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+pub fn query_scalar<DB, O>(sql: &str) -> QueryScalar<'_, DB, O>
+where
+    DB: Database,
+{
+    QueryScalar {
+        inner: query_as(sql),
+    }
+}
+
+// Like [QueryAs], [QueryScalar] needs database-specific traits; these delegate to the
+// [QueryAs] trait of the database to decode a 1-tuple from each row and then unwrap it
+
+#[allow(unused_macros)]
+macro_rules! make_query_scalar {
+    ($name:ident, $query_as:ident, $db:ident, $row:ident) => {
+        pub trait $name<'q, O> {
+            fn fetch<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::stream::BoxStream<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + Unpin,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
+
+            fn fetch_all<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<Vec<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
+
+            fn fetch_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
+
+            fn fetch_optional<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<Option<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
+        }
+
+        impl<'q, O> $name<'q, O> for crate::query_scalar::QueryScalar<'q, $db, O> {
+            fn fetch<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::stream::BoxStream<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + Unpin,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use futures_util::TryStreamExt;
+
+                Box::pin($query_as::fetch(self.inner, executor).map_ok(|(value,)| value))
+            }
+
+            fn fetch_all<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<Vec<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use futures_util::TryFutureExt;
+
+                Box::pin(
+                    $query_as::fetch_all(self.inner, executor)
+                        .map_ok(|rows| rows.into_iter().map(|(value,)| value).collect()),
+                )
+            }
+
+            fn fetch_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use futures_util::TryFutureExt;
+
+                Box::pin($query_as::fetch_one(self.inner, executor).map_ok(|(value,)| value))
+            }
+
+            fn fetch_optional<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<Option<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send,
+                (O,): for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use futures_util::TryFutureExt;
+
+                Box::pin(
+                    $query_as::fetch_optional(self.inner, executor)
+                        .map_ok(|row| row.map(|(value,)| value)),
+                )
+            }
+        }
+    };
+}
@@ -1,7 +1,14 @@
 //! Contains the `ColumnIndex`, `Row`, and `FromRow` traits.
 
+use std::any::type_name;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
 use crate::database::Database;
 use crate::decode::Decode;
+#[cfg(feature = "json")]
+use crate::types::Json;
 use crate::types::{Type, TypeInfo};
 use crate::value::{HasRawValue, RawValue};
 
@@ -40,11 +47,45 @@ where
     }
 }
 
+/// A column name that also matches a column whose name differs only in ASCII case;
+/// e.g., `CaseInsensitive("name")` matches a column named `NAME`.
+///
+/// A column with exactly the given name is preferred; otherwise, of the columns whose names
+/// differ only in case, the first column of the row is chosen. This is used for the fields of a
+/// derived [`FromRow`] marked `#[sqlx(case_insensitive)]`.
+///
+/// [`FromRow`]: trait.FromRow.html
+#[derive(Debug, Copy, Clone)]
+pub struct CaseInsensitive<'a>(pub &'a str);
+
+impl CaseInsensitive<'_> {
+    #[allow(dead_code)]
+    pub(crate) fn find<K, V>(&self, names: &HashMap<K, V>) -> crate::Result<V>
+    where
+        K: Borrow<str> + Hash + Eq,
+        V: Copy + Ord,
+    {
+        names
+            .get(self.0)
+            .copied()
+            .or_else(|| {
+                // the order of a map is arbitrary so the column is chosen by its position
+                names
+                    .iter()
+                    .filter(|(name, _)| Borrow::<str>::borrow(*name).eq_ignore_ascii_case(self.0))
+                    .map(|(_, index)| *index)
+                    .min()
+            })
+            .ok_or_else(|| crate::Error::ColumnNotFound(self.0.into()))
+    }
+}
+
 // Prevent users from implementing the `ColumnIndex` trait.
 mod private_column_index {
     pub trait Sealed {}
     impl Sealed for usize {}
     impl Sealed for str {}
+    impl Sealed for super::CaseInsensitive<'_> {}
     impl<T> Sealed for &'_ T where T: Sealed + ?Sized {}
 }
 
@@ -75,6 +116,12 @@ where
     /// Returns the number of columns in this row.
     fn len(&self) -> usize;
 
+    /// Returns the name of the column at the given position; or, `None` if there is no
+    /// such column.
+    ///
+    /// If several columns of the row have the same name, each of them has it.
+    fn column_name(&self, index: usize) -> Option<&str>;
+
     /// Index into the database row and decode a single value.
     ///
     /// A string index can be used to access a column by name and a `usize` index
@@ -162,12 +209,64 @@ where
         T::decode(value)
     }
 
+    /// Index into the database row and decode a single value, checking that the SQL type
+    /// of the column is exactly the SQL type of `T`.
+    ///
+    /// [`try_get`](#method.try_get) accepts any SQL type that is compatible with `T`; e.g., a
+    /// Postgres `VARCHAR` for a `String` (which is a `TEXT`). This is used for the fields of
+    /// a derived [`FromRow`] marked `#[sqlx(type_check)]` to catch a column whose type has
+    /// drifted from the Rust type. As with `try_get`, a `NULL` value is not checked.
+    ///
+    /// # Errors
+    ///  * As [`try_get`](#method.try_get).
+    ///  * [`Decode`] if the SQL type of the column is not the SQL type of `T`.
+    ///
+    /// [`Decode`]: crate::Error::Decode
+    fn try_get_exact<T, I>(&self, index: I) -> crate::Result<T>
+    where
+        T: Type<Self::Database>,
+        I: ColumnIndex<'c, Self>,
+        T: Decode<'c, Self::Database>,
+    {
+        let value = self.try_get_raw(index)?;
+
+        if let Some(actual_ty) = value.type_info() {
+            let expected_ty = T::type_info();
+
+            if !actual_ty.same_type(&expected_ty) {
+                return Err(decode_err!(
+                    "mismatched types; Rust type `{}` (as SQL type {}) is not SQL type {}",
+                    type_name::<T>(),
+                    expected_ty,
+                    actual_ty
+                ));
+            }
+        }
+
+        T::decode(value)
+    }
+
     /// Index into the database row and decode a single value.
     ///
     /// Unlike [`try_get`](#method.try_get), this method does not check that the type
     /// being returned from the database is compatible with the Rust type and just blindly tries
     /// to decode the value. An example of where this could be useful is decoding a Postgres
-    /// enumeration as a Rust string (instead of deriving a new Rust enum).
+    /// enumeration as a Rust string (instead of deriving a new Rust enum). Skipping the check
+    /// also saves a comparison of type information for each value in a hot loop.
+    ///
+    /// The value is decoded as if it was of the SQL type of `T`. If it is not, this can
+    /// return a [`Decode`] error or, worse, silently return a nonsensical value; e.g., the
+    /// bytes of a `TEXT` value decoded as a `Vec<u8>`. This is never undefined behavior but
+    /// should only be used where the SQL type of the column is known.
+    ///
+    /// # Errors
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///  * [`Decode`] if the value could not be decoded into the requested type.
+    ///
+    /// [`Decode`]: crate::Error::Decode
+    /// [`ColumnNotFound`]: crate::Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: crate::Error::ColumnIndexOutOfBounds
     #[inline]
     fn try_get_unchecked<T, I>(&self, index: I) -> crate::Result<T>
     where
@@ -178,6 +277,27 @@ where
         self.try_get_raw(index).and_then(T::decode)
     }
 
+    /// Index into the database row and decode a single JSON value; the same as
+    /// `row.try_get::<Json<T>, _>(index)` without the `Json` wrapper.
+    ///
+    /// ```rust,ignore
+    /// let payload: Event = row.try_get_json("payload")?;
+    /// ```
+    ///
+    /// # Errors
+    /// See [`try_get`](#method.try_get).
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    #[inline]
+    fn try_get_json<T, I>(&self, index: I) -> crate::Result<T>
+    where
+        I: ColumnIndex<'c, Self>,
+        Json<T>: Type<Self::Database>,
+        Json<T>: Decode<'c, Self::Database>,
+    {
+        self.try_get::<Json<T>, I>(index).map(|json| json.0)
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(
         &self,
@@ -224,8 +344,71 @@ pub trait HasRow<'c> {
 /// }
 /// ```
 ///
+/// With `#[sqlx(case_insensitive)]` on the struct, each field also matches a column whose
+/// name differs only in ASCII case; e.g., the field `name` reads the column `NAME`.
+///
+/// A field may also be read from a column named by one of its `#[sqlx(alias(..))]`; the first
+/// of its name and aliases that is a column of the row is read.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     #[sqlx(alias("user_id", "uid"))]
+///     id: i32,
+/// }
+/// ```
+///
+/// A field marked `#[sqlx(rest)]` collects the columns which are not read by any other
+/// field, by name or alias. Its type is a map such as `HashMap<String, PgRawValue>`; each value is
+/// read without checking its SQL type.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct Entity {
+///     id: i32,
+///     #[sqlx(rest)]
+///     attributes: HashMap<String, PgRawValue>,
+/// }
+/// ```
+///
+/// An enum can be derived with `#[sqlx(discriminant = "..")]`, naming a text column whose
+/// value selects the variant; e.g., for single-table inheritance. The value of each variant is
+/// its `#[sqlx(rename = "..")]` (or its name). A variant with named fields reads them from the
+/// other columns of the row and a variant with a single field decodes it with `FromRow`.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// #[sqlx(discriminant = "kind")]
+/// enum Shape {
+///     #[sqlx(rename = "circle")]
+///     Circle { radius: f64 },
+///
+///     #[sqlx(rename = "square")]
+///     Square { side: f64 },
+/// }
+/// ```
+///
+/// A struct may borrow from the row through a single lifetime, with borrowed and owned fields
+/// mixed. As the row must outlive the struct, it is read from a row of [`Cursor::next`] rather
+/// than with [`query_as`].
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct UserRef<'a> {
+///     name: &'a str,
+///     email: String,
+/// }
+///
+/// let row = cursor.next().await?.unwrap();
+/// let user = UserRef::from_row(&row)?;
+/// ```
+///
+/// A row whose columns are all of the same type can also be read into a `HashMap<String, T>`,
+/// keyed by the name of each column.
+///
 /// [`query_as`]: crate::query_as
 /// [`Row::try_get`]: crate::row::Row::try_get
+/// [`Cursor::next`]: crate::cursor::Cursor::next
 pub trait FromRow<'c, R>
 where
     Self: Sized,
@@ -235,6 +418,31 @@ where
     fn from_row(row: &R) -> crate::Result<Self>;
 }
 
+/// Each column of the row, decoded as a `T` and keyed by its name; e.g., for a row of metrics
+/// that are all `BIGINT`.
+///
+/// Decoding fails if any column is not of the SQL type of `T`. If several columns have the same
+/// name, only the column that is found by that name is included.
+impl<'c, R, T> FromRow<'c, R> for HashMap<String, T>
+where
+    R: Row<'c>,
+    usize: ColumnIndex<'c, R>,
+    T: Type<R::Database>,
+    T: Decode<'c, R::Database>,
+{
+    fn from_row(row: &R) -> crate::Result<Self> {
+        let mut map = HashMap::with_capacity(row.len());
+
+        for index in 0..row.len() {
+            if let Some(name) = row.column_name(index) {
+                map.insert(name.to_owned(), row.try_get(index)?);
+            }
+        }
+
+        Ok(map)
+    }
+}
+
 // Macros to help unify the internal implementations as a good chunk
 // is very similar
 
@@ -331,6 +539,118 @@ macro_rules! impl_from_row_for_tuples {
             (7) -> T8;
             (8) -> T9;
         );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+            (11) -> T12;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+            (11) -> T12;
+            (12) -> T13;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+            (11) -> T12;
+            (12) -> T13;
+            (13) -> T14;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+            (11) -> T12;
+            (12) -> T13;
+            (13) -> T14;
+            (14) -> T15;
+        );
+
+        impl_from_row_for_tuple!($db, $r;
+            (0) -> T1;
+            (1) -> T2;
+            (2) -> T3;
+            (3) -> T4;
+            (4) -> T5;
+            (5) -> T6;
+            (6) -> T7;
+            (7) -> T8;
+            (8) -> T9;
+            (9) -> T10;
+            (10) -> T11;
+            (11) -> T12;
+            (12) -> T13;
+            (13) -> T14;
+            (14) -> T15;
+            (15) -> T16;
+        );
     };
 }
 
@@ -372,3 +692,18 @@ macro_rules! impl_from_row_for_row {
         }
     };
 }
+
+#[test]
+fn test_case_insensitive_chooses_first_column() {
+    let mut names: HashMap<Box<str>, usize> = HashMap::new();
+    names.insert("NAME".into(), 2);
+    names.insert("Name".into(), 0);
+    names.insert("nAmE".into(), 1);
+
+    for _ in 0..10 {
+        assert_eq!(CaseInsensitive("name").find(&names).unwrap(), 0);
+    }
+
+    assert_eq!(CaseInsensitive("nAmE").find(&names).unwrap(), 1);
+    assert!(CaseInsensitive("id").find(&names).is_err());
+}
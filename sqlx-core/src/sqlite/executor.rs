@@ -122,7 +122,6 @@ impl Executor for SqliteConnection {
         SqliteCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -32,5 +32,6 @@ pub use value::SqliteValue;
 pub type SqlitePool = crate::pool::Pool<SqliteConnection>;
 
 make_query_as!(SqliteQueryAs, Sqlite, SqliteRow);
+make_query_scalar!(SqliteQueryScalar, SqliteQueryAs, Sqlite, SqliteRow);
 impl_map_row_for_row!(Sqlite, SqliteRow);
 impl_from_row_for_tuples!(Sqlite, SqliteRow);
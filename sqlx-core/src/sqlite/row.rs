@@ -1,4 +1,4 @@
-use crate::row::{ColumnIndex, Row};
+use crate::row::{CaseInsensitive, ColumnIndex, Row};
 use crate::sqlite::statement::Statement;
 use crate::sqlite::value::SqliteValue;
 use crate::sqlite::{Sqlite, SqliteConnection};
@@ -33,6 +33,10 @@ impl<'c> Row<'c> for SqliteRow<'c> {
         self.values
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.statement().column_names.get(index).map(|name| &**name)
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<SqliteValue<'c>>
     where
@@ -66,3 +70,9 @@ impl<'c> ColumnIndex<'c, SqliteRow<'c>> for str {
             .map(|&index| index as usize)
     }
 }
+
+impl<'c> ColumnIndex<'c, SqliteRow<'c>> for CaseInsensitive<'_> {
+    fn index(&self, row: &SqliteRow<'c>) -> crate::Result<usize> {
+        self.find(&row.statement().columns)
+    }
+}
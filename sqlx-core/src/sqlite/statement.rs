@@ -42,6 +42,7 @@ pub(super) struct Statement {
     pub(super) worker: Worker,
     pub(super) tail: usize,
     pub(super) columns: HashMap<String, usize>,
+    pub(super) column_names: Vec<String>,
 }
 
 // SQLite3 statement objects are safe to send between threads, but *not* safe
@@ -96,16 +97,19 @@ impl Statement {
             connection: conn.handle,
             handle: NonNull::new(statement_handle).map(SqliteStatementHandle),
             columns: HashMap::new(),
+            column_names: Vec::new(),
             tail,
         };
 
         // Prepare a column hash map for use in pulling values from a column by name
         let count = self_.column_count();
         self_.columns.reserve(count);
+        self_.column_names.reserve(count);
 
         for i in 0..count {
             let name = self_.column_name(i).to_owned();
-            self_.columns.insert(name, i);
+            self_.columns.insert(name.clone(), i);
+            self_.column_names.push(name);
         }
 
         Ok(self_)
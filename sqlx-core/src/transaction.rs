@@ -29,6 +29,10 @@ use crate::runtime::spawn;
 ///     .await?;
 /// ```
 ///
+/// A `&mut Transaction` is an executor like a `&mut` connection; the queries are executed on
+/// the wrapped connection, within the transaction. A function that is generic over the executor
+/// can be called with either.
+///
 /// [`commit`]: #method.commit
 /// [`rollback`]: #method.rollback
 // Transaction<PoolConnection<PgConnection>>
@@ -63,6 +67,20 @@ where
 
     /// Creates a new save point in the current transaction and returns
     /// a new `Transaction` object to manage its scope.
+    ///
+    /// Committing the returned transaction releases the save point while rolling it back
+    /// undoes only what was done since the save point was created. Save points may be nested
+    /// to any depth.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = conn.begin().await?;
+    /// let mut savepoint = tx.begin().await?;
+    ///
+    /// sqlx::query("DELETE FROM users").execute(&mut savepoint).await?;
+    ///
+    /// // the users are back; `tx` is still in progress
+    /// let mut tx = savepoint.rollback().await?;
+    /// ```
     pub async fn begin(self) -> crate::Result<Transaction<Transaction<C>>> {
         Transaction::new(self.depth, self).await
     }
@@ -152,6 +170,11 @@ where
         })
     }
 
+    #[inline]
+    fn is_broken(&self) -> bool {
+        self.deref().is_broken()
+    }
+
     #[inline]
     fn ping(&mut self) -> BoxFuture<'_, crate::Result<()>> {
         self.deref_mut().ping()
@@ -182,7 +205,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
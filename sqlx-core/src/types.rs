@@ -22,7 +22,7 @@ pub use uuid::Uuid;
 #[cfg(feature = "chrono")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
 pub mod chrono {
-    pub use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    pub use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 }
 
 #[cfg(feature = "time")]
@@ -35,6 +35,10 @@ pub mod time {
 #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
 pub use bigdecimal::BigDecimal;
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use bytes::Bytes;
+
 #[cfg(feature = "ipnetwork")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipnetwork")))]
 pub mod ipnetwork {
@@ -50,6 +54,13 @@ pub trait TypeInfo: PartialEq<Self> + Debug + Display + Clone {
     /// Compares type information to determine if `other` is compatible at the Rust level
     /// with `self`.
     fn compatible(&self, other: &Self) -> bool;
+
+    /// Compares type information to determine if `other` is exactly the same SQL type as `self`.
+    ///
+    /// By default, this is the same as [`compatible`](#tymethod.compatible).
+    fn same_type(&self, other: &Self) -> bool {
+        self.compatible(other)
+    }
 }
 
 /// Indicates that a SQL type is supported for a database.
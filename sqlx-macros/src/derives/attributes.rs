@@ -1,7 +1,7 @@
 use proc_macro2::Ident;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Variant};
+use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Type, Variant};
 
 macro_rules! assert_attribute {
     ($e:expr, $err:expr, $input:expr) => {
@@ -29,24 +29,55 @@ macro_rules! try_set {
 #[derive(Copy, Clone)]
 pub enum RenameAll {
     LowerCase,
+    SnakeCase,
 }
 
 pub struct SqlxContainerAttributes {
     pub transparent: bool,
     pub rename: Option<String>,
     pub rename_all: Option<RenameAll>,
+    pub label_prefix: Option<String>,
+    pub trim: bool,
+    pub from_str: bool,
+    pub eq_label: bool,
     pub repr: Option<Ident>,
+    pub via_int: Option<Ident>,
+    pub ordinal: bool,
+    pub order_by_ordinal: bool,
+    pub case_insensitive: bool,
+    pub verbatim: bool,
+    pub discriminant: Option<String>,
+    pub check: bool,
+    pub compatible: Option<String>,
+    pub create_type: bool,
 }
 
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
+    pub alias: Vec<String>,
+    pub try_from: Option<Type>,
+    pub type_check: bool,
+    pub rest: bool,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
     let mut transparent = None;
     let mut repr = None;
+    let mut via_int = None;
     let mut rename = None;
     let mut rename_all = None;
+    let mut label_prefix = None;
+    let mut trim = None;
+    let mut from_str = None;
+    let mut eq_label = None;
+    let mut ordinal = None;
+    let mut order_by_ordinal = None;
+    let mut case_insensitive = None;
+    let mut verbatim = None;
+    let mut discriminant = None;
+    let mut check = None;
+    let mut compatible = None;
+    let mut create_type = None;
 
     for attr in input {
         let meta = attr
@@ -61,6 +92,38 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 try_set!(transparent, true, value)
                             }
 
+                            Meta::Path(p) if p.is_ident("trim") => try_set!(trim, true, value),
+
+                            Meta::Path(p) if p.is_ident("from_str") => {
+                                try_set!(from_str, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("eq_label") => {
+                                try_set!(eq_label, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("ordinal") => {
+                                try_set!(ordinal, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("order_by_ordinal") => {
+                                try_set!(order_by_ordinal, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("case_insensitive") => {
+                                try_set!(case_insensitive, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("verbatim") => {
+                                try_set!(verbatim, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("check") => try_set!(check, true, value),
+
+                            Meta::Path(p) if p.is_ident("create_type") => {
+                                try_set!(create_type, true, value)
+                            }
+
                             Meta::NameValue(MetaNameValue {
                                 path,
                                 lit: Lit::Str(val),
@@ -68,6 +131,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                             }) if path.is_ident("rename_all") => {
                                 let val = match &*val.value() {
                                     "lowercase" => RenameAll::LowerCase,
+                                    "snake_case" => RenameAll::SnakeCase,
 
                                     _ => fail!(meta, "unexpected value for rename_all"),
                                 };
@@ -81,6 +145,47 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("label_prefix") => {
+                                try_set!(label_prefix, val.value(), value)
+                            }
+
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("discriminant") => {
+                                try_set!(discriminant, val.value(), value)
+                            }
+
+                            // The name of a builtin type that a transparent type is equivalent to
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("compatible") => {
+                                try_set!(compatible, val.value(), value)
+                            }
+
+                            // The same as #[repr(..)] but without changing the layout of the enum
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("repr") => try_set!(repr, val.parse()?, value),
+
+                            // Convert through the `From`/`Into` impls of the enum and an integer
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("via_int") => {
+                                try_set!(via_int, val.parse()?, value)
+                            }
+
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -105,13 +210,30 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     Ok(SqlxContainerAttributes {
         transparent: transparent.unwrap_or(false),
         repr,
+        via_int,
         rename,
         rename_all,
+        label_prefix,
+        trim: trim.unwrap_or(false),
+        from_str: from_str.unwrap_or(false),
+        eq_label: eq_label.unwrap_or(false),
+        ordinal: ordinal.unwrap_or(false),
+        order_by_ordinal: order_by_ordinal.unwrap_or(false),
+        case_insensitive: case_insensitive.unwrap_or(false),
+        verbatim: verbatim.unwrap_or(false),
+        discriminant,
+        check: check.unwrap_or(false),
+        compatible,
+        create_type: create_type.unwrap_or(false),
     })
 }
 
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
+    let mut alias = None;
+    let mut try_from = None;
+    let mut type_check = None;
+    let mut rest = None;
 
     for attr in input {
         let meta = attr
@@ -129,6 +251,33 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("try_from") => {
+                                try_set!(try_from, val.parse()?, value)
+                            }
+
+                            Meta::List(list) if list.path.is_ident("alias") => {
+                                let labels = list
+                                    .nested
+                                    .iter()
+                                    .map(|label| match label {
+                                        NestedMeta::Lit(Lit::Str(label)) => Ok(label.value()),
+                                        u => Err(syn::Error::new_spanned(u, "expected a string")),
+                                    })
+                                    .collect::<syn::Result<_>>()?;
+
+                                try_set!(alias, labels, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("type_check") => {
+                                try_set!(type_check, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("rest") => try_set!(rest, true, value),
+
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -139,7 +288,13 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         }
     }
 
-    Ok(SqlxChildAttributes { rename })
+    Ok(SqlxChildAttributes {
+        rename,
+        alias: alias.unwrap_or_default(),
+        try_from,
+        type_check: type_check.unwrap_or_default(),
+        rest: rest.unwrap_or_default(),
+    })
 }
 
 pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::Result<()> {
@@ -157,8 +312,56 @@ pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::
         field
     );
 
+    assert_attribute!(
+        attributes.label_prefix.is_none(),
+        "unexpected #[sqlx(label_prefix = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.trim, "unexpected #[sqlx(trim)]", input);
+
+    assert_attribute!(!attributes.from_str, "unexpected #[sqlx(from_str)]", input);
+
+    assert_attribute!(!attributes.eq_label, "unexpected #[sqlx(eq_label)]", input);
+
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    assert_attribute!(
+        attributes.via_int.is_none(),
+        "unexpected #[sqlx(via_int = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.ordinal, "unexpected #[sqlx(ordinal)]", input);
+
+    assert_attribute!(
+        !attributes.order_by_ordinal,
+        "unexpected #[sqlx(order_by_ordinal)]",
+        input
+    );
+
+    assert_attribute!(
+        !attributes.case_insensitive,
+        "unexpected #[sqlx(case_insensitive)]",
+        input
+    );
+
+    assert_attribute!(!attributes.verbatim, "unexpected #[sqlx(verbatim)]", input);
+
+    assert_attribute!(
+        attributes.discriminant.is_none(),
+        "unexpected #[sqlx(discriminant = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.check, "unexpected #[sqlx(check)]", input);
+
+    assert_attribute!(
+        !attributes.create_type,
+        "unexpected #[sqlx(create_type)]",
+        input
+    );
+
     let attributes = parse_child_attributes(&field.attrs)?;
 
     assert_attribute!(
@@ -167,6 +370,14 @@ pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::
         field
     );
 
+    assert_attribute!(
+        attributes.alias.is_empty(),
+        "unexpected #[sqlx(alias(..))]",
+        field
+    );
+
+    assert_attribute!(!attributes.rest, "unexpected #[sqlx(rest)]", field);
+
     Ok(())
 }
 
@@ -179,6 +390,26 @@ pub fn check_enum_attributes<'a>(input: &'a DeriveInput) -> syn::Result<SqlxCont
         input
     );
 
+    assert_attribute!(
+        !attributes.case_insensitive,
+        "unexpected #[sqlx(case_insensitive)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.discriminant.is_none(),
+        "unexpected #[sqlx(discriminant = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.check, "unexpected #[sqlx(check)]", input);
+
+    assert_attribute!(
+        attributes.compatible.is_none(),
+        "unexpected #[sqlx(compatible = ..)]",
+        input
+    );
+
     Ok(attributes)
 }
 
@@ -196,6 +427,114 @@ pub fn check_weak_enum_attributes(
         input
     );
 
+    assert_attribute!(
+        attributes.label_prefix.is_none(),
+        "unexpected #[sqlx(label_prefix = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.trim, "unexpected #[sqlx(trim)]", input);
+
+    assert_attribute!(!attributes.from_str, "unexpected #[sqlx(from_str)]", input);
+
+    assert_attribute!(!attributes.eq_label, "unexpected #[sqlx(eq_label)]", input);
+
+    assert_attribute!(!attributes.verbatim, "unexpected #[sqlx(verbatim)]", input);
+
+    assert_attribute!(
+        !attributes.order_by_ordinal,
+        "unexpected #[sqlx(order_by_ordinal)]",
+        input
+    );
+
+    assert_attribute!(
+        !attributes.create_type,
+        "unexpected #[sqlx(create_type)]",
+        input
+    );
+
+    for variant in variants {
+        // With #[sqlx(ordinal)], the value of a variant is its position in the enum
+        if attributes.ordinal {
+            if let Some((_, discriminant)) = &variant.discriminant {
+                fail!(
+                    discriminant,
+                    "unexpected discriminant with #[sqlx(ordinal)]"
+                );
+            }
+        }
+
+        let attributes = parse_child_attributes(&variant.attrs)?;
+
+        assert_attribute!(
+            attributes.rename.is_none(),
+            "unexpected #[sqlx(rename = ..)]",
+            variant
+        );
+
+        assert_attribute!(
+            attributes.alias.is_empty(),
+            "unexpected #[sqlx(alias(..))]",
+            variant
+        );
+
+        assert_attribute!(!attributes.rest, "unexpected #[sqlx(rest)]", variant);
+    }
+
+    Ok(attributes)
+}
+
+pub fn check_via_int_enum_attributes(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<SqlxContainerAttributes> {
+    let attributes = check_enum_attributes(input)?;
+
+    assert_attribute!(
+        attributes.via_int.is_some(),
+        "expected #[sqlx(via_int = ..)]",
+        input
+    );
+
+    // The value of each variant is whatever the conversions make of it
+    assert_attribute!(
+        !attributes.ordinal,
+        "unexpected #[sqlx(ordinal)] with #[sqlx(via_int = ..)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.rename_all.is_none(),
+        "unexpected #[sqlx(rename_all = ..)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.label_prefix.is_none(),
+        "unexpected #[sqlx(label_prefix = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.trim, "unexpected #[sqlx(trim)]", input);
+
+    assert_attribute!(!attributes.from_str, "unexpected #[sqlx(from_str)]", input);
+
+    assert_attribute!(!attributes.eq_label, "unexpected #[sqlx(eq_label)]", input);
+
+    assert_attribute!(!attributes.verbatim, "unexpected #[sqlx(verbatim)]", input);
+
+    assert_attribute!(
+        !attributes.order_by_ordinal,
+        "unexpected #[sqlx(order_by_ordinal)]",
+        input
+    );
+
+    assert_attribute!(
+        !attributes.create_type,
+        "unexpected #[sqlx(create_type)]",
+        input
+    );
+
     for variant in variants {
         let attributes = parse_child_attributes(&variant.attrs)?;
 
@@ -204,6 +543,14 @@ pub fn check_weak_enum_attributes(
             "unexpected #[sqlx(rename = ..)]",
             variant
         );
+
+        assert_attribute!(
+            attributes.alias.is_empty(),
+            "unexpected #[sqlx(alias(..))]",
+            variant
+        );
+
+        assert_attribute!(!attributes.rest, "unexpected #[sqlx(rest)]", variant);
     }
 
     Ok(attributes)
@@ -211,12 +558,43 @@ pub fn check_weak_enum_attributes(
 
 pub fn check_strong_enum_attributes(
     input: &DeriveInput,
-    _variants: &Punctuated<Variant, Comma>,
+    variants: &Punctuated<Variant, Comma>,
 ) -> syn::Result<SqlxContainerAttributes> {
     let attributes = check_enum_attributes(input)?;
 
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    assert_attribute!(
+        !attributes.ordinal,
+        "expected #[sqlx(repr = ..)] with #[sqlx(ordinal)]",
+        input
+    );
+
+    // With #[sqlx(verbatim)], the label of each variant is always its identifier
+    if attributes.verbatim {
+        assert_attribute!(
+            attributes.rename_all.is_none(),
+            "unexpected #[sqlx(rename_all = ..)] with #[sqlx(verbatim)]",
+            input
+        );
+
+        assert_attribute!(
+            attributes.label_prefix.is_none(),
+            "unexpected #[sqlx(label_prefix = ..)] with #[sqlx(verbatim)]",
+            input
+        );
+
+        for variant in variants {
+            let attributes = parse_child_attributes(&variant.attrs)?;
+
+            assert_attribute!(
+                attributes.rename.is_none(),
+                "unexpected #[sqlx(rename = ..)] with #[sqlx(verbatim)]",
+                variant
+            );
+        }
+    }
+
     Ok(attributes)
 }
 
@@ -238,8 +616,71 @@ pub fn check_struct_attributes<'a>(
         input
     );
 
+    assert_attribute!(
+        attributes.label_prefix.is_none(),
+        "unexpected #[sqlx(label_prefix = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.trim, "unexpected #[sqlx(trim)]", input);
+
+    assert_attribute!(!attributes.from_str, "unexpected #[sqlx(from_str)]", input);
+
+    assert_attribute!(!attributes.eq_label, "unexpected #[sqlx(eq_label)]", input);
+
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    assert_attribute!(
+        attributes.via_int.is_none(),
+        "unexpected #[sqlx(via_int = ..)]",
+        input
+    );
+
+    assert_attribute!(!attributes.ordinal, "unexpected #[sqlx(ordinal)]", input);
+
+    assert_attribute!(
+        !attributes.order_by_ordinal,
+        "unexpected #[sqlx(order_by_ordinal)]",
+        input
+    );
+
+    assert_attribute!(
+        !attributes.case_insensitive,
+        "unexpected #[sqlx(case_insensitive)]",
+        input
+    );
+
+    assert_attribute!(!attributes.verbatim, "unexpected #[sqlx(verbatim)]", input);
+
+    assert_attribute!(
+        attributes.discriminant.is_none(),
+        "unexpected #[sqlx(discriminant = ..)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.compatible.is_none(),
+        "unexpected #[sqlx(compatible = ..)]",
+        input
+    );
+
+    assert_attribute!(
+        !attributes.create_type,
+        "unexpected #[sqlx(create_type)]",
+        input
+    );
+
+    // The name of the type may be qualified with its schema; e.g., `catalog.inventory_item`
+    if let Some(rename) = &attributes.rename {
+        let parts: Vec<&str> = rename.split('.').collect();
+
+        assert_attribute!(
+            parts.len() <= 2 && parts.iter().all(|part| !part.is_empty()),
+            "expected #[sqlx(rename = \"name\")] or #[sqlx(rename = \"schema.name\")]",
+            input
+        );
+    }
+
     for field in fields {
         let attributes = parse_child_attributes(&field.attrs)?;
 
@@ -248,6 +689,14 @@ pub fn check_struct_attributes<'a>(
             "unexpected #[sqlx(rename = ..)]",
             field
         );
+
+        assert_attribute!(
+            attributes.alias.is_empty(),
+            "unexpected #[sqlx(alias(..))]",
+            field
+        );
+
+        assert_attribute!(!attributes.rest, "unexpected #[sqlx(rest)]", field);
     }
 
     Ok(attributes)
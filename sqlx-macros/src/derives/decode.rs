@@ -1,8 +1,9 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes,
+    check_via_int_enum_attributes, check_weak_enum_attributes, parse_child_attributes,
+    parse_container_attributes,
 };
-use super::rename_all;
+use super::variant_label;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
@@ -20,6 +21,9 @@ pub fn expand_derive_decode(input: &DeriveInput) -> syn::Result<proc_macro2::Tok
         }) if unnamed.len() == 1 => {
             expand_derive_decode_transparent(input, unnamed.first().unwrap())
         }
+        Data::Enum(DataEnum { variants, .. }) if attrs.via_int.is_some() => {
+            expand_derive_decode_via_int_enum(input, variants)
+        }
         Data::Enum(DataEnum { variants, .. }) => match attrs.repr {
             Some(_) => expand_derive_decode_weak_enum(input, variants),
             None => expand_derive_decode_strong_enum(input, variants),
@@ -59,14 +63,26 @@ fn expand_derive_decode_transparent(
     let generics = &input.generics;
     let (_, ty_generics, _) = generics.split_for_impl();
 
-    // add db type for impl generics & where clause
+    // add db type and the lifetime of the decoded value for impl generics & where clause
+    // the lifetimes of the type must be outlived by the decoded value so that a borrowing type,
+    // e.g., `struct Username<'a>(&'a str)`, can be decoded
     let mut generics = generics.clone();
-    generics.params.insert(0, parse_quote!(DB: sqlx::Database));
-    generics.params.insert(0, parse_quote!('de));
+    let lifetimes: Vec<_> = generics
+        .lifetimes()
+        .map(|def| def.lifetime.clone())
+        .collect();
     generics
-        .make_where_clause()
-        .predicates
-        .push(parse_quote!(#ty: sqlx::decode::Decode<'de, DB>));
+        .params
+        .insert(lifetimes.len(), parse_quote!(DB: sqlx::Database));
+    generics.params.insert(0, parse_quote!('de));
+
+    let predicates = &mut generics.make_where_clause().predicates;
+    predicates.push(parse_quote!(#ty: sqlx::decode::Decode<'de, DB>));
+
+    for lifetime in lifetimes {
+        predicates.push(parse_quote!('de: #lifetime));
+    }
+
     let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     let tts = quote!(
@@ -113,6 +129,30 @@ fn expand_derive_decode_weak_enum(
     ))
 }
 
+fn expand_derive_decode_via_int_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attr = check_via_int_enum_attributes(input, variants)?;
+    let int = attr.via_int.unwrap();
+
+    let ident = &input.ident;
+    let ident_s = ident.to_string();
+
+    // A `From<#int>` also provides `TryFrom<#int>`
+    Ok(quote!(
+        impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident where #int: sqlx::decode::Decode<'de, DB> {
+            fn decode(value: <DB as sqlx::value::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
+                let value = <#int as sqlx::decode::Decode<'de, DB>>::decode(value)?;
+
+                <#ident as std::convert::TryFrom<#int>>::try_from(value).map_err(|_| {
+                    sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into())
+                })
+            }
+        }
+    ))
+}
+
 fn expand_derive_decode_strong_enum(
     input: &DeriveInput,
     variants: &Punctuated<Variant, Comma>,
@@ -122,31 +162,64 @@ fn expand_derive_decode_strong_enum(
     let ident = &input.ident;
     let ident_s = ident.to_string();
 
-    let value_arms = variants.iter().map(|v| -> Arm {
-        let id = &v.ident;
-        let attributes = parse_child_attributes(&v.attrs).unwrap();
+    let value_arms: Vec<Arm> = variants
+        .iter()
+        .map(|v| -> Arm {
+            let id = &v.ident;
+            let attributes = parse_child_attributes(&v.attrs).unwrap();
 
-        if let Some(rename) = attributes.rename {
-            parse_quote!(#rename => Ok(#ident :: #id),)
-        } else if let Some(pattern) = cattr.rename_all {
-            let name = rename_all(&*id.to_string(), pattern);
+            let name = variant_label(id, attributes.rename, &cattr);
 
-            parse_quote!(#name => Ok(#ident :: #id),)
-        } else {
-            let name = id.to_string();
-            parse_quote!(#name => Ok(#ident :: #id),)
-        }
-    });
+            // An alias is accepted as-is in addition to the label of the variant
+            let alias = &attributes.alias;
+
+            parse_quote!(#name #(| #alias)* => Ok(#ident :: #id),)
+        })
+        .collect();
+
+    // With #[sqlx(trim)], the padding of a fixed-width type (e.g., CHAR(n)) is removed
+    // before the value is matched against the labels
+    let trim = if cattr.trim {
+        quote!(let value = value.trim_end();)
+    } else {
+        quote!()
+    };
+
+    // With #[sqlx(from_str)], the enum is also parsed from its labels with `FromStr`; this is
+    // opt-in as the enum may already implement `FromStr` itself
+    let from_str = if cattr.from_str {
+        quote!(
+            impl std::str::FromStr for #ident {
+                type Err = sqlx::error::UnexpectedLabelError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    match value {
+                        #(#value_arms)*
+
+                        _ => Err(sqlx::error::UnexpectedLabelError::new(#ident_s, value))
+                    }
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
 
     Ok(quote!(
+        #from_str
+
         impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident where &'de str: sqlx::decode::Decode<'de, DB> {
             fn decode(value: <DB as sqlx::value::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
                 let value = <&'de str as sqlx::decode::Decode<'de, DB>>::decode(value)?;
-                match value {
+                #trim
+
+                let result: Result<Self, sqlx::error::UnexpectedLabelError> = match value {
                     #(#value_arms)*
 
-                    _ => Err(sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into()))
-                }
+                    _ => Err(sqlx::error::UnexpectedLabelError::new(#ident_s, value))
+                };
+
+                result.map_err(|e| sqlx::Error::Decode(e.into()))
             }
         }
     ))
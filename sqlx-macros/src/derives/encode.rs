@@ -1,8 +1,9 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes,
+    check_via_int_enum_attributes, check_weak_enum_attributes, parse_child_attributes,
+    parse_container_attributes,
 };
-use super::rename_all;
+use super::variant_label;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
@@ -21,6 +22,9 @@ pub fn expand_derive_encode(input: &DeriveInput) -> syn::Result<proc_macro2::Tok
         }) if unnamed.len() == 1 => {
             expand_derive_encode_transparent(&input, unnamed.first().unwrap())
         }
+        Data::Enum(DataEnum { variants, .. }) if args.via_int.is_some() => {
+            expand_derive_encode_via_int_enum(input, variants)
+        }
         Data::Enum(DataEnum { variants, .. }) => match args.repr {
             Some(_) => expand_derive_encode_weak_enum(input, variants),
             None => expand_derive_encode_strong_enum(input, variants),
@@ -61,8 +65,12 @@ fn expand_derive_encode_transparent(
     let (_, ty_generics, _) = generics.split_for_impl();
 
     // add db type for impl generics & where clause
+    // lifetimes must be declared before the db type
     let mut generics = generics.clone();
-    generics.params.insert(0, parse_quote!(DB: sqlx::Database));
+    let lifetimes = generics.lifetimes().count();
+    generics
+        .params
+        .insert(lifetimes, parse_quote!(DB: sqlx::Database));
     generics
         .make_where_clause()
         .predicates
@@ -93,18 +101,66 @@ fn expand_derive_encode_weak_enum(
 
     let ident = &input.ident;
 
+    // Casting the path of each variant does not require the enum to be `Copy`
+    let value_arms = variants.iter().map(|v| {
+        let id = &v.ident;
+        quote!(#ident :: #id => #ident :: #id as #repr,)
+    });
+
+    let value = quote!(match self { #(#value_arms)* });
+
     Ok(quote!(
         impl<DB: sqlx::Database> sqlx::encode::Encode<DB> for #ident where #repr: sqlx::encode::Encode<DB> {
             fn encode(&self, buf: &mut DB::RawBuffer) {
-                sqlx::encode::Encode::encode(&(*self as #repr), buf)
+                let value: #repr = #value;
+                sqlx::encode::Encode::encode(&value, buf)
             }
 
             fn encode_nullable(&self, buf: &mut DB::RawBuffer) -> sqlx::encode::IsNull {
-                sqlx::encode::Encode::encode_nullable(&(*self as #repr), buf)
+                let value: #repr = #value;
+                sqlx::encode::Encode::encode_nullable(&value, buf)
             }
 
             fn size_hint(&self) -> usize {
-                sqlx::encode::Encode::size_hint(&(*self as #repr))
+                let value: #repr = #value;
+                sqlx::encode::Encode::size_hint(&value)
+            }
+        }
+    ))
+}
+
+fn expand_derive_encode_via_int_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attr = check_via_int_enum_attributes(input, variants)?;
+    let int = attr.via_int.unwrap();
+
+    let ident = &input.ident;
+
+    // `Into` takes the enum by value so it is cloned; the bound on `Clone` reports an enum that
+    // does not implement it at the derive rather than in the generated body
+    let value = quote!(<#ident as std::convert::Into<#int>>::into(std::clone::Clone::clone(self)));
+
+    Ok(quote!(
+        impl<DB: sqlx::Database> sqlx::encode::Encode<DB> for #ident
+        where
+            #ident: std::clone::Clone,
+            #int: sqlx::encode::Encode<DB>,
+        {
+            fn encode(&self, buf: &mut DB::RawBuffer) {
+                let value: #int = #value;
+                sqlx::encode::Encode::encode(&value, buf)
+            }
+
+            fn encode_nullable(&self, buf: &mut DB::RawBuffer) -> sqlx::encode::IsNull {
+                let value: #int = #value;
+                sqlx::encode::Encode::encode_nullable(&value, buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                let value: #int = #value;
+                sqlx::encode::Encode::size_hint(&value)
             }
         }
     ))
@@ -123,19 +179,70 @@ fn expand_derive_encode_strong_enum(
         let id = &v.ident;
         let attributes = parse_child_attributes(&v.attrs)?;
 
-        if let Some(rename) = attributes.rename {
-            value_arms.push(quote!(#ident :: #id => #rename,));
-        } else if let Some(pattern) = cattr.rename_all {
-            let name = rename_all(&*id.to_string(), pattern);
+        let name = variant_label(id, attributes.rename, &cattr);
 
-            value_arms.push(quote!(#ident :: #id => #name,));
-        } else {
-            let name = id.to_string();
-            value_arms.push(quote!(#ident :: #id => #name,));
-        }
+        value_arms.push(quote!(#ident :: #id => #name,));
     }
 
+    // #[sqlx(eq_label)] compares against a label without first decoding it into the enum
+    let eq_label = if cattr.eq_label {
+        let label = if cattr.trim {
+            quote!(label.trim_end())
+        } else {
+            quote!(label)
+        };
+
+        quote!(
+            impl #ident {
+                /// Returns `true` if `label` is the SQL label of this variant.
+                pub fn eq_label(&self, label: &str) -> bool {
+                    let val = match self {
+                        #(#value_arms)*
+                    };
+                    val == #label
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    // #[sqlx(order_by_ordinal)] orders the variants by their position in the enum rather than
+    // by their label
+    let order_by_ordinal = if cattr.order_by_ordinal {
+        let ordinal_arms = variants.iter().enumerate().map(|(ordinal, v)| {
+            let id = &v.ident;
+            quote!(#ident :: #id => #ordinal,)
+        });
+
+        quote!(
+            impl std::cmp::PartialOrd for #ident {
+                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                    Some(std::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl std::cmp::Ord for #ident {
+                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                    let ordinal = |value: &Self| -> usize {
+                        match value {
+                            #(#ordinal_arms)*
+                        }
+                    };
+
+                    ordinal(self).cmp(&ordinal(other))
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
     Ok(quote!(
+        #eq_label
+
+        #order_by_ordinal
+
         impl<DB: sqlx::Database> sqlx::encode::Encode<DB> for #ident where str: sqlx::encode::Encode<DB> {
             fn encode(&self, buf: &mut DB::RawBuffer) {
                 let val = match self {
@@ -9,9 +9,9 @@ pub(crate) use encode::expand_derive_encode;
 pub(crate) use r#type::expand_derive_type;
 pub(crate) use row::expand_derive_from_row;
 
-use self::attributes::RenameAll;
+use self::attributes::{RenameAll, SqlxContainerAttributes};
 use std::iter::FromIterator;
-use syn::DeriveInput;
+use syn::{DeriveInput, Ident};
 
 pub(crate) fn expand_derive_type_encode_decode(
     input: &DeriveInput,
@@ -27,8 +27,55 @@ pub(crate) fn expand_derive_type_encode_decode(
     Ok(combined)
 }
 
-pub(crate) fn rename_all(s: &str, pattern: RenameAll) -> String {
+fn rename_all(s: &str, pattern: RenameAll) -> String {
     match pattern {
         RenameAll::LowerCase => s.to_lowercase(),
+        RenameAll::SnakeCase => snake_case(s),
+    }
+}
+
+// A word starts at an uppercase letter after a lowercase letter or a digit, or at the last
+// uppercase letter of an acronym that is followed by a lowercase one; e.g., `HTTPRequest` is
+// `http_request`
+fn snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut name = String::with_capacity(s.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let ends_acronym = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && ends_acronym) {
+                name.push('_');
+            }
+        }
+
+        name.extend(c.to_lowercase());
+    }
+
+    name
+}
+
+// Compute the label of a variant of a strong enum
+// An explicit #[sqlx(rename)] is used as-is, otherwise #[sqlx(rename_all)] and then
+// #[sqlx(label_prefix)] are applied to the name of the variant
+pub(crate) fn variant_label(
+    id: &Ident,
+    rename: Option<String>,
+    cattr: &SqlxContainerAttributes,
+) -> String {
+    if let Some(rename) = rename {
+        return rename;
+    }
+
+    let name = match cattr.rename_all {
+        Some(pattern) => rename_all(&id.to_string(), pattern),
+        None => id.to_string(),
+    };
+
+    match &cattr.label_prefix {
+        Some(prefix) => format!("{}{}", prefix, name),
+        None => name,
     }
 }
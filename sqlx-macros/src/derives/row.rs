@@ -1,11 +1,15 @@
 use proc_macro2::Span;
 use quote::quote;
 use syn::{
-    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
-    Fields, FieldsNamed, Lifetime, Stmt,
+    parse_quote, punctuated::Punctuated, token::Comma, Data, DataEnum, DataStruct, DeriveInput,
+    Field, Fields, FieldsNamed, FieldsUnnamed, GenericArgument, Generics, Lifetime, PathArguments,
+    Stmt, Type, TypePath, Variant, WherePredicate,
 };
 
-use super::attributes::parse_child_attributes;
+use super::attributes::{
+    parse_child_attributes, parse_container_attributes, SqlxContainerAttributes,
+};
+use super::variant_label;
 
 pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
@@ -30,7 +34,7 @@ pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::T
             "unit structs are not supported",
         )),
 
-        Data::Enum(_) => Err(syn::Error::new_spanned(input, "enums are not supported")),
+        Data::Enum(DataEnum { variants, .. }) => expand_derive_from_row_enum(input, variants),
 
         Data::Union(_) => Err(syn::Error::new_spanned(input, "unions are not supported")),
     }
@@ -42,17 +46,147 @@ fn expand_derive_from_row_struct(
 ) -> syn::Result<proc_macro2::TokenStream> {
     let ident = &input.ident;
 
-    let generics = &input.generics;
+    let cattr = parse_container_attributes(&input.attrs)?;
+
+    if cattr.discriminant.is_some() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "unexpected #[sqlx(discriminant = ..)]",
+        ));
+    }
+
+    let (lifetime, mut generics) = from_row_generics(input, &cattr);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    push_field_predicates(fields, &lifetime, predicates)?;
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let reads = field_reads(fields, &cattr, None);
+    let names = fields.iter().map(|field| &field.ident);
+
+    Ok(quote!(
+        impl #impl_generics sqlx::row::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: &R) -> sqlx::Result<Self> {
+                #(#reads)*
+
+                Ok(#ident {
+                    #(#names),*
+                })
+            }
+        }
+    ))
+}
+
+// An enum is decoded from a row by the value of its discriminant column, which selects the
+// variant; the fields of the variant are then decoded from the other columns of the row
+fn expand_derive_from_row_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let ident_s = ident.to_string();
+
+    let cattr = parse_container_attributes(&input.attrs)?;
+
+    let discriminant_s = match &cattr.discriminant {
+        Some(discriminant) => discriminant,
+
+        None => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "expected #[sqlx(discriminant = ..)] to select the variant",
+            ))
+        }
+    };
+
+    let discriminant = column_name(discriminant_s.clone(), &cattr);
+
+    let (lifetime, mut generics) = from_row_generics(input, &cattr);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    predicates.push(parse_quote!(String: sqlx::decode::Decode<#lifetime, R::Database>));
+    predicates.push(parse_quote!(String: sqlx::types::Type<R::Database>));
+
+    let mut arms = Vec::with_capacity(variants.len());
+
+    for variant in variants {
+        let id = &variant.ident;
+        let attributes = parse_child_attributes(&variant.attrs)?;
+        let label = variant_label(id, attributes.rename, &cattr);
+
+        arms.push(match &variant.fields {
+            Fields::Named(FieldsNamed { named, .. }) => {
+                push_field_predicates(named, &lifetime, predicates)?;
+
+                let reads = field_reads(named, &cattr, Some(discriminant_s));
+                let names = named.iter().map(|field| &field.ident);
+
+                quote!(#label => {
+                    #(#reads)*
+
+                    Ok(#ident::#id {
+                        #(#names),*
+                    })
+                })
+            }
+
+            // A variant of a single field is decoded from the row as a whole
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) if unnamed.len() == 1 => {
+                let ty = &unnamed[0].ty;
+
+                predicates.push(parse_quote!(#ty: sqlx::row::FromRow<#lifetime, R>));
+
+                quote!(#label => Ok(#ident::#id(
+                    <#ty as sqlx::row::FromRow<#lifetime, R>>::from_row(row)?
+                )))
+            }
+
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "expected a variant with named fields or a single field",
+                ))
+            }
+
+            Fields::Unit => quote!(#label => Ok(#ident::#id)),
+        });
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics sqlx::row::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: &R) -> sqlx::Result<Self> {
+                let discriminant: String = row.try_get(#discriminant)?;
+
+                match &*discriminant {
+                    #(#arms,)*
+
+                    _ => Err(sqlx::Error::Decode(
+                        format!("invalid value {:?} for enum {}", discriminant, #ident_s).into(),
+                    )),
+                }
+            }
+        }
+    ))
+}
 
-    let (lifetime, provided) = generics
+// The generics of the implementation; the row type `R` and its lifetime are added to the
+// generics of the type
+fn from_row_generics(input: &DeriveInput, cattr: &SqlxContainerAttributes) -> (Lifetime, Generics) {
+    let (lifetime, provided) = input
+        .generics
         .lifetimes()
         .next()
         .map(|def| (def.lifetime.clone(), false))
         .unwrap_or_else(|| (Lifetime::new("'a", Span::call_site()), true));
 
-    let (_, ty_generics, _) = generics.split_for_impl();
-
-    let mut generics = generics.clone();
+    let mut generics = input.generics.clone();
     generics
         .params
         .insert(0, parse_quote!(R: sqlx::Row<#lifetime>));
@@ -63,42 +197,191 @@ fn expand_derive_from_row_struct(
 
     let predicates = &mut generics.make_where_clause().predicates;
 
-    predicates.push(parse_quote!(&#lifetime str: sqlx::row::ColumnIndex<#lifetime, R>));
+    // With #[sqlx(case_insensitive)], a field also matches a column whose name differs
+    // only in ASCII case
+    if cattr.case_insensitive {
+        predicates.push(parse_quote!(
+            sqlx::row::CaseInsensitive<#lifetime>: sqlx::row::ColumnIndex<#lifetime, R>
+        ));
+    } else {
+        predicates.push(parse_quote!(&#lifetime str: sqlx::row::ColumnIndex<#lifetime, R>));
+    }
+
+    (lifetime, generics)
+}
+
+fn push_field_predicates(
+    fields: &Punctuated<Field, Comma>,
+    lifetime: &Lifetime,
+    predicates: &mut Punctuated<WherePredicate, Comma>,
+) -> syn::Result<()> {
+    let mut rest = None;
 
     for field in fields {
-        let ty = &field.ty;
+        let attributes = parse_child_attributes(&field.attrs)?;
+
+        if !attributes.alias.is_empty() {
+            predicates.push(parse_quote!(usize: sqlx::row::ColumnIndex<#lifetime, R>));
+        }
+
+        let ty = if attributes.rest {
+            if !attributes.alias.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "unexpected #[sqlx(alias(..))] on a #[sqlx(rest)] field",
+                ));
+            }
+
+            if rest.replace(field).is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "only one field may be #[sqlx(rest)]",
+                ));
+            }
+
+            predicates.push(parse_quote!(usize: sqlx::row::ColumnIndex<#lifetime, R>));
+
+            map_value_type(&field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &field.ty,
+                    "expected a map of column names to values for #[sqlx(rest)]; \
+                     e.g., HashMap<String, PgRawValue>",
+                )
+            })?
+        } else {
+            attributes.try_from.as_ref().unwrap_or(&field.ty)
+        };
 
         predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
         predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
     }
 
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    Ok(())
+}
 
-    let reads = fields.iter().filter_map(|field| -> Option<Stmt> {
-        let id = &field.ident.as_ref()?;
-        let attributes = parse_child_attributes(&field.attrs).unwrap();
-        let id_s = match attributes.rename {
-            Some(rename) => rename,
-            None => id.to_string().trim_start_matches("r#").to_owned(),
-        };
-        let ty = &field.ty;
+// The value type of a map type; the last type argument, e.g., `V` of `HashMap<String, V>`
+fn map_value_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last()?,
+        _ => return None,
+    };
 
-        Some(parse_quote!(
-            let #id: #ty = row.try_get(#id_s)?;
-        ))
-    });
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().rev().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
 
-    let names = fields.iter().map(|field| &field.ident);
+        _ => None,
+    }
+}
 
-    Ok(quote!(
-        impl #impl_generics sqlx::row::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
-            fn from_row(row: &R) -> sqlx::Result<Self> {
-                #(#reads)*
+// The index of a column by its name
+fn column_name(name: String, cattr: &SqlxContainerAttributes) -> proc_macro2::TokenStream {
+    if cattr.case_insensitive {
+        quote!(sqlx::row::CaseInsensitive(#name))
+    } else {
+        quote!(#name)
+    }
+}
 
-                Ok(#ident {
-                    #(#names),*
-                })
+// Read each field from the column of its name, or of one of its aliases
+// A #[sqlx(rest)] field collects the columns which are not read by the other fields, nor are
+// the discriminant of an enum
+fn field_reads(
+    fields: &Punctuated<Field, Comma>,
+    cattr: &SqlxContainerAttributes,
+    discriminant: Option<&String>,
+) -> Vec<Stmt> {
+    let mut mapped: Vec<String> = discriminant.into_iter().cloned().collect();
+    let mut rest = None;
+
+    let mut reads: Vec<Stmt> = fields
+        .iter()
+        .filter_map(|field| -> Option<Stmt> {
+            let id = &field.ident.as_ref()?;
+            let attributes = parse_child_attributes(&field.attrs).unwrap();
+
+            if attributes.rest {
+                rest = Some(field);
+                return None;
             }
-        }
-    ))
+
+            let id_s = match attributes.rename {
+                Some(rename) => rename,
+                None => id.to_string().trim_start_matches("r#").to_owned(),
+            };
+            let ty = &field.ty;
+
+            mapped.push(id_s.clone());
+            mapped.extend(attributes.alias.iter().cloned());
+
+            let id_s = if attributes.alias.is_empty() {
+                column_name(id_s, cattr)
+            } else {
+                // the field is read from the first column of the row named by the field or one
+                // of its aliases
+                let names = std::iter::once(id_s.clone())
+                    .chain(attributes.alias.iter().cloned())
+                    .map(|name| column_name(name, cattr));
+
+                quote!(
+                    [#(#names),*]
+                        .iter()
+                        .find_map(|name| sqlx::row::ColumnIndex::index(name, row).ok())
+                        .ok_or_else(|| sqlx::Error::ColumnNotFound(#id_s.into()))?
+                )
+            };
+
+            // #[sqlx(type_check)] requires the column to be exactly the SQL type of the field
+            let try_get = if attributes.type_check {
+                quote!(try_get_exact)
+            } else {
+                quote!(try_get)
+            };
+
+            Some(match attributes.try_from {
+                // read the column as the intermediate type and convert it into the field type
+                Some(try_from) => parse_quote!(
+                    let #id: #ty = <#ty as std::convert::TryFrom<#try_from>>::try_from(row.#try_get::<#try_from, _>(#id_s)?)
+                        .map_err(|e| sqlx::Error::Decode(e.into()))?;
+                ),
+
+                None => parse_quote!(
+                    let #id: #ty = row.#try_get(#id_s)?;
+                ),
+            })
+        })
+        .collect();
+
+    if let Some(Field {
+        ident: Some(id),
+        ty,
+        ..
+    }) = rest
+    {
+        let is_mapped = if cattr.case_insensitive {
+            quote!(name.eq_ignore_ascii_case(mapped))
+        } else {
+            quote!(name == *mapped)
+        };
+
+        reads.push(parse_quote!(
+            let mut #id: #ty = Default::default();
+        ));
+
+        reads.push(parse_quote!(
+            for index in 0..row.len() {
+                match row.column_name(index) {
+                    Some(name) if ![#(#mapped),*].iter().any(|mapped: &&str| #is_mapped) => {
+                        #id.insert(name.to_owned(), row.try_get_unchecked(index)?);
+                    }
+
+                    _ => {}
+                }
+            }
+        ));
+    }
+
+    reads
 }
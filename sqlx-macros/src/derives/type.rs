@@ -1,7 +1,9 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_container_attributes,
+    check_via_int_enum_attributes, check_weak_enum_attributes, parse_child_attributes,
+    parse_container_attributes,
 };
+use super::variant_label;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
@@ -19,6 +21,9 @@ pub fn expand_derive_type(input: &DeriveInput) -> syn::Result<proc_macro2::Token
         }) if unnamed.len() == 1 => {
             expand_derive_has_sql_type_transparent(input, unnamed.first().unwrap())
         }
+        Data::Enum(DataEnum { variants, .. }) if attrs.via_int.is_some() => {
+            expand_derive_has_sql_type_via_int_enum(input, variants)
+        }
         Data::Enum(DataEnum { variants, .. }) => match attrs.repr {
             Some(_) => expand_derive_has_sql_type_weak_enum(input, variants),
             None => expand_derive_has_sql_type_strong_enum(input, variants),
@@ -51,6 +56,12 @@ fn expand_derive_has_sql_type_transparent(
 ) -> syn::Result<proc_macro2::TokenStream> {
     check_transparent_attributes(input, field)?;
 
+    let attributes = parse_container_attributes(&input.attrs)?;
+
+    if let Some(compatible) = &attributes.compatible {
+        return expand_derive_has_sql_type_compatible(input, compatible);
+    }
+
     let ident = &input.ident;
     let ty = &field.ty;
 
@@ -59,8 +70,12 @@ fn expand_derive_has_sql_type_transparent(
     let (_, ty_generics, _) = generics.split_for_impl();
 
     // add db type for clause
+    // lifetimes must be declared before the db type
     let mut generics = generics.clone();
-    generics.params.insert(0, parse_quote!(DB: sqlx::Database));
+    let lifetimes = generics.lifetimes().count();
+    generics
+        .params
+        .insert(lifetimes, parse_quote!(DB: sqlx::Database));
     generics
         .make_where_clause()
         .predicates
@@ -77,6 +92,54 @@ fn expand_derive_has_sql_type_transparent(
     ))
 }
 
+// With #[sqlx(compatible = "..")], the type is the builtin type of Postgres with that name; the
+// OID of the type is resolved here so that it is not looked up at runtime
+#[cfg(feature = "postgres")]
+fn expand_derive_has_sql_type_compatible(
+    input: &DeriveInput,
+    compatible: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let oid = match sqlx::postgres::PgTypeInfo::__resolve_builtin_oid(compatible) {
+        Some(oid) => oid,
+        None => {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "unknown builtin type {:?} in #[sqlx(compatible = ..)]",
+                    compatible
+                ),
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics sqlx::types::Type< sqlx::Postgres > for #ident #ty_generics
+        #where_clause
+        {
+            fn type_info() -> sqlx::postgres::PgTypeInfo {
+                sqlx::postgres::PgTypeInfo::__with_builtin_oid(#oid)
+            }
+        }
+    ))
+}
+
+#[cfg(not(feature = "postgres"))]
+fn expand_derive_has_sql_type_compatible(
+    input: &DeriveInput,
+    compatible: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    Err(syn::Error::new_spanned(
+        input,
+        format!(
+            "#[sqlx(compatible = {:?})] requires the `postgres` feature",
+            compatible
+        ),
+    ))
+}
+
 fn expand_derive_has_sql_type_weak_enum(
     input: &DeriveInput,
     variants: &Punctuated<Variant, Comma>,
@@ -97,6 +160,26 @@ fn expand_derive_has_sql_type_weak_enum(
     ))
 }
 
+fn expand_derive_has_sql_type_via_int_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attr = check_via_int_enum_attributes(input, variants)?;
+    let int = attr.via_int.unwrap();
+    let ident = &input.ident;
+
+    Ok(quote!(
+        impl<DB: sqlx::Database> sqlx::Type<DB> for #ident
+        where
+            #int: sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <#int as sqlx::Type<DB>>::type_info()
+            }
+        }
+    ))
+}
+
 fn expand_derive_has_sql_type_strong_enum(
     input: &DeriveInput,
     variants: &Punctuated<Variant, Comma>,
@@ -117,7 +200,51 @@ fn expand_derive_has_sql_type_strong_enum(
     }
 
     if cfg!(feature = "postgres") {
-        let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
+        let ty_name = attributes
+            .rename
+            .clone()
+            .unwrap_or_else(|| ident.to_string());
+
+        // #[sqlx(create_type)] declares the statement that creates the enum in Postgres
+        if attributes.create_type {
+            let labels = variants
+                .iter()
+                .map(|v| {
+                    let rename = parse_child_attributes(&v.attrs)?.rename;
+                    let label = variant_label(&v.ident, rename, &attributes);
+
+                    Ok(format!("'{}'", label.replace('\'', "''")))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            // each part of the (possibly schema-qualified) name is quoted as an identifier and,
+            // as with an unquoted name, folded to lowercase; a part that is quoted already is
+            // kept as it is
+            let create_name = ty_name
+                .split('.')
+                .map(|part| {
+                    if part.len() > 1 && part.starts_with('"') && part.ends_with('"') {
+                        part.to_owned()
+                    } else {
+                        format!("\"{}\"", part.to_lowercase().replace('"', "\"\""))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(".");
+
+            let create_type = format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                create_name,
+                labels.join(", ")
+            );
+
+            tts.extend(quote!(
+                impl #ident {
+                    /// The statement that creates the type of this enum in Postgres.
+                    pub const CREATE_TYPE_SQL: &'static str = #create_type;
+                }
+            ));
+        }
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
@@ -153,14 +280,142 @@ fn expand_derive_has_sql_type_struct(
     if cfg!(feature = "postgres") {
         let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
 
+        #[cfg(feature = "postgres")]
+        {
+            if attributes.check {
+                check_composite_fields(input, fields, &ty_name)?;
+            }
+        }
+
+        // Postgres names the array type of a composite type by a `_` before its name; the
+        // schema of the type stays in front; e.g., `catalog._inventory_item`. The `_` of a
+        // quoted name goes within the quotes
+        let (schema, name) = match ty_name.rfind('.') {
+            Some(dot) => (&ty_name[..=dot], &ty_name[dot + 1..]),
+            None => ("", &*ty_name),
+        };
+
+        let array_ty_name = match name.strip_prefix('"') {
+            Some(name) => format!("{}\"_{}", schema, name),
+            None => format!("{}_{}", schema, name),
+        };
+
         tts.extend(quote!(
             impl sqlx::types::Type< sqlx::Postgres > for #ident {
                 fn type_info() -> sqlx::postgres::PgTypeInfo {
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::types::PgHasArrayType for #ident {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
     Ok(tts)
 }
+
+// With #[sqlx(check)], the fields are checked in order against the attributes of the composite
+// type in the database at DATABASE_URL; without a Postgres DATABASE_URL, nothing is checked
+#[cfg(feature = "postgres")]
+fn check_composite_fields(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+    ty_name: &str,
+) -> syn::Result<()> {
+    use crate::database::DatabaseExt;
+    use sqlx::connection::Connect;
+    use sqlx::executor::Executor;
+    use sqlx::postgres::{PgConnection, Postgres};
+
+    let db_url = match dotenv::var("DATABASE_URL") {
+        Ok(url) if url.starts_with("postgres:") || url.starts_with("postgresql:") => url,
+        _ => return Ok(()),
+    };
+
+    // The columns of `(NULL::type).*` are the attributes of the type
+    let describe = crate::block_on(async {
+        let mut conn = PgConnection::connect(&*db_url).await?;
+        let query = format!("SELECT (NULL::{}).*", ty_name);
+
+        conn.describe(&*query).await
+    })
+    .map_err(|e| {
+        syn::Error::new_spanned(
+            input,
+            format!("failed to check composite type {}: {}", ty_name, e),
+        )
+    })?;
+
+    let attributes = &describe.result_columns;
+
+    if attributes.len() != fields.len() {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!(
+                "composite type {} has {} attributes but the struct has {} fields",
+                ty_name,
+                attributes.len(),
+                fields.len()
+            ),
+        ));
+    }
+
+    for (field, attribute) in fields.iter().zip(attributes.iter()) {
+        let type_info = match &attribute.type_info {
+            Some(type_info) => type_info,
+            None => continue,
+        };
+
+        let expected = match <Postgres as DatabaseExt>::return_type_for_id(type_info) {
+            Some(expected) => expected,
+
+            // e.g., another composite type or an enum; these can not be checked by name
+            None => continue,
+        };
+
+        let borrowed = <Postgres as DatabaseExt>::param_type_for_id(type_info);
+
+        if !field_type_matches(&field.ty, expected)
+            && !matches!(borrowed, Some(borrowed) if field_type_matches(&field.ty, borrowed))
+        {
+            let ty = &field.ty;
+
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "field `{}` has type `{}` but attribute {} of composite type {} is {}; \
+                     expected `{}`",
+                    field.ident.as_ref().unwrap(),
+                    quote!(#ty).to_string().replace(' ', ""),
+                    attribute.name.as_deref().unwrap_or("?"),
+                    ty_name,
+                    type_info,
+                    expected
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Compare a field type, optionally wrapped in `Option`, with the name of a type as written by
+// [DatabaseExt]; a type may be named by its full path or only its last segment
+#[cfg(feature = "postgres")]
+fn field_type_matches(ty: &syn::Type, expected: &str) -> bool {
+    let mut ty = quote!(#ty).to_string().replace(' ', "");
+
+    if ty.starts_with("Option<") && ty.ends_with('>') {
+        ty = ty["Option<".len()..ty.len() - 1].to_owned();
+    }
+
+    let expected = expected.replace(' ', "");
+
+    ty == expected
+        || expected.ends_with(&format!("::{}", ty))
+        || ty.ends_with(&format!("::{}", expected))
+}
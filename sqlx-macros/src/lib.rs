@@ -19,6 +19,7 @@ type Result<T> = std::result::Result<T, Error>;
 
 mod database;
 mod derives;
+mod migrate;
 mod query_macros;
 mod runtime;
 
@@ -163,6 +164,16 @@ pub fn query_file_as_unchecked(input: TokenStream) -> TokenStream {
     async_macro!(db, input: QueryAsMacroInput => expand_query_file_as(input, db, false))
 }
 
+#[proc_macro]
+pub fn migrate(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as migrate::MigrateInput);
+
+    match migrate::expand_migrator(input) {
+        Ok(ts) => macro_result(ts),
+        Err(e) => macro_result(e.to_compile_error()),
+    }
+}
+
 #[proc_macro_derive(Encode, attributes(sqlx))]
 pub fn derive_encode(tokenstream: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(tokenstream as syn::DeriveInput);
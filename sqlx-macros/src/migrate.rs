@@ -0,0 +1,118 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+pub struct MigrateInput {
+    path: Option<LitStr>,
+}
+
+impl Parse for MigrateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = if input.is_empty() {
+            None
+        } else {
+            Some(input.parse()?)
+        };
+
+        Ok(MigrateInput { path })
+    }
+}
+
+struct MigrationFile {
+    version: i64,
+    description: String,
+    path: String,
+}
+
+// Embed the `<version>_<description>.sql` files of the directory as a `Migrator`
+// The files are included with `include_str!` so that changing them triggers a rebuild
+pub fn expand_migrator(input: MigrateInput) -> syn::Result<TokenStream> {
+    let (source, span) = match &input.path {
+        Some(path) => (path.value(), path.span()),
+        None => ("migrations".to_owned(), Span::call_site()),
+    };
+
+    let base_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+        syn::Error::new(
+            span,
+            "CARGO_MANIFEST_DIR is not set; please use Cargo to build",
+        )
+    })?;
+
+    let dir = Path::new(&base_dir).join(&source);
+
+    let entries = fs::read_dir(&dir).map_err(|e| {
+        syn::Error::new(
+            span,
+            format!("failed to read migrations at {}: {}", dir.display(), e),
+        )
+    })?;
+
+    let mut migrations = Vec::new();
+
+    for entry in entries {
+        let path = entry
+            .map_err(|e| syn::Error::new(span, format!("failed to read migrations: {}", e)))?
+            .path();
+
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("sql") {
+            continue;
+        }
+
+        let file_name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("");
+
+        let mut parts = file_name.splitn(2, '_');
+        let version = parts.next().and_then(|version| version.parse::<i64>().ok());
+
+        let version = version.ok_or_else(|| {
+            syn::Error::new(
+                span,
+                format!(
+                    "migration file name {:?} must be of the form <version>_<description>.sql",
+                    file_name
+                ),
+            )
+        })?;
+
+        let description = parts.next().unwrap_or("").replace('_', " ");
+
+        migrations.push(MigrationFile {
+            version,
+            description,
+            path: path.to_string_lossy().into_owned(),
+        });
+    }
+
+    migrations.sort_by_key(|migration| migration.version);
+
+    if let Some(pair) = migrations.windows(2).find(|w| w[0].version == w[1].version) {
+        return Err(syn::Error::new(
+            span,
+            format!("duplicate migration version {}", pair[0].version),
+        ));
+    }
+
+    let migrations = migrations.iter().map(|migration| {
+        let MigrationFile {
+            version,
+            description,
+            path,
+        } = migration;
+
+        quote! {
+            sqlx::migrate::Migration::new(#version, #description, include_str!(#path))
+        }
+    });
+
+    Ok(quote! {
+        sqlx::migrate::Migrator::new(vec![#(#migrations),*])
+    })
+}
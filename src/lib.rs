@@ -6,13 +6,14 @@ pub use sqlx_core::cursor::{self, Cursor};
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::executor::{self, Execute, Executor};
 pub use sqlx_core::pool::{self, Pool};
-pub use sqlx_core::query::{self, query, Query};
+pub use sqlx_core::query::{self, query, query_from_file, Query};
 pub use sqlx_core::query_as::{query_as, QueryAs};
+pub use sqlx_core::query_scalar::{query_scalar, QueryScalar};
 pub use sqlx_core::row::{self, FromRow, Row};
 pub use sqlx_core::transaction::Transaction;
 pub use sqlx_core::value;
+pub use sqlx_core::Either;
 
-#[doc(hidden)]
 pub use sqlx_core::describe;
 
 #[doc(inline)]
@@ -29,6 +30,14 @@ pub use sqlx_core::mysql::{self, MySql, MySqlConnection, MySqlPool};
 #[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
 pub use sqlx_core::postgres::{self, PgConnection, PgPool, Postgres};
 
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub use sqlx_core::unnest_columns;
+
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub use sqlx_core::migrate;
+
 #[cfg(feature = "sqlite")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub use sqlx_core::sqlite::{self, Sqlite, SqliteConnection, SqlitePool};
@@ -54,7 +63,7 @@ pub mod result_ext;
 
 /// Types and traits for encoding values for the database.
 pub mod encode {
-    pub use sqlx_core::encode::{Encode, IsNull};
+    pub use sqlx_core::encode::{EmptyAsNull, Encode, IsNull};
 
     #[cfg(feature = "macros")]
     pub use sqlx_macros::Encode;
@@ -78,11 +87,11 @@ pub mod prelude {
     pub use super::Row;
 
     #[cfg(feature = "postgres")]
-    pub use super::postgres::PgQueryAs;
+    pub use super::postgres::{PgQueryAs, PgQueryScalar};
 
     #[cfg(feature = "mysql")]
-    pub use super::mysql::MySqlQueryAs;
+    pub use super::mysql::{MySqlQueryAs, MySqlQueryScalar};
 
     #[cfg(feature = "sqlite")]
-    pub use super::sqlite::SqliteQueryAs;
+    pub use super::sqlite::{SqliteQueryAs, SqliteQueryScalar};
 }
@@ -363,3 +363,36 @@ macro_rules! query_file_as_unchecked (
         macro_result!($($args),*)
     })
 );
+
+/// Embeds the migrations of a directory in the application as a
+/// [`Migrator`](migrate/struct.Migrator.html).
+///
+/// The directory is relative to the project root (the directory containing `Cargo.toml`) and
+/// defaults to `migrations`. Each migration is a file named `<version>_<description>.sql`,
+/// e.g., `1_create_users.sql`; migrations are applied in ascending order of version.
+///
+/// ```rust,ignore
+/// sqlx::migrate!("migrations").run(pool.acquire().await?).await?;
+/// ```
+#[macro_export]
+#[cfg(all(feature = "macros", feature = "postgres"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "macros", feature = "postgres"))))]
+macro_rules! migrate (
+    ($dir:literal) => ({
+        #[allow(dead_code)]
+        #[macro_use]
+        mod _macro_result {
+            $crate::sqlx_macros::migrate!($dir);
+        }
+        macro_result!()
+    });
+
+    () => ({
+        #[allow(dead_code)]
+        #[macro_use]
+        mod _macro_result {
+            $crate::sqlx_macros::migrate!();
+        }
+        macro_result!()
+    })
+);
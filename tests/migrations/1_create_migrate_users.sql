@@ -0,0 +1,4 @@
+CREATE TABLE migrate_users (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL
+);
@@ -0,0 +1,3 @@
+ALTER TABLE migrate_users ADD COLUMN email TEXT;
+
+INSERT INTO migrate_users (name, email) VALUES ('Alice', 'alice@example.com');
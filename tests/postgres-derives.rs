@@ -1,5 +1,5 @@
 use sqlx::{postgres::PgQueryAs, Executor, Postgres};
-use sqlx_test::{new, test_type};
+use sqlx_test::{new, test_type, test_unprepared_type};
 use std::fmt::Debug;
 
 // Transparent types are rust-side wrappers over DB types
@@ -7,6 +7,21 @@ use std::fmt::Debug;
 #[sqlx(transparent)]
 struct Transparent(i32);
 
+// The SQL type of a transparent wrapper over an `Option` is that of the optional type
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(transparent)]
+struct MaybeId(Option<i32>);
+
+// The SQL type of a transparent wrapper over a `Vec` is the array type; e.g., TEXT[]
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(transparent)]
+struct Tags(Vec<String>);
+
+// With #[sqlx(compatible = "..")] the SQL type is the named builtin type
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(transparent, compatible = "varchar")]
+struct Email(String);
+
 // "Weak" enums map to an integer type indicated by #[repr]
 #[derive(PartialEq, Copy, Clone, Debug, sqlx::Type)]
 #[repr(i32)]
@@ -16,10 +31,50 @@ enum Weak {
     Three = 4,
 }
 
+// With #[sqlx(ordinal)] the value of each variant is its position, stored as the integer
+// type of #[sqlx(repr)]
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(repr = "i16", ordinal)]
+enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+// With #[sqlx(via_int = "..")] an enum is converted with its own conversions to and from the
+// integer type; its layout does not matter
+#[derive(PartialEq, Debug, Clone, sqlx::Type)]
+#[sqlx(via_int = "i32")]
+enum Status {
+    Ok,
+    NotFound,
+    Other(i32),
+}
+
+impl From<i32> for Status {
+    fn from(code: i32) -> Self {
+        match code {
+            200 => Status::Ok,
+            404 => Status::NotFound,
+            code => Status::Other(code),
+        }
+    }
+}
+
+impl From<Status> for i32 {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Ok => 200,
+            Status::NotFound => 404,
+            Status::Other(code) => code,
+        }
+    }
+}
+
 // "Strong" enums can map to TEXT (25) or a custom enum type
 #[derive(PartialEq, Debug, sqlx::Type)]
 #[sqlx(rename = "text")]
-#[sqlx(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase", eq_label, from_str)]
 enum Strong {
     One,
     Two,
@@ -28,6 +83,110 @@ enum Strong {
     Three,
 }
 
+// A prefix can be applied to the labels of a "strong" enum after `rename_all`
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "text")]
+#[sqlx(label_prefix = "order_", rename_all = "snake_case", from_str)]
+enum OrderStatus {
+    Pending,
+    PartiallyShipped,
+    AwaitingHTTPCallback,
+
+    #[sqlx(rename = "delivered")]
+    Delivered,
+}
+
+// The labels of a "strong" enum stored in a fixed-width column are padded with spaces
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "bpchar")]
+#[sqlx(rename_all = "lowercase", trim, eq_label)]
+enum Padded {
+    One,
+    Two,
+}
+
+// With #[sqlx(order_by_ordinal)] a "strong" enum is ordered by the declaration of its variants
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(rename = "text")]
+#[sqlx(rename_all = "lowercase", order_by_ordinal)]
+enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+// Labels of a "strong" enum may have aliases which are accepted when decoding
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "text", from_str)]
+enum Color {
+    #[sqlx(rename = "red", alias("crimson", "RED"))]
+    Red,
+
+    #[sqlx(rename = "blue")]
+    Blue,
+}
+
+// A "strong" enum may implement `FromStr` itself; it is only derived with #[sqlx(from_str)]
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "text", rename_all = "lowercase")]
+enum Direction {
+    North,
+    South,
+}
+
+impl std::str::FromStr for Direction {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "N" => Ok(Direction::North),
+            "S" => Ok(Direction::South),
+            _ => Err(format!("unknown direction {:?}", value)),
+        }
+    }
+}
+
+// With #[sqlx(verbatim)] the label of each variant of a "strong" enum is its identifier
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "text", verbatim)]
+enum Verbatim {
+    One,
+    TwoWords,
+}
+
+// With #[sqlx(create_type)] a "strong" enum declares the statement that creates its type
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "weather", rename_all = "snake_case", create_type)]
+enum Weather {
+    Sunny,
+    PartlyCloudy,
+
+    #[sqlx(rename = "it's raining")]
+    Raining,
+}
+
+// The name of the type is quoted in its CREATE TYPE statement
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(
+    rename = "public.Traffic Lights",
+    rename_all = "lowercase",
+    create_type
+)]
+enum Lights {
+    Green,
+    Amber,
+    Red,
+}
+
+// A quoted name keeps its case
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "public.\"Signal\"", rename_all = "lowercase", create_type)]
+enum Signal {
+    Go,
+    Stop,
+}
+
 // Records must map to a custom type
 // Note that all types are types in Postgres
 #[derive(PartialEq, Debug, sqlx::Type)]
@@ -38,6 +197,22 @@ struct InventoryItem {
     price: Option<i64>,
 }
 
+// The name of the type may be qualified with its schema
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "sqlx_catalog.inventory_item")]
+struct CatalogInventoryItem {
+    name: String,
+    price: i64,
+}
+
+// Fields of records may be arrays
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "tagged_inventory_item")]
+struct TaggedInventoryItem {
+    name: String,
+    tags: Vec<String>,
+}
+
 test_type!(transparent(
     Postgres,
     Transparent,
@@ -45,6 +220,90 @@ test_type!(transparent(
     "23523" == Transparent(23523)
 ));
 
+test_type!(transparent_option(
+    Postgres,
+    MaybeId,
+    "NULL::int4" == MaybeId(None),
+    "5::int4" == MaybeId(Some(5))
+));
+
+#[test]
+fn test_transparent_option_type_info() {
+    use sqlx::Type;
+
+    assert_eq!(
+        <MaybeId as Type<Postgres>>::type_info(),
+        <i32 as Type<Postgres>>::type_info()
+    );
+}
+
+test_type!(transparent_vec(
+    Postgres,
+    Tags,
+    "ARRAY['rust', 'sql']::text[]" == Tags(vec!["rust".to_owned(), "sql".to_owned()]),
+    "'{}'::text[]" == Tags(vec![])
+));
+
+#[test]
+fn test_transparent_vec_type_info() {
+    use sqlx::Type;
+
+    assert_eq!(
+        <Tags as Type<Postgres>>::type_info(),
+        <Vec<String> as Type<Postgres>>::type_info()
+    );
+}
+
+#[test]
+fn test_compatible_type_info() {
+    use sqlx::Type;
+
+    assert_eq!(<Email as Type<Postgres>>::type_info().oid(), Some(1043));
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_compatible_to_varchar() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE subscribers (email VARCHAR NOT NULL)")
+        .await?;
+
+    sqlx::query("INSERT INTO subscribers (email) VALUES ($1)")
+        .bind(Email("joe@example.com".to_owned()))
+        .execute(&mut conn)
+        .await?;
+
+    let (email, type_name): (Email, String) =
+        sqlx::query_as("SELECT email, pg_typeof($1)::text FROM subscribers")
+            .bind(Email("joe@example.com".to_owned()))
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(email, Email("joe@example.com".to_owned()));
+    assert_eq!(type_name, "character varying");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_transparent_none() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // `None` is sent as a NULL of INT4
+    let (is_null, type_name): (bool, String) =
+        sqlx::query_as("SELECT $1 IS NULL, pg_typeof($1)::text")
+            .bind(MaybeId(None))
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(is_null);
+    assert_eq!(type_name, "integer");
+
+    Ok(())
+}
+
 test_type!(weak_enum(
     Postgres,
     Weak,
@@ -53,6 +312,35 @@ test_type!(weak_enum(
     "4::int4" == Weak::Three
 ));
 
+test_type!(ordinal_enum(
+    Postgres,
+    Priority,
+    "0::int2" == Priority::Low,
+    "1::int2" == Priority::Medium,
+    "2::int2" == Priority::High
+));
+
+test_type!(via_int_enum(
+    Postgres,
+    Status,
+    "200::int4" == Status::Ok,
+    "404::int4" == Status::NotFound,
+    "500::int4" == Status::Other(500)
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_ordinal_enum_out_of_range() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: sqlx::Result<(Priority,)> =
+        sqlx::query_as("SELECT 3::int2").fetch_one(&mut conn).await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
 test_type!(strong_enum(
     Postgres,
     Strong,
@@ -61,6 +349,198 @@ test_type!(strong_enum(
     "'four'::text" == Strong::Three
 ));
 
+test_type!(strong_enum_label_prefix(
+    Postgres,
+    OrderStatus,
+    "'order_pending'::text" == OrderStatus::Pending,
+    "'order_partially_shipped'::text" == OrderStatus::PartiallyShipped,
+    "'order_awaiting_http_callback'::text" == OrderStatus::AwaitingHTTPCallback,
+    "'delivered'::text" == OrderStatus::Delivered
+));
+
+test_type!(strong_enum_trim(
+    Postgres,
+    Padded,
+    "'one'::char(10)" == Padded::One,
+    "'two'::char(10)" == Padded::Two
+));
+
+// An alias is decoded into its variant but the variant is always encoded as its label
+test_type!(strong_enum_alias(
+    Postgres,
+    Color,
+    "'red'::text" == Color::Red,
+    "'blue'::text" == Color::Blue
+));
+
+test_unprepared_type!(strong_enum_alias_decode(
+    Postgres,
+    Color,
+    "'crimson'::text" == Color::Red,
+    "'RED'::text" == Color::Red
+));
+
+test_type!(strong_enum_verbatim(
+    Postgres,
+    Verbatim,
+    "'One'::text" == Verbatim::One,
+    "'TwoWords'::text" == Verbatim::TwoWords
+));
+
+// With #[sqlx(from_str)], a "strong" enum is parsed from its labels with `FromStr`
+#[test]
+fn test_strong_enum_from_str() {
+    assert_eq!("two".parse::<Strong>().unwrap(), Strong::Two);
+    assert_eq!("four".parse::<Strong>().unwrap(), Strong::Three);
+    assert_eq!(
+        "order_partially_shipped".parse::<OrderStatus>().unwrap(),
+        OrderStatus::PartiallyShipped
+    );
+
+    // the variant name is not a label once renamed
+    let err = "Three".parse::<Strong>().unwrap_err();
+
+    assert_eq!(err.label(), "Three");
+    assert_eq!(err.to_string(), "invalid value \"Three\" for enum Strong");
+
+    let _: &dyn std::error::Error = &err;
+
+    assert_eq!("crimson".parse::<Color>().unwrap(), Color::Red);
+    assert_eq!("RED".parse::<Color>().unwrap(), Color::Red);
+    assert!("Red".parse::<Color>().is_err());
+}
+
+test_type!(strong_enum_with_own_from_str(
+    Postgres,
+    Direction,
+    "'north'::text" == Direction::North,
+    "'south'::text" == Direction::South
+));
+
+#[test]
+fn test_strong_enum_own_from_str() {
+    assert_eq!("N".parse::<Direction>().unwrap(), Direction::North);
+    assert!("north".parse::<Direction>().is_err());
+}
+
+#[test]
+fn test_strong_enum_create_type_sql() {
+    assert_eq!(
+        Weather::CREATE_TYPE_SQL,
+        "CREATE TYPE \"weather\" AS ENUM ('sunny', 'partly_cloudy', 'it''s raining')"
+    );
+
+    assert_eq!(
+        Lights::CREATE_TYPE_SQL,
+        "CREATE TYPE \"public\".\"traffic lights\" AS ENUM ('green', 'amber', 'red')"
+    );
+
+    assert_eq!(
+        Signal::CREATE_TYPE_SQL,
+        "CREATE TYPE \"public\".\"Signal\" AS ENUM ('go', 'stop')"
+    );
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_strong_enum_create_type() -> anyhow::Result<()> {
+    use sqlx::Connection;
+
+    let conn = new::<Postgres>().await?;
+
+    // the type is only created for the transaction
+    let mut tx = conn.begin().await?;
+
+    tx.execute(Weather::CREATE_TYPE_SQL).await?;
+
+    // binding a value fetches (and caches) the OID of the type
+    let (weather, sunny): (Weather, Weather) =
+        sqlx::query_as("SELECT 'it''s raining'::weather, $1")
+            .bind(Weather::Sunny)
+            .fetch_one(&mut tx)
+            .await?;
+
+    assert_eq!(weather, Weather::Raining);
+    assert_eq!(sunny, Weather::Sunny);
+
+    tx.execute(Lights::CREATE_TYPE_SQL).await?;
+
+    let (lights,): (Lights,) = sqlx::query_as("SELECT $1")
+        .bind(Lights::Amber)
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(lights, Lights::Amber);
+
+    tx.execute(Signal::CREATE_TYPE_SQL).await?;
+
+    let (signal,): (Signal,) = sqlx::query_as("SELECT $1 AS signal")
+        .bind(Signal::Stop)
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(signal, Signal::Stop);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[test]
+fn test_strong_enum_eq_label() {
+    assert!(Strong::One.eq_label("one"));
+    assert!(Strong::Three.eq_label("four"));
+    assert!(!Strong::Three.eq_label("three"));
+    assert!(!Strong::Two.eq_label("one"));
+
+    // the padding of a fixed-width label is ignored with #[sqlx(trim)]
+    assert!(Padded::One.eq_label("one       "));
+    assert!(!Strong::One.eq_label("one       "));
+}
+
+#[test]
+fn test_strong_enum_order_by_ordinal() {
+    use std::collections::BTreeSet;
+
+    assert!(Severity::Low < Severity::Medium);
+    assert!(Severity::High < Severity::Critical);
+
+    // the labels would sort as critical, high, low, medium
+    let mut severities = vec![
+        Severity::Critical,
+        Severity::Low,
+        Severity::High,
+        Severity::Medium,
+    ];
+    severities.sort();
+
+    assert_eq!(
+        severities,
+        vec![
+            Severity::Low,
+            Severity::Medium,
+            Severity::High,
+            Severity::Critical
+        ]
+    );
+
+    let set: BTreeSet<_> = vec![Severity::High, Severity::Low, Severity::High]
+        .into_iter()
+        .collect();
+
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        vec![Severity::Low, Severity::High]
+    );
+}
+
+test_type!(severity(
+    Postgres,
+    Severity,
+    "'low'::text" == Severity::Low,
+    "'critical'::text" == Severity::Critical
+));
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn test_record_type() -> anyhow::Result<()> {
@@ -104,6 +584,164 @@ END $$;
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_record_type_in_schema() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // Unlike the type of the same name in the public schema, this has no supplier
+    conn.execute(
+        r#"
+CREATE SCHEMA IF NOT EXISTS sqlx_catalog;
+
+DO $$ BEGIN
+
+CREATE TYPE sqlx_catalog.inventory_item AS (
+    name            text,
+    price           bigint
+);
+
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+    "#,
+    )
+    .await?;
+
+    let value = CatalogInventoryItem {
+        name: "fuzzy dice".to_owned(),
+        price: 199,
+    };
+
+    let rec: (bool, CatalogInventoryItem) = sqlx::query_as(
+        "
+        SELECT $1 = ROW('fuzzy dice', 199)::sqlx_catalog.inventory_item, $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_record_type_with_array_field() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DO $$ BEGIN
+
+CREATE TYPE tagged_inventory_item AS (
+    name            text,
+    tags            text[]
+);
+
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+    "#,
+    )
+    .await?;
+
+    let value = TaggedInventoryItem {
+        name: "fuzzy dice".to_owned(),
+        tags: vec![
+            "red".to_owned(),
+            "large size".to_owned(),
+            "\"quoted\"".to_owned(),
+        ],
+    };
+
+    let rec: (bool, TaggedInventoryItem) = sqlx::query_as(
+        r#"
+        SELECT $1 = ROW('fuzzy dice', ARRAY['red', 'large size', '"quoted"'])::tagged_inventory_item, $1
+        "#,
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    // an empty array field
+    let rec: (TaggedInventoryItem,) =
+        sqlx::query_as("SELECT ROW('nothing', '{}'::text[])::tagged_inventory_item")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0.name, "nothing");
+    assert!(rec.0.tags.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_array_of_records_with_array_field() -> anyhow::Result<()> {
+    use sqlx::{Cursor, Row};
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DO $$ BEGIN
+
+CREATE TYPE tagged_inventory_item AS (
+    name            text,
+    tags            text[]
+);
+
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+    "#,
+    )
+    .await?;
+
+    let items = vec![
+        TaggedInventoryItem {
+            name: "fuzzy dice".to_owned(),
+            tags: vec!["red".to_owned(), "large size".to_owned()],
+        },
+        TaggedInventoryItem {
+            name: "air freshener".to_owned(),
+            tags: vec!["pine, fresh".to_owned(), "\"quoted\"".to_owned()],
+        },
+    ];
+
+    let expected = r#"
+        ARRAY[
+            ROW('fuzzy dice', ARRAY['red', 'large size']),
+            ROW('air freshener', ARRAY['pine, fresh', '"quoted"'])
+        ]::tagged_inventory_item[]
+    "#;
+
+    let (matches, returned): (bool, Vec<TaggedInventoryItem>) =
+        sqlx::query_as(&format!("SELECT $1 = {}, $1", expected))
+            .bind(&items)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(matches);
+    assert_eq!(returned, items);
+
+    // an unprepared query returns the text form of the nested array and records
+    let sql = format!("SELECT {}", expected);
+    let mut cursor = conn.fetch(&*sql);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<Vec<TaggedInventoryItem>, _>(0)?, items);
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
@@ -151,6 +789,220 @@ async fn test_from_row() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "macros")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_borrowed_and_owned_fields() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+
+    let mut conn = new::<Postgres>().await?;
+
+    #[derive(sqlx::FromRow)]
+    struct Message<'a> {
+        id: i32,
+        author: String,
+        body: &'a str,
+        subject: Option<&'a str>,
+        attachment: &'a [u8],
+    }
+
+    let mut cursor = sqlx::query(
+        "SELECT 1 AS id, 'Alice'::text AS author, 'Hello'::text AS body, \
+             NULL::text AS subject, '\\x00ff'::bytea AS attachment",
+    )
+    .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+    let message = Message::from_row(&row)?;
+
+    assert_eq!(message.id, 1);
+    assert_eq!(message.author, "Alice");
+    assert_eq!(message.body, "Hello");
+    assert_eq!(message.subject, None);
+    assert_eq!(message.attachment, &[0x00, 0xff]);
+
+    // the owned fields may outlive the row
+    let author = message.author;
+
+    drop(row);
+
+    assert_eq!(author, "Alice");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_fetch_all() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+        name: String,
+    }
+
+    let accounts: Vec<Account> = sqlx::query_as(
+        "SELECT * from (VALUES (1, 'Herp Derpinson'), (2, 'Harp Darpinson')) accounts(id, name) \
+         ORDER BY id",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(
+        accounts,
+        vec![
+            Account {
+                id: 1,
+                name: "Herp Derpinson".to_owned()
+            },
+            Account {
+                id: 2,
+                name: "Harp Darpinson".to_owned()
+            },
+        ]
+    );
+
+    // No rows is an empty `Vec` rather than an error
+    let accounts: Vec<Account> = sqlx::query_as(
+        "SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name) where id = $1",
+    )
+    .bind(2_i32)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert!(accounts.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_fetch_all_returning() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+        balance: i64,
+    }
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE accounts (id INT PRIMARY KEY, balance INT8 NOT NULL);
+INSERT INTO accounts VALUES (1, 100), (2, 200), (3, 300);
+        "#,
+    )
+    .await?;
+
+    let mut accounts: Vec<Account> =
+        sqlx::query_as("UPDATE accounts SET balance = balance + $1 WHERE id < 3 RETURNING *")
+            .bind(5_i64)
+            .fetch_all(&mut conn)
+            .await?;
+
+    // the order of the rows of RETURNING is not defined
+    accounts.sort_by_key(|account| account.id);
+
+    assert_eq!(
+        accounts,
+        vec![
+            Account {
+                id: 1,
+                balance: 105
+            },
+            Account {
+                id: 2,
+                balance: 205
+            },
+        ]
+    );
+
+    // the statement completed; the next query on the connection sees the update
+    let (total,): (i64,) = sqlx::query_as("SELECT SUM(balance)::INT8 FROM accounts")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(total, 610);
+
+    Ok(())
+}
+
+// A field of a "strong" enum is decoded from a column of a Postgres enum type
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_enum_field() -> anyhow::Result<()> {
+    use sqlx::Connection;
+
+    let conn = new::<Postgres>().await?;
+
+    // the type is only created for the transaction
+    let mut conn = conn.begin().await?;
+
+    conn.execute("CREATE TYPE person_mood AS ENUM ('sad', 'ok', 'happy')")
+        .await?;
+
+    #[derive(Debug, PartialEq, sqlx::Type)]
+    #[sqlx(rename = "person_mood", rename_all = "lowercase")]
+    enum Mood {
+        Sad,
+        Ok,
+        Happy,
+    }
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Person {
+        name: String,
+        mood: Mood,
+        previous: Option<Mood>,
+    }
+
+    let sql = "SELECT * FROM (VALUES ('alice', 'happy'::person_mood, NULL::person_mood), \
+                                     ('bob', 'sad'::person_mood, 'ok'::person_mood)) \
+               people(name, mood, previous) \
+               WHERE mood <> $1 OR previous IS NULL \
+               ORDER BY name";
+
+    let people: Vec<Person> = sqlx::query_as(sql)
+        .bind(Mood::Sad)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        people,
+        vec![Person {
+            name: "alice".to_owned(),
+            mood: Mood::Happy,
+            previous: None,
+        }]
+    );
+
+    let people: Vec<Person> = sqlx::query_as(sql)
+        .bind(Mood::Happy)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        people,
+        vec![
+            Person {
+                name: "alice".to_owned(),
+                mood: Mood::Happy,
+                previous: None,
+            },
+            Person {
+                name: "bob".to_owned(),
+                mood: Mood::Sad,
+                previous: Some(Mood::Ok),
+            },
+        ]
+    );
+
+    conn.rollback().await?;
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
@@ -224,3 +1076,314 @@ async fn test_from_row_with_rename() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "macros")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_rename_and_try_from() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+    use std::convert::TryFrom;
+
+    #[derive(Debug, PartialEq)]
+    enum Status {
+        Ok,
+        NotFound,
+    }
+
+    impl TryFrom<i16> for Status {
+        type Error = String;
+
+        fn try_from(value: i16) -> Result<Self, Self::Error> {
+            match value {
+                200 => Ok(Status::Ok),
+                404 => Ok(Status::NotFound),
+                _ => Err(format!("unknown status code: {}", value)),
+            }
+        }
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Response {
+        #[sqlx(rename = "status_code", try_from = "i16")]
+        status: Status,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let response: Response = sqlx::query_as("SELECT 404::int2 AS status_code")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(Status::NotFound, response.status);
+
+    let res: sqlx::Result<Response> = sqlx::query_as("SELECT 500::int2 AS status_code")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_type_check() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        #[sqlx(type_check)]
+        id: i64,
+
+        #[sqlx(type_check)]
+        name: String,
+
+        #[sqlx(type_check)]
+        email: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct LooseAccount {
+        name: String,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account =
+        sqlx::query_as("SELECT 1::int8 AS id, 'alice'::text AS name, NULL::text AS email")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "alice");
+    assert_eq!(account.email, None);
+
+    // a VARCHAR is compatible with a String but is not its exact type
+    let res: sqlx::Result<Account> =
+        sqlx::query_as("SELECT 1::int8 AS id, 'alice'::varchar AS name, NULL::text AS email")
+            .fetch_one(&mut conn)
+            .await;
+
+    match res {
+        Err(sqlx::Error::Decode(e)) => {
+            let message = e.to_string();
+
+            assert!(message.contains("mismatched types"), "{}", message);
+            assert!(message.contains("VARCHAR"), "{}", message);
+        }
+
+        res => panic!("expected a decode error, got {:?}", res),
+    }
+
+    // without the check the column is accepted
+    let account: LooseAccount = sqlx::query_as("SELECT 'alice'::varchar AS name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(account.name, "alice");
+
+    Ok(())
+}
+
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(transparent)]
+struct Username<'a>(&'a str);
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_transparent_borrowed() -> anyhow::Result<()> {
+    use sqlx::{Cursor, Row};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = sqlx::query("SELECT $1::text AS username, 'bob'::text AS other")
+        .bind(Username("alice"))
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let username: Username<'_> = row.try_get("username")?;
+    let other: Username<'_> = row.try_get(1)?;
+
+    assert_eq!(username, Username("alice"));
+    assert_eq!(other, Username("bob"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_case_insensitive() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(case_insensitive)]
+    struct Account {
+        id: i32,
+        name: String,
+
+        #[sqlx(rename = "Email")]
+        email: Option<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account =
+        sqlx::query_as(r#"SELECT 1 AS "ID", 'alice' AS "NAME", NULL::text AS "EMAIL""#)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "alice");
+    assert_eq!(account.email, None);
+
+    // a column with the exact name is preferred
+    let account: Account = sqlx::query_as(
+        r#"SELECT 1 AS "ID", 'bob' AS "NAME", 'alice' AS name, NULL::text AS "Email""#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.name, "alice");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_enum_discriminant() -> anyhow::Result<()> {
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Rectangle {
+        width: f64,
+        height: f64,
+    }
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    #[sqlx(discriminant = "kind")]
+    enum Shape {
+        #[sqlx(rename = "circle")]
+        Circle { radius: f64 },
+
+        // a variant of a single field is decoded from the whole row
+        #[sqlx(rename = "rectangle")]
+        Rectangle(Rectangle),
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let shapes: Vec<Shape> = sqlx::query_as(
+        "SELECT * FROM (VALUES \
+         ('circle', 1.5::float8, NULL::float8, NULL::float8), \
+         ('rectangle', NULL, 2.0, 3.0)) AS shapes (kind, radius, width, height)",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(
+        shapes,
+        vec![
+            Shape::Circle { radius: 1.5 },
+            Shape::Rectangle(Rectangle {
+                width: 2.0,
+                height: 3.0
+            }),
+        ]
+    );
+
+    let res: sqlx::Result<Shape> = sqlx::query_as("SELECT 'triangle' AS kind")
+        .fetch_one(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::Decode(e)) => {
+            assert_eq!(e.to_string(), "invalid value \"triangle\" for enum Shape");
+        }
+
+        res => panic!("expected a decode error, got {:?}", res),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_rest() -> anyhow::Result<()> {
+    use sqlx::postgres::PgRawValue;
+    use std::collections::HashMap;
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Entity {
+        id: i32,
+
+        #[sqlx(rename = "entity_name")]
+        name: String,
+
+        #[sqlx(rest)]
+        attributes: HashMap<String, PgRawValue>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let entity: Entity = sqlx::query_as(
+        "SELECT 1 AS id, 'widget' AS entity_name, 'blue' AS color, 12 AS weight, \
+         NULL::text AS notes",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(entity.id, 1);
+    assert_eq!(entity.name, "widget");
+
+    let mut keys: Vec<_> = entity.attributes.keys().map(String::as_str).collect();
+    keys.sort();
+
+    assert_eq!(keys, ["color", "notes", "weight"]);
+
+    assert_eq!(entity.attributes["color"].try_decode::<String>()?, "blue");
+    assert_eq!(entity.attributes["weight"].try_decode::<i32>()?, 12);
+    assert!(entity.attributes["notes"].is_null());
+    assert!(entity.attributes["weight"].try_decode::<String>().is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_alias() -> anyhow::Result<()> {
+    use sqlx::postgres::PgRawValue;
+    use std::collections::HashMap;
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Entity {
+        #[sqlx(alias("entity_id", "eid"))]
+        id: i32,
+
+        #[sqlx(rest)]
+        attributes: HashMap<String, PgRawValue>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let entity: Entity = sqlx::query_as("SELECT 'blue' AS color, 7 AS eid")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(entity.id, 7);
+    assert_eq!(entity.attributes.keys().collect::<Vec<_>>(), ["color"]);
+
+    // the name of the field is preferred to its aliases
+    let entity: Entity = sqlx::query_as("SELECT 1 AS entity_id, 2 AS id, 3 AS eid, 4 AS id")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(entity.id, 4);
+    assert!(entity.attributes.is_empty());
+
+    let res = sqlx::query_as::<_, Entity>("SELECT 1 AS uid")
+        .fetch_one(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::ColumnNotFound(name)) => assert_eq!(&*name, "id"),
+        res => panic!("expected a missing column, got {:?}", res),
+    }
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use sqlx::migrate::{MigrateError, Migration, Migrator};
+use sqlx::postgres::PgQueryAs;
 use sqlx::Postgres;
 use sqlx_test::new;
 
@@ -244,3 +246,51 @@ async fn test_array_from_slice() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_migrate() -> anyhow::Result<()> {
+    let conn = new::<Postgres>().await?;
+
+    let migrator = sqlx::migrate!("tests/migrations");
+
+    let versions: Vec<_> = migrator.migrations().iter().map(|m| m.version).collect();
+
+    assert_eq!(versions, [1, 2]);
+    assert_eq!(migrator.migrations()[0].description, "create migrate users");
+
+    // applying the migrations again does nothing
+    let conn = migrator.run(conn).await?;
+    let mut conn = migrator.run(conn).await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM migrate_users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    let applied: Vec<(i64,)> = sqlx::query_as(
+        "SELECT version FROM _sqlx_migrations WHERE version IN (1, 2) ORDER BY version",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(applied, [(1,), (2,)]);
+
+    // a migration that was changed after being applied is an error
+    let changed = Migrator::new(vec![Migration::new(
+        1,
+        "create migrate users",
+        "CREATE TABLE migrate_users ()",
+    )]);
+
+    let res = changed.run(conn).await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::Migrate(ref err))
+            if matches!(err.downcast_ref(), Some(MigrateError::VersionMismatch(1)))
+    ));
+
+    Ok(())
+}
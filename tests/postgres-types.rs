@@ -3,9 +3,15 @@ extern crate time_ as time;
 use sqlx::decode::Decode;
 use sqlx::encode::Encode;
 use sqlx::postgres::types::raw::{PgNumeric, PgNumericSign, PgRecordDecoder, PgRecordEncoder};
-use sqlx::postgres::{PgQueryAs, PgRawBuffer, PgTypeInfo, PgValue};
+use sqlx::postgres::types::{
+    PgInterval, PgJsonBytes, PgJsonPath, PgLSeg, PgLexeme, PgLexemePosition, PgLine, PgLsn,
+    PgMacAddress, PgMacAddress8, PgMoney, PgPath, PgPoint, PgPolygon, PgTid, PgTsQuery, PgTsVector,
+    PgTsWeight, PgXml,
+};
+use sqlx::postgres::{PgQueryAs, PgQueryScalar, PgRawBuffer, PgRow, PgTypeInfo, PgValue};
 use sqlx::{Cursor, Executor, Postgres, Row, Type};
 use sqlx_test::{new, test_prepared_type, test_type};
+use std::time::Duration;
 
 // TODO: With support for concatenation of sql literals in query! macros this should be updated
 macro_rules! array_macro_test {
@@ -53,6 +59,28 @@ array_macro_test!(bool(
 ));
 
 test_type!(i8(Postgres, i8, "120::\"char\"" == 120_i8));
+test_type!(i8_nul(Postgres, i8, "0::\"char\"" == 0_i8));
+
+// `pg_class.relkind` is a "char"; the kind of `pg_class` itself is 'r' (a table)
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_char_from_catalog() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = "SELECT relkind FROM pg_class WHERE relname = 'pg_class'";
+
+    let (prepared,): (i8,) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+    let unprepared: i8 = row.get(0);
+
+    assert_eq!(prepared, b'r' as i8);
+    assert_eq!(unprepared, b'r' as i8);
+
+    Ok(())
+}
+
 test_type!(i16(Postgres, i16, "821::smallint" == 821_i16));
 
 test_type!(i32(
@@ -67,6 +95,67 @@ array_macro_test!(i32(
 ));
 
 test_type!(u32(Postgres, u32, "94101::oid" == 94101_u32));
+
+// The reg* types are aliases for the OID of an object; 1259 is the OID of `pg_class`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_regclass() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (class, oid, name): (u32, u32, String) = sqlx::query_as(
+        "SELECT 'pg_class'::regclass, 'pg_class'::regclass::oid, 'pg_class'::regclass::text",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(class, 1259);
+    assert_eq!(class, oid);
+    assert_eq!(name, "pg_class");
+
+    let (config,): (u32,) = sqlx::query_as("SELECT $1::regconfig")
+        .bind("english")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (oid,): (u32,) = sqlx::query_as("SELECT oid FROM pg_ts_config WHERE cfgname = 'english'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(config, oid);
+
+    Ok(())
+}
+
+// The index of a table on (b, a) has the column numbers 2 and 1 in `indkey`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_int2vector_oidvector() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE vector_test (a INT, b INT)")
+        .await?;
+
+    conn.execute("CREATE INDEX vector_test_b_a ON vector_test (b, a)")
+        .await?;
+
+    let query = "SELECT indkey, '23 25'::oidvector FROM pg_index \
+                 WHERE indexrelid = 'vector_test_b_a'::regclass";
+
+    let (key, types): (Vec<i16>, Vec<u32>) = sqlx::query_as(query).fetch_one(&mut conn).await?;
+
+    assert_eq!(key, vec![2, 1]);
+    assert_eq!(types, vec![23, 25]);
+
+    // an unprepared query returns the text form; e.g., 2 1
+    let mut cursor = conn.fetch(query);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<Vec<i16>, _>(0)?, vec![2, 1]);
+    assert_eq!(row.try_get::<Vec<u32>, _>(1)?, vec![23, 25]);
+
+    Ok(())
+}
+
 test_type!(i64(Postgres, i64, "9358295312::bigint" == 9358295312_i64));
 
 test_type!(f32(Postgres, f32, "9419.122::real" == 9419.122_f32));
@@ -88,6 +177,283 @@ test_type!(string(
     "''" == ""
 ));
 
+test_type!(macaddr(
+    Postgres,
+    PgMacAddress,
+    "'08:00:2b:01:02:03'::macaddr" == PgMacAddress([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]),
+    "'FF-FF-FF-FF-FF-FF'::macaddr" == PgMacAddress([0xff; 6])
+));
+
+test_type!(macaddr8(
+    Postgres,
+    PgMacAddress8,
+    "'08:00:2b:01:02:03:04:05'::macaddr8"
+        == PgMacAddress8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]),
+    // a 6-byte address is stored with FF:FE in the middle
+    "'08:00:2b:01:02:03'::macaddr8"
+        == PgMacAddress8([0x08, 0x00, 0x2b, 0xff, 0xfe, 0x01, 0x02, 0x03])
+));
+
+test_type!(macaddr_vec(
+    Postgres,
+    Vec<PgMacAddress>,
+    "'{08:00:2b:01:02:03,00:00:00:00:00:00}'::macaddr[]"
+        == vec![PgMacAddress([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]), PgMacAddress([0; 6])]
+));
+
+test_type!(pg_lsn(
+    Postgres,
+    PgLsn,
+    "'16/B374D848'::pg_lsn" == PgLsn(0x16_B374_D848),
+    "'0/0'::pg_lsn" == PgLsn(0),
+    "'FFFFFFFF/FFFFFFFF'::pg_lsn" == PgLsn(u64::MAX)
+));
+
+test_type!(pg_lsn_vec(
+    Postgres,
+    Vec<PgLsn>,
+    "'{0/1,1/0}'::pg_lsn[]" == vec![PgLsn(1), PgLsn(1 << 32)]
+));
+
+// The number of fractional digits of MONEY depends on `lc_monetary`; two in most locales
+test_type!(money(
+    Postgres,
+    PgMoney,
+    "'12.34'::money" == PgMoney(1234),
+    "'-0.05'::money" == PgMoney(-5),
+    "'1234567.89'::money" == PgMoney(123456789)
+));
+
+test_type!(money_vec(
+    Postgres,
+    Vec<PgMoney>,
+    "'{12.34,-0.05}'::money[]" == vec![PgMoney(1234), PgMoney(-5)],
+    "'{}'::money[]" == Vec::<PgMoney>::new()
+));
+
+test_type!(money_opt_vec(
+    Postgres,
+    Vec<Option<PgMoney>>,
+    "ARRAY['1.00'::money, NULL]" == vec![Some(PgMoney(100)), None]
+));
+
+test_type!(tid(
+    Postgres,
+    PgTid,
+    "'(0,1)'::tid"
+        == PgTid {
+            block: 0,
+            offset: 1
+        },
+    "'(4294967295,65535)'::tid"
+        == PgTid {
+            block: u32::MAX,
+            offset: u16::MAX
+        }
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_tid_of_ctid() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE tuples (id INT NOT NULL);
+INSERT INTO tuples (id) VALUES (1), (2);
+        "#,
+    )
+    .await?;
+
+    let rows: Vec<(PgTid, i32)> = sqlx::query_as("SELECT ctid, id FROM tuples ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    let locations: Vec<(u32, u16)> = rows
+        .iter()
+        .map(|(tid, _)| (tid.block, tid.offset))
+        .collect();
+
+    // the rows of a new table are the first tuples of its first block
+    assert_eq!(locations, vec![(0, 1), (0, 2)]);
+
+    // a row can be found by its location
+    let (id,): (i32,) = sqlx::query_as("SELECT id FROM tuples WHERE ctid = $1")
+        .bind(rows[1].0)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 2);
+
+    let mut cursor = conn.fetch("SELECT ctid FROM tuples WHERE id = 1");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<PgTid, _>(0)?, rows[0].0);
+
+    Ok(())
+}
+
+test_type!(interval_duration(
+    Postgres,
+    Duration,
+    "'0'::interval" == Duration::from_secs(0),
+    "'90 minutes 1.5 seconds'::interval" == Duration::from_millis(5_401_500),
+    "'30 hours'::interval" == Duration::from_secs(30 * 60 * 60),
+    "'0.000001 seconds'::interval" == Duration::from_micros(1)
+));
+
+test_type!(interval_duration_vec(
+    Postgres,
+    Vec<Duration>,
+    "'{1 second,2 minutes}'::interval[]" == vec![Duration::from_secs(1), Duration::from_secs(120)]
+));
+
+test_type!(interval(
+    Postgres,
+    PgInterval,
+    "'0'::interval" == PgInterval::new(0, 0, 0),
+    "'1 year 2 months 3 days'::interval" == PgInterval::new(14, 3, 0),
+    "'-1 day 90 minutes 1.5 seconds'::interval" == PgInterval::new(0, -1, 5_401_500_000),
+    "'-1 month -00:00:00.000001'::interval" == PgInterval::new(-1, 0, -1)
+));
+
+test_type!(interval_vec(
+    Postgres,
+    Vec<PgInterval>,
+    "'{1 day,2 months}'::interval[]" == vec![PgInterval::new(0, 1, 0), PgInterval::new(2, 0, 0)]
+));
+
+// An interval with months or days, or a negative interval, is not a `Duration`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_interval_not_a_duration() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for sql in &[
+        "SELECT '1 day'::interval",
+        "SELECT '1 month'::interval",
+        "SELECT '-1 second'::interval",
+    ] {
+        let prepared: Result<(Duration,), _> = sqlx::query_as(sql).fetch_one(&mut conn).await;
+
+        assert!(prepared.is_err(), "{}", sql);
+
+        let mut cursor = conn.fetch(*sql);
+        let row = cursor.next().await?.unwrap();
+
+        assert!(row.try_get::<Duration, _>(0).is_err(), "{}", sql);
+    }
+
+    Ok(())
+}
+
+test_type!(name(
+    Postgres,
+    String,
+    "'pg_class'::name" == "pg_class",
+    "''::name" == ""
+));
+
+// The grants of a relation are an ACLITEM[]; e.g., `pg_class.relacl`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_aclitem_from_catalog() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE granted (id INT NOT NULL);
+GRANT SELECT ON granted TO PUBLIC;
+        "#,
+    )
+    .await?;
+
+    let (user,): (String,) = sqlx::query_as("SELECT current_user::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let sql = "SELECT relacl, relacl[1] FROM pg_class WHERE oid = 'granted'::regclass";
+
+    let prepared: (Vec<String>, String) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+    let unprepared: (Vec<String>, String) = (row.try_get(0)?, row.try_get(1)?);
+
+    for (acl, first) in &[prepared, unprepared] {
+        // the owner has all privileges and PUBLIC may SELECT
+        assert_eq!(acl.len(), 2);
+        assert_eq!(acl[0], *first);
+        assert!(acl[0].starts_with(&format!("{}=arwd", user)));
+        assert_eq!(acl[1], format!("=r/{}", user));
+    }
+
+    Ok(())
+}
+
+// The names of objects in the catalogs are of the NAME type; e.g., `pg_attribute.attname`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_name_from_catalog() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = "SELECT relname, ARRAY( \
+                   SELECT attname FROM pg_attribute \
+                   WHERE attrelid = pg_class.oid AND attname IN ('relname', 'relkind') \
+                   ORDER BY attname \
+               ) FROM pg_class WHERE relname = 'pg_class'";
+
+    let prepared: (String, Vec<String>) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+    let unprepared: (String, Vec<String>) = (row.get(0), row.get(1));
+
+    let expected = (
+        "pg_class".to_owned(),
+        vec!["relkind".to_owned(), "relname".to_owned()],
+    );
+
+    assert_eq!(prepared, expected);
+    assert_eq!(unprepared, expected);
+
+    Ok(())
+}
+
+// An untyped string literal is of the `unknown` type before Postgres 10 and is resolved to
+// `text` since; the decoding of a value that really is `unknown` is tested in sqlx-core
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unknown_literal() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (version,): (String,) = sqlx::query_as("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let expected_oid = if version.parse::<u32>()? < 100000 {
+        705
+    } else {
+        25
+    };
+
+    let sql = "SELECT 'literal'";
+
+    let (prepared,): (String,) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+    let unprepared: String = row.try_get(0)?;
+    let raw: sqlx::postgres::PgRawValue = row.try_get_unchecked(0)?;
+
+    assert_eq!(raw.type_info().and_then(|ty| ty.oid()), Some(expected_oid));
+
+    assert_eq!(prepared, "literal");
+    assert_eq!(unprepared, "literal");
+
+    Ok(())
+}
+
 test_type!(bytea(
     Postgres,
     Vec<u8>,
@@ -99,6 +465,335 @@ test_type!(bytea(
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+// `bytea_output` only changes the text format; the value is decoded the same in either format
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bytea_output_escape() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("SET bytea_output = 'escape'").await?;
+
+    let sql = r"SELECT '\x00015c41ff'::bytea";
+
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+    let unprepared: Vec<u8> = row.get(0);
+    drop(cursor);
+
+    let (prepared,): (Vec<u8>,) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(unprepared, [0x00, 0x01, b'\\', b'A', 0xFF]);
+    assert_eq!(prepared, unprepared);
+
+    Ok(())
+}
+
+#[cfg(feature = "bytes")]
+test_type!(bytea_bytes(
+    Postgres,
+    sqlx::types::Bytes,
+    "E'\\\\xDEADBEEF'::bytea" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "E'\\\\x'::bytea" == sqlx::types::Bytes::new()
+));
+
+// A shared value is inserted between the values bound around it
+#[cfg(feature = "bytes")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_large_bytes() -> anyhow::Result<()> {
+    use sqlx::types::Bytes;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let blob: Vec<u8> = (0..4 * 1024 * 1024).map(|i| i as u8).collect();
+
+    let (before, len, matches, after): (i32, i32, bool, String) =
+        sqlx::query_as("SELECT $1, length($2), $2 = $3, $4")
+            .bind(1_i32)
+            .bind(Bytes::from(blob.clone()))
+            .bind(blob)
+            .bind("after")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(before, 1);
+    assert_eq!(len, 4 * 1024 * 1024);
+    assert!(matches);
+    assert_eq!(after, "after");
+
+    Ok(())
+}
+
+// A streamed value is read in several chunks; a reader that ends early fails only its query
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_byte_stream() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgByteStream;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::io::Cursor;
+
+    #[cfg(feature = "runtime-tokio")]
+    use std::io::Cursor;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let blob: Vec<u8> = (0..200 * 1024 + 7).map(|i| (i % 251) as u8).collect();
+
+    let (before, data, after): (i32, Vec<u8>, String) = sqlx::query_as("SELECT $1, $2, $3")
+        .bind(1_i32)
+        .bind(PgByteStream::new(Cursor::new(blob.clone()), blob.len()))
+        .bind("after")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(before, 1);
+    assert!(data == blob);
+    assert_eq!(after, "after");
+
+    let res = sqlx::query("SELECT $1")
+        .bind(PgByteStream::new(Cursor::new(vec![1_u8; 10]), 100))
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // The connection is still usable
+    // "SELECT $1" is cached as a statement with a BYTEA parameter so another query is used
+    let (value,): (i32,) = sqlx::query_as("SELECT $1::int4")
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 2);
+
+    Ok(())
+}
+
+// JSONPATH has no equality operator so it is compared in Rust
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_json_path() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let path = PgJsonPath(r#"$."a"[*]?(@ > 2)"#.to_owned());
+
+    let (returned, matched): (PgJsonPath, String) =
+        sqlx::query_as(r#"SELECT $1, jsonb_path_query_array('{"a": [1, 2, 3, 4]}', $1)::text"#)
+            .bind(&path)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(returned, path);
+    assert_eq!(matched, "[3, 4]");
+
+    {
+        // the path is normalized by Postgres
+        let mut cursor = conn.fetch("SELECT '$.a[*] ? (@ > 2)'::jsonpath");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgJsonPath, _>(0)?, path);
+    }
+
+    Ok(())
+}
+
+// XML has no equality operator so [test_type] cannot be used
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_xml() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let xml = PgXml("<a><b>text</b></a>".to_owned());
+
+    let (returned, text): (PgXml, String) = sqlx::query_as("SELECT $1, $1::text")
+        .bind(&xml)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(returned, xml);
+    assert_eq!(text, xml.0);
+
+    let (prepared,): (PgXml,) = sqlx::query_as("SELECT '<a/>'::xml")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(prepared, PgXml("<a/>".to_owned()));
+
+    {
+        let mut cursor = conn.fetch("SELECT '<a/>'::xml");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgXml, _>(0)?, PgXml("<a/>".to_owned()));
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_json_bytes() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // the same value is received as text when it is not prepared
+    for prepared in &[true, false] {
+        let query = "SELECT '{\"a\": [1, 2]}'::jsonb, '[true]'::json";
+
+        let mut cursor = if *prepared {
+            sqlx::query(query).fetch(&mut conn)
+        } else {
+            conn.fetch(query)
+        };
+
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(
+            row.try_get::<PgJsonBytes, _>(0)?,
+            PgJsonBytes(b"{\"a\": [1, 2]}")
+        );
+        assert_eq!(row.try_get::<PgJsonBytes, _>(1)?, PgJsonBytes(b"[true]"));
+    }
+
+    Ok(())
+}
+
+// The geometric types can not be bound yet so [test_type] cannot be used
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_geometry() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    const QUERY: &str = "SELECT '(1.5,-2)'::point, '{1,-1,0}'::line, '[(0,0),(1,1)]'::lseg, \
+                         '[(0,0),(1,1),(2,0)]'::path, '((0,0),(1,1),(2,0))'::path, \
+                         '((0,0),(0,1),(1,1),(1,0))'::polygon";
+
+    let p = |x, y| PgPoint { x, y };
+
+    let expected = (
+        p(1.5, -2.0),
+        PgLine {
+            a: 1.0,
+            b: -1.0,
+            c: 0.0,
+        },
+        PgLSeg {
+            start: p(0.0, 0.0),
+            end: p(1.0, 1.0),
+        },
+        PgPath {
+            closed: false,
+            points: vec![p(0.0, 0.0), p(1.0, 1.0), p(2.0, 0.0)],
+        },
+        PgPath {
+            closed: true,
+            points: vec![p(0.0, 0.0), p(1.0, 1.0), p(2.0, 0.0)],
+        },
+        PgPolygon {
+            points: vec![p(0.0, 0.0), p(0.0, 1.0), p(1.0, 1.0), p(1.0, 0.0)],
+        },
+    );
+
+    let prepared: (PgPoint, PgLine, PgLSeg, PgPath, PgPath, PgPolygon) =
+        sqlx::query_as(QUERY).fetch_one(&mut conn).await?;
+
+    assert_eq!(prepared, expected);
+
+    let mut cursor = conn.fetch(QUERY);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<PgPoint, _>(0)?, expected.0);
+    assert_eq!(row.try_get::<PgLine, _>(1)?, expected.1);
+    assert_eq!(row.try_get::<PgLSeg, _>(2)?, expected.2);
+    assert_eq!(row.try_get::<PgPath, _>(3)?, expected.3);
+    assert_eq!(row.try_get::<PgPath, _>(4)?, expected.4);
+    assert_eq!(row.try_get::<PgPolygon, _>(5)?, expected.5);
+
+    Ok(())
+}
+
+fn lexeme(word: &str, positions: &[u16]) -> PgLexeme {
+    PgLexeme {
+        word: word.to_owned(),
+        positions: positions
+            .iter()
+            .map(|&position| PgLexemePosition {
+                position,
+                weight: PgTsWeight::D,
+            })
+            .collect(),
+    }
+}
+
+test_type!(tsvector(
+    Postgres,
+    PgTsVector,
+    "to_tsvector('simple', 'a b c')"
+        == PgTsVector {
+            lexemes: vec![lexeme("a", &[1]), lexeme("b", &[2]), lexeme("c", &[3])]
+        },
+    "'a b c'::tsvector"
+        == PgTsVector {
+            lexemes: vec![lexeme("a", &[]), lexeme("b", &[]), lexeme("c", &[])]
+        },
+    "'''it''''s'':1A'::tsvector"
+        == PgTsVector {
+            lexemes: vec![PgLexeme {
+                word: "it's".to_owned(),
+                positions: vec![PgLexemePosition {
+                    position: 1,
+                    weight: PgTsWeight::A,
+                }],
+            }]
+        },
+    "''::tsvector" == PgTsVector::default(),
+));
+
+test_type!(tsquery(
+    Postgres,
+    PgTsQuery,
+    "to_tsquery('simple', 'a & !b')"
+        == PgTsQuery::And(
+            Box::new(PgTsQuery::lexeme("a")),
+            Box::new(PgTsQuery::Not(Box::new(PgTsQuery::lexeme("b")))),
+        ),
+    "to_tsquery('simple', 'a | b <-> c:*A')"
+        == PgTsQuery::Or(
+            Box::new(PgTsQuery::lexeme("a")),
+            Box::new(PgTsQuery::Phrase(
+                Box::new(PgTsQuery::lexeme("b")),
+                Box::new(PgTsQuery::Lexeme {
+                    word: "c".to_owned(),
+                    weights: PgTsWeight::A.mask(),
+                    prefix: true,
+                }),
+                1,
+            )),
+        ),
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decode_empty_tsquery() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // every word is a stop word
+    let query = "SELECT to_tsquery('english', 'the')";
+
+    // prepared
+    let empty: PgTsQuery = sqlx::query_scalar(query).fetch_one(&mut conn).await?;
+
+    assert_eq!(empty, PgTsQuery::Empty);
+
+    // unprepared
+    let mut cursor = conn.fetch(query);
+    let row: PgRow = cursor.next().await?.unwrap();
+    let empty: PgTsQuery = row.get(0);
+
+    assert_eq!(empty, PgTsQuery::Empty);
+
+    Ok(())
+}
+
 // PgNumeric only works on the wire protocol
 test_prepared_type!(numeric(
     Postgres,
@@ -162,6 +857,31 @@ test_prepared_type!(numeric(
     "'NaN'::numeric" == PgNumeric::NotANumber,
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_numeric_scaled() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for &(value, scale, text) in &[
+        (12345_i128, 2, "123.45"),
+        (-5, 3, "-0.005"),
+        (i128::MAX, 6, "170141183460469231731687303715884.105727"),
+        (i128::MIN, 0, "-170141183460469231731687303715884105728"),
+    ] {
+        let (sent, received): (String, PgNumeric) =
+            sqlx::query_as("SELECT $1::numeric::text, $2::numeric")
+                .bind(PgNumeric::from_scaled(value, scale))
+                .bind(text)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(sent, text);
+        assert_eq!(received.to_scaled(scale)?, value);
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "bigdecimal")]
 test_type!(decimal(
     Postgres,
@@ -174,6 +894,58 @@ test_type!(decimal(
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+test_type!(decimal_vec(
+    Postgres,
+    Vec<sqlx::types::BigDecimal>,
+    "'{1,12.34,-0.001}'::numeric[]"
+        == vec![
+            "1".parse::<sqlx::types::BigDecimal>().unwrap(),
+            "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
+            "-0.001".parse::<sqlx::types::BigDecimal>().unwrap(),
+        ]
+));
+
+#[cfg(feature = "bigdecimal")]
+test_type!(decimal_opt_vec(
+    Postgres,
+    Vec<Option<sqlx::types::BigDecimal>>,
+    "ARRAY[NULL, 5.5]::numeric[]"
+        == vec![None, Some("5.5".parse::<sqlx::types::BigDecimal>().unwrap())]
+));
+
+// BigDecimal has no NaN; decoding it is an error rather than a panic
+#[cfg(feature = "bigdecimal")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decimal_nan() -> anyhow::Result<()> {
+    use sqlx::types::BigDecimal;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query_as::<_, (BigDecimal,)>("SELECT 'NaN'::numeric")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    {
+        let mut cursor = conn.fetch("SELECT 'NaN'::numeric");
+        let row = cursor.next().await?.unwrap();
+
+        assert!(row.try_get::<BigDecimal, _>(0).is_err());
+    }
+
+    // NaN is still available through PgNumeric
+    let (value,): (PgNumeric,) = sqlx::query_as("SELECT 'NaN'::numeric")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, PgNumeric::NotANumber);
+
+    Ok(())
+}
+
 // TODO: This is a minimal example that reproduces a typechecking error with
 // arrays of BigDecimal in macros.
 //
@@ -216,6 +988,77 @@ array_macro_test!(uuid(sqlx::types::Uuid, "select '{b731678f-636f-4135-bc6f-1944
         sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
     ]));
 
+#[cfg(feature = "uuid")]
+test_type!(uuid_vec(
+    Postgres,
+    Vec<sqlx::types::Uuid>,
+    "'{b731678f-636f-4135-bc6f-19440c13bd19,00000000-0000-0000-0000-000000000000,6c2c5b7e-2ab4-4a4e-8a6e-3d4b3f4c5e6f}'::uuid[]"
+        == vec![
+            sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap(),
+            sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap(),
+            sqlx::types::Uuid::parse_str("6c2c5b7e-2ab4-4a4e-8a6e-3d4b3f4c5e6f").unwrap(),
+        ],
+    "'{}'::uuid[]" == Vec::<sqlx::types::Uuid>::new()
+));
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_uuid_vec_any() -> anyhow::Result<()> {
+    use sqlx::types::Uuid;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let ids = vec![
+        Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19")?,
+        Uuid::parse_str("00000000-0000-0000-0000-000000000000")?,
+        Uuid::parse_str("6c2c5b7e-2ab4-4a4e-8a6e-3d4b3f4c5e6f")?,
+    ];
+
+    // a batch of ids is bound as a single uuid[]
+    let found: Vec<(Uuid,)> = sqlx::query_as(
+        "SELECT id FROM (VALUES ('b731678f-636f-4135-bc6f-19440c13bd19'::uuid), \
+         ('11111111-1111-1111-1111-111111111111'::uuid)) AS t (id) WHERE id = ANY($1)",
+    )
+    .bind(&ids)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(found, vec![(ids[0],)]);
+
+    Ok(())
+}
+
+test_type!(ip_addr(
+    Postgres,
+    std::net::IpAddr,
+    "'127.0.0.1'::inet" == std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)),
+    "'::1'::inet" == std::net::IpAddr::V6(std::net::Ipv6Addr::LOCALHOST)
+));
+
+// A CIDR of a single host is decoded as its address; the address is encoded as an INET
+test_type!(ip_addr_cidr(
+    Postgres,
+    std::net::IpAddr,
+    "'127.0.0.1/32'::cidr" == std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)),
+    "'::1/128'::cidr" == std::net::IpAddr::V6(std::net::Ipv6Addr::LOCALHOST)
+));
+
+test_type!(ipv4_addr(
+    Postgres,
+    std::net::Ipv4Addr,
+    "'192.168.0.10'::inet" == std::net::Ipv4Addr::new(192, 168, 0, 10)
+));
+
+test_type!(ipv6_addr(
+    Postgres,
+    std::net::Ipv6Addr,
+    "'2001:db8::8a2e:370:7334'::inet"
+        == "2001:db8::8a2e:370:7334"
+            .parse::<std::net::Ipv6Addr>()
+            .unwrap()
+));
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork(
     Postgres,
@@ -261,6 +1104,7 @@ array_macro_test!(ipnetwork(
 
 #[cfg(feature = "chrono")]
 mod chrono {
+    use sqlx::postgres::types::PgTimeTz;
     use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
     use super::*;
@@ -298,6 +1142,73 @@ mod chrono {
                 Utc,
             )
     ));
+
+    test_type!(chrono_time_tz(
+        Postgres,
+        PgTimeTz<NaiveTime, sqlx::types::chrono::FixedOffset>,
+        "'12:34:56+02'::timetz"
+            == PgTimeTz {
+                time: NaiveTime::from_hms(12, 34, 56),
+                offset: sqlx::types::chrono::FixedOffset::east(2 * 3600),
+            },
+        "'01:02:03.5-05:30'::timetz"
+            == PgTimeTz {
+                time: NaiveTime::from_hms_milli(1, 2, 3, 500),
+                offset: sqlx::types::chrono::FixedOffset::west(5 * 3600 + 30 * 60),
+            }
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_chrono_date_time_fixed_offset() -> anyhow::Result<()> {
+        use sqlx::types::chrono::FixedOffset;
+
+        let mut conn = new::<Postgres>().await?;
+
+        let expected = DateTime::<Utc>::from_utc(
+            NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100),
+            Utc,
+        );
+
+        let sql = "SELECT TIMESTAMPTZ '2019-01-02 05:10:20.115100+00'";
+
+        conn.execute("SET TIME ZONE INTERVAL '+05:30' HOUR TO MINUTE")
+            .await?;
+
+        // prepared; sent in UTC and converted to the session time zone
+        let (date_time,): (DateTime<FixedOffset>,) =
+            sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+        assert_eq!(date_time, expected);
+        assert_eq!(date_time.offset(), &FixedOffset::east(5 * 3600 + 30 * 60));
+
+        // unprepared; sent in the session time zone
+        {
+            let mut cursor = conn.fetch(sql);
+            let row = cursor.next().await?.unwrap();
+
+            let date_time: DateTime<FixedOffset> = row.get(0);
+
+            assert_eq!(date_time, expected);
+            assert_eq!(date_time.offset(), &FixedOffset::east(5 * 3600 + 30 * 60));
+
+            let date_time: DateTime<Utc> = row.get(0);
+
+            assert_eq!(date_time, expected);
+        }
+
+        // a named time zone can't be resolved and is decoded in UTC
+        conn.execute("SET TIME ZONE 'America/New_York'").await?;
+
+        let (date_time,): (DateTime<FixedOffset>,) =
+            sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+        assert_eq!(date_time, expected);
+        assert_eq!(date_time.offset(), &FixedOffset::east(0));
+
+        Ok(())
+    }
+
     // TODO: Can't seem to get this to work
     // array_macro_test!(chrono_date_time_tz(
     //     DateTime::<Utc>,
@@ -312,7 +1223,8 @@ mod chrono {
 #[cfg(feature = "time")]
 mod time_tests {
     use super::*;
-    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+    use sqlx::postgres::types::PgTimeTz;
+    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
     use time::{date, time};
 
     test_type!(time_date(
@@ -328,6 +1240,21 @@ mod time_tests {
         "TIME '05:10:20.115100'" == time!(5:10:20.115100)
     ));
 
+    test_type!(time_time_tz(
+        Postgres,
+        PgTimeTz<Time, UtcOffset>,
+        "'12:34:56+02'::timetz"
+            == PgTimeTz {
+                time: time!(12:34:56),
+                offset: UtcOffset::hours(2),
+            },
+        "'01:02:03.5-05:30'::timetz"
+            == PgTimeTz {
+                time: time!(1:02:03.5),
+                offset: UtcOffset::minutes(-330),
+            }
+    ));
+
     test_type!(time_date_time(
         Postgres,
         PrimitiveDateTime,
@@ -362,10 +1289,76 @@ test_type!(bool_opt_vec(Postgres, Vec<Option<bool>>,
     "ARRAY[NULL, true, NULL, false]::bool[]" == vec![None, Some(true), None, Some(false)],
 ));
 
+test_type!(i32_opt_vec(Postgres, Vec<Option<i32>>,
+    "ARRAY[1, NULL, 3, NULL]::int4[]" == vec![Some(1_i32), None, Some(3), None],
+    "ARRAY[NULL]::int4[]" == vec![None::<i32>],
+));
+
+// An empty array has zero dimensions
+test_type!(i32_empty_vec(Postgres, Vec<i32>,
+    "'{}'::int4[]" == Vec::<i32>::new(),
+    "ARRAY[]::int4[]" == Vec::<i32>::new(),
+));
+
 test_type!(f32_vec(Postgres, Vec<f32>,
     "ARRAY[0.0, 1.0, 3.14, 1.234, -0.002, 100000.0]::real[]" == vec![0.0_f32, 1.0, 3.14, 1.234, -0.002, 100000.0],
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_fixed_size_array() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = "SELECT ARRAY[1.0, 2.0, 3.0]::real[]";
+
+    let (embedding,): ([f32; 3],) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(embedding, [1.0, 2.0, 3.0]);
+
+    {
+        let mut cursor = conn.fetch(sql);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<[f32; 3], _>(0)?, [1.0, 2.0, 3.0]);
+
+        // the length of the array must match
+        assert!(row.try_get::<[f32; 2], _>(0).is_err());
+        assert!(row.try_get::<[f32; 4], _>(0).is_err());
+    }
+
+    let res = sqlx::query_as::<_, ([f32; 4],)>(sql)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("expected an array of 4 elements but received 3 elements"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_array_with_lower_bound() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = "SELECT '[2:4]={a,b,c}'::text[]";
+
+    // binary
+    let (array,): (Vec<String>,) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(array, ["a", "b", "c"]);
+
+    // text
+    let mut cursor = conn.fetch(sql);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<Vec<String>, _>(0)?, ["a", "b", "c"]);
+
+    Ok(())
+}
+
 test_type!(f64_vec(Postgres, Vec<f64>,
     "ARRAY[0.0, 1.0, 3.14, 1.234, -0.002, 100000.0]::double precision[]" == vec![0.0_f64, 1.0, 3.14, 1.234, -0.002, 100000.0],
 ));
@@ -376,6 +1369,43 @@ test_type!(i16_vec(Postgres, Vec<i16>,
     "ARRAY[0]::smallint[]" == vec![0_i16]
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_str_slice_any() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    const QUERY: &str = "SELECT name FROM (VALUES ('alice'), ('bob'), ('carol')) AS t (name) \
+                         WHERE name = ANY($1) ORDER BY name";
+
+    let names: &[&str] = &["carol", "alice", "dave"];
+
+    let found: Vec<(String,)> = sqlx::query_as(QUERY)
+        .bind(names)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(found, vec![("alice".to_owned(),), ("carol".to_owned(),)]);
+
+    // A fixed-size array or the strings of an iterator can be bound without copying them
+    let found: Vec<(String,)> = sqlx::query_as(QUERY)
+        .bind(["bob", "dave"])
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(found, vec![("bob".to_owned(),)]);
+
+    let owned = ["bob".to_owned(), "carol".to_owned()];
+
+    let found: Vec<(String,)> = sqlx::query_as(QUERY)
+        .bind(owned.iter().map(String::as_str).collect::<Vec<_>>())
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(found, vec![("bob".to_owned(),), ("carol".to_owned(),)]);
+
+    Ok(())
+}
+
 test_type!(string_vec(Postgres, Vec<String>,
     "ARRAY['', '\"']::text[]"
         == vec!["".to_string(), "\"".to_string()],
@@ -529,6 +1559,40 @@ async fn test_prepared_anonymous_record_arrays() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_anonymous_record_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = "SELECT ROW(1, 'x', 2::int8)";
+
+    let (rec,): ((i32, String, i64),) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(rec, (1, "x".to_string(), 2));
+
+    // the record must have exactly as many fields as the tuple
+    let res = sqlx::query_as::<_, ((i32, String),)>(sql)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    {
+        let mut cursor = conn.fetch(sql);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(
+            row.try_get::<(i32, String, i64), _>(0)?,
+            (1, "x".to_string(), 2)
+        );
+
+        assert!(row.try_get::<(i32, String), _>(0).is_err());
+        assert!(row.try_get::<(i32, String, i64, i32), _>(0).is_err());
+    }
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn test_prepared_structs() -> anyhow::Result<()> {
@@ -620,6 +1684,134 @@ END $$;
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_type_name_lookup() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // The name of a type is folded to lowercase unless it is quoted and `_` is not a wildcard
+    struct Named;
+
+    impl Type<Postgres> for Named {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("INT4")
+        }
+    }
+
+    impl Encode<Postgres> for Named {
+        fn encode(&self, buf: &mut PgRawBuffer) {
+            buf.extend_from_slice(&5_i32.to_be_bytes());
+        }
+    }
+
+    struct Wildcard;
+
+    impl Type<Postgres> for Wildcard {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("in_4")
+        }
+    }
+
+    impl Encode<Postgres> for Wildcard {
+        fn encode(&self, buf: &mut PgRawBuffer) {
+            buf.extend_from_slice(&5_i32.to_be_bytes());
+        }
+    }
+
+    let (is_five,): (bool,) = sqlx::query_as("SELECT $1 = 5")
+        .bind(Named)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(is_five);
+
+    let res = sqlx::query("SELECT $1")
+        .bind(Wildcard)
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // A quoted name keeps its case and is looked up in the `search_path`
+    struct Quoted;
+
+    impl Type<Postgres> for Quoted {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("\"Five\"")
+        }
+    }
+
+    impl Encode<Postgres> for Quoted {
+        fn encode(&self, buf: &mut PgRawBuffer) {
+            buf.extend_from_slice(&5_i32.to_be_bytes());
+        }
+    }
+
+    conn.execute(r#"CREATE DOMAIN pg_temp."Five" AS INT4 CHECK (VALUE = 5)"#)
+        .await?;
+
+    let (type_name, is_five): (String, bool) = sqlx::query_as("SELECT pg_typeof($1)::text, $1 = 5")
+        .bind(Quoted)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(type_name.ends_with(r#""Five""#));
+    assert!(is_five);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_text_format() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    //
+    // A type which is sent to Postgres in its text form
+    //
+
+    #[derive(Debug, PartialEq)]
+    struct TextInt(i32);
+
+    impl Type<Postgres> for TextInt {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("int4").with_text_format()
+        }
+    }
+
+    impl Encode<Postgres> for TextInt {
+        fn encode(&self, buf: &mut PgRawBuffer) {
+            buf.extend_from_slice(self.0.to_string().as_bytes());
+        }
+    }
+
+    impl<'de> Decode<'de, Postgres> for TextInt {
+        fn decode(value: PgValue<'de>) -> sqlx::Result<Self> {
+            <i32 as Decode<Postgres>>::decode(value).map(TextInt)
+        }
+    }
+
+    let rec: (bool, TextInt) = sqlx::query_as("SELECT $1 = 5012, $1")
+        .bind(TextInt(5012))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, TextInt(5012));
+
+    //
+    // A type which Postgres can only send in its text form
+    //
+
+    let rec: (String,) = sqlx::query_as("SELECT makeaclitem(0, 10, 'SELECT', false)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(rec.0.starts_with("=r/"));
+
+    Ok(())
+}
+
 //
 // JSON
 //
@@ -672,6 +1864,114 @@ mod json {
         "'{\"name\":\"Joe\",\"age\":33}'::json" == Json(Friend { name: "Joe".to_string(), age: 33 })
     ));
 
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_agg_into_vec() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let expected = vec![
+            Friend {
+                name: "Joe".to_string(),
+                age: 33,
+            },
+            Friend {
+                name: "Ann".to_string(),
+                age: 29,
+            },
+        ];
+
+        // `json_agg` produces JSON and `jsonb_agg` produces JSONB
+        for agg in &["json_agg", "jsonb_agg"] {
+            let query = format!(
+                "SELECT {}(friends ORDER BY idx) \
+                 FROM (VALUES (1, 'Joe', 33), (2, 'Ann', 29)) friends(idx, name, age)",
+                agg
+            );
+
+            // prepared
+            let friends: Json<Vec<Friend>> =
+                sqlx::query_scalar(&query).fetch_one(&mut conn).await?;
+
+            assert_eq!(friends.0, expected);
+
+            // unprepared
+            let mut cursor = conn.fetch(&*query);
+            let row: PgRow = cursor.next().await?.unwrap();
+            let friends: Json<Vec<Friend>> = row.get(0);
+
+            assert_eq!(friends.0, expected);
+        }
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_bind_json_and_try_get_json() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let friend = Friend {
+            name: "Joe".to_string(),
+            age: 33,
+        };
+
+        let (name,): (String,) = sqlx::query_as("SELECT $1->>'name'")
+            .bind_json(&friend)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(name, "Joe");
+
+        let mut cursor = sqlx::query("SELECT $1::jsonb, '[1, 2]'::json")
+            .bind_json(&friend)
+            .fetch(&mut conn);
+
+        let row: PgRow = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get_json::<Friend, _>(0)?, friend);
+        assert_eq!(row.try_get_json::<Vec<i32>, _>(1)?, vec![1, 2]);
+        assert!(row.try_get_json::<Friend, _>(1).is_err());
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_bind_jsonb_set() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        conn.execute(
+            r#"
+CREATE TEMPORARY TABLE profiles (id INT PRIMARY KEY, data JSONB NOT NULL);
+INSERT INTO profiles VALUES (1, '{"name": "Joe", "address": {"city": "Oslo", "zip": "0150"}}');
+            "#,
+        )
+        .await?;
+
+        sqlx::query("UPDATE profiles SET data = jsonb_set(data, $1, $2) WHERE id = $3")
+            .bind_jsonb_set("/address/city".parse()?, "Paris")
+            .bind(1_i32)
+            .execute(&mut conn)
+            .await?;
+
+        let (data,): (Json<JsonValue>,) =
+            sqlx::query_as("UPDATE profiles SET data = jsonb_set(data, $1, $2) RETURNING data")
+                .bind_jsonb_set("/a~1b".parse()?, json!([1, 2]))
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(
+            data.0,
+            json!({
+                "name": "Joe",
+                "address": { "city": "Paris", "zip": "0150" },
+                "a/b": [1, 2]
+            })
+        );
+
+        Ok(())
+    }
+
     #[cfg_attr(feature = "runtime-async-std", async_std::test)]
     #[cfg_attr(feature = "runtime-tokio", tokio::test)]
     async fn test_prepared_jsonb_raw_value() -> anyhow::Result<()> {
@@ -1,7 +1,13 @@
 use futures::TryStreamExt;
-use sqlx::postgres::{PgPool, PgQueryAs, PgRow};
-use sqlx::{Connection, Cursor, Executor, Postgres, Row};
+use sqlx::encode::EmptyAsNull;
+use sqlx::executor::RefExecutor;
+use sqlx::postgres::{
+    query_with_in, PgArguments, PgError, PgNamedArguments, PgPool, PgQueryAs, PgQueryBuilder,
+    PgQueryScalar, PgRow,
+};
+use sqlx::{Connection, Cursor, Execute, Executor, Postgres, Row};
 use sqlx_test::new;
+use std::collections::HashMap;
 use std::time::Duration;
 
 // TODO: As soon as I tried to deserialize a json value in a function, inferance for this test stopped working. I am at a loss as to how to resolve this.
@@ -88,6 +94,56 @@ async fn it_can_return_interleaved_nulls_issue_104() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_a_row_into_a_wide_tuple() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (id, name): (i32, String) = sqlx::query_as("SELECT 1, 'alice'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+    assert_eq!(name, "alice");
+
+    type Row = (
+        i32,
+        String,
+        bool,
+        i64,
+        Option<i32>,
+        f64,
+        i16,
+        String,
+        Option<String>,
+        Vec<i32>,
+        bool,
+        i32,
+    );
+
+    let row: Row = sqlx::query_as(
+        "SELECT 1, 'two', true, 4::INT8, NULL::INT4, 6.5::FLOAT8, 7::INT2, 'eight', NULL::TEXT, \
+         ARRAY[10]::INT4[], false, 12",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.0, 1);
+    assert_eq!(row.1, "two");
+    assert!(row.2);
+    assert_eq!(row.3, 4);
+    assert_eq!(row.4, None);
+    assert_eq!(row.5, 6.5);
+    assert_eq!(row.6, 7);
+    assert_eq!(row.7, "eight");
+    assert_eq!(row.8, None);
+    assert_eq!(row.9, vec![10]);
+    assert!(!row.10);
+    assert_eq!(row.11, 12);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_can_work_with_transactions() -> anyhow::Result<()> {
@@ -154,6 +210,52 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+// The same helpers can be called with a connection or a transaction
+async fn insert_account<E>(executor: E, id: i32) -> anyhow::Result<()>
+where
+    E: Executor<Database = Postgres>,
+{
+    sqlx::query("INSERT INTO accounts (id) VALUES ($1)")
+        .bind(id)
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+async fn count_accounts<'e, E>(executor: E) -> anyhow::Result<i64>
+where
+    E: 'e + Send + RefExecutor<'e, Database = Postgres>,
+{
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM accounts")
+        .fetch_one(executor)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_helpers_with_a_connection_or_a_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE accounts (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    insert_account(&mut conn, 1).await?;
+
+    let mut tx = conn.begin().await?;
+
+    insert_account(&mut tx, 2).await?;
+    assert_eq!(count_accounts(&mut tx).await?, 2);
+
+    let mut conn = tx.rollback().await?;
+
+    assert_eq!(count_accounts(&mut conn).await?, 1);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
@@ -247,6 +349,169 @@ async fn it_can_rollback_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_can_nest_savepoints_deeply() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO savepoints (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO savepoints (id) VALUES (2)")
+        .execute(&mut tx)
+        .await?;
+
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO savepoints (id) VALUES (3)")
+        .execute(&mut tx)
+        .await?;
+
+    // rollback of the innermost save point only discards its own insert
+    let tx = tx.rollback().await?;
+
+    // a save point can be created again at the same depth
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO savepoints (id) VALUES (4)")
+        .execute(&mut tx)
+        .await?;
+
+    let tx = tx.commit().await?;
+    let tx = tx.commit().await?;
+    let mut conn = tx.commit().await?;
+
+    let ids: Vec<(i32,)> = sqlx::query_as("SELECT id FROM savepoints ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![(1,), (2,), (4,)]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_pool_stats() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(2)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let counts = |pool: &PgPool| {
+        let stats = pool.stats();
+
+        (stats.size, stats.idle, stats.in_use, stats.opened)
+    };
+
+    assert_eq!(counts(&pool), (0, 0, 0, 0));
+
+    let a = pool.acquire().await?;
+    let b = pool.acquire().await?;
+
+    assert_eq!(counts(&pool), (2, 0, 2, 2));
+
+    // connections are returned to the pool on drop
+    drop(a);
+
+    assert_eq!(counts(&pool), (2, 1, 1, 2));
+
+    // an idle connection is reused
+    let a = pool.acquire().await?;
+
+    assert_eq!(pool.stats().opened, 2);
+    assert_eq!(pool.stats().in_use, 2);
+
+    drop(a);
+    drop(b);
+
+    assert_eq!(counts(&pool), (2, 2, 0, 2));
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_on_a_pool_reference() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // each query acquires a connection and releases it once it completes; with a single
+    // connection, the pool would otherwise deadlock on the second query
+    let affected = sqlx::query("SELECT generate_series(1, 3)")
+        .execute(&pool)
+        .await?;
+
+    assert_eq!(affected, 3);
+    assert_eq!(pool.stats().in_use, 0);
+
+    let (sum,): (i32,) = sqlx::query_as("SELECT $1 + 1")
+        .bind(41_i32)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(sum, 42);
+    assert_eq!(pool.stats().in_use, 0);
+
+    let values: Vec<(i32,)> = sqlx::query_as("SELECT generate_series(1, 3)")
+        .fetch_all(&pool)
+        .await?;
+
+    assert_eq!(values, vec![(1,), (2,), (3,)]);
+    assert_eq!(pool.stats().in_use, 0);
+
+    let value: Option<(i32,)> = sqlx::query_as("SELECT 1 WHERE false")
+        .fetch_optional(&pool)
+        .await?;
+
+    assert_eq!(value, None);
+    assert_eq!(pool.stats().opened, 1);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+// requires a server with TLS enabled whose certificate is issued for `localhost` by the CA in
+// `PGSSLROOTCERT`; run with
+// `cargo test --features postgres,tls -- --ignored it_connects_with_ssl_modes`
+#[cfg(feature = "tls")]
+#[ignore]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_ssl_modes() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgConnectOptions, PgConnection, PgSslMode};
+
+    let options: PgConnectOptions = dotenv::var("DATABASE_URL")?.parse()?;
+
+    let mut conn =
+        PgConnection::connect_with(&options.clone().ssl_mode(PgSslMode::Require)).await?;
+
+    conn.ping().await?;
+    conn.close().await?;
+
+    // The certificate is valid but not for this host name
+    let res =
+        PgConnection::connect_with(&options.host("127.0.0.1").ssl_mode(PgSslMode::VerifyFull))
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Tls(_))));
+
+    Ok(())
+}
+
 // run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
 #[ignore]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -383,3 +648,1433 @@ async fn test_describe() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_on_query() -> anyhow::Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::time::delay_for as sleep;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::sleep;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let logged_ = Arc::clone(&logged);
+
+    conn.on_query(move |log| {
+        logged_
+            .lock()
+            .unwrap()
+            .push((log.sql.to_owned(), log.arguments, log.elapsed));
+    });
+
+    conn.execute("SELECT pg_sleep(0.1)").await?;
+
+    let _: (i32,) = sqlx::query_as("SELECT $1::int4 + $2::int4")
+        .bind(1_i32)
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    // the rows of this query are read to completion so it is logged before the next query
+    let _ = sqlx::query("SELECT 1").execute(&mut conn).await?;
+
+    // the query is logged once its completion is read by the next query
+    let _: (i32,) = sqlx::query_as("SELECT 2").fetch_one(&mut conn).await?;
+
+    sleep(Duration::from_millis(200)).await;
+
+    // queries of the driver itself are not logged
+    conn.ping().await?;
+
+    let logged = logged.lock().unwrap();
+
+    assert_eq!(logged.len(), 4);
+
+    assert_eq!(logged[0].0, "SELECT pg_sleep(0.1)");
+    assert_eq!(logged[0].1, 0);
+    assert!(logged[0].2 >= Duration::from_millis(100));
+
+    assert_eq!(logged[1].0, "SELECT $1::int4 + $2::int4");
+    assert_eq!(logged[1].1, 2);
+
+    assert_eq!(logged[2].0, "SELECT 1");
+    assert_eq!(logged[2].1, 0);
+
+    assert_eq!(logged[3].0, "SELECT 2");
+    assert_eq!(logged[3].1, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_query_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM generate_series(1, $1)")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5);
+
+    let value: Option<i32> = sqlx::query_scalar("SELECT NULL::int4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+
+    let value: Option<String> = sqlx::query_scalar("SELECT 'hello' WHERE false")
+        .fetch_optional(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+
+    let values: Vec<i32> = sqlx::query_scalar("SELECT generate_series(1, 3)")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_row_not_found() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query_as::<_, (i32,)>("SELECT 1 WHERE false")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    let res = sqlx::query_scalar::<_, i32>("SELECT 1 WHERE $1")
+        .bind(false)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    let res = sqlx::query("SELECT 1 WHERE false")
+        .map(|row: PgRow| row.get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    // Other errors are not reported as a missing row
+    let res = sqlx::query_as::<_, (i32,)>("SELECT 1 FROM not_a_table")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+
+    // The connection is still usable after a statement fails to prepare
+    let (two,): (i32,) = sqlx::query_as("SELECT 2").fetch_one(&mut conn).await?;
+
+    assert_eq!(two, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_typed_null() -> anyhow::Result<()> {
+    use sqlx::arguments::Arguments;
+    use sqlx::postgres::PgArguments;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // the type of the parameter is sent with the NULL so Postgres does not need to infer it
+    let (ty, is_null, eq): (String, bool, Option<bool>) =
+        sqlx::query_as("SELECT pg_typeof($1)::text, $1 IS NULL, $1 = 10")
+            .bind(None::<i32>)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(ty, "integer");
+    assert!(is_null);
+    assert_eq!(eq, None);
+
+    // arguments built manually
+    let mut args = PgArguments::default();
+    args.add(None::<i32>);
+    args.add(Some(10_i32));
+
+    let (ty, matched): (String, bool) =
+        sqlx::query("SELECT pg_typeof($1)::text, $1 IS DISTINCT FROM $2")
+            .bind_all(args)
+            .try_map(|row: PgRow| Ok((row.try_get::<String, _>(0)?, row.try_get::<bool, _>(1)?)))
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(ty, "integer");
+    assert!(matched);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_default() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgDefault;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE orders (id INT NOT NULL, status TEXT NOT NULL DEFAULT 'new');
+        "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO orders (id, status) VALUES ($1, $2)")
+        .bind(1_i32)
+        .bind(PgDefault)
+        .execute(&mut conn)
+        .await?;
+
+    sqlx::query("INSERT INTO orders (id, status) VALUES ($1, $2)")
+        .bind(2_i32)
+        .bind("shipped")
+        .execute(&mut conn)
+        .await?;
+
+    // parameters following the DEFAULT keep their positions
+    sqlx::query("INSERT INTO orders (status, id) VALUES ($1, $2)")
+        .bind(PgDefault)
+        .bind(3_i32)
+        .execute(&mut conn)
+        .await?;
+
+    let statuses: Vec<(i32, String)> = sqlx::query_as("SELECT id, status FROM orders ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        statuses,
+        vec![
+            (1, "new".to_owned()),
+            (2, "shipped".to_owned()),
+            (3, "new".to_owned())
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_on_notice() -> anyhow::Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let notices_ = notices.clone();
+
+    conn.on_notice(move |notice| {
+        notices_.lock().unwrap().push((
+            notice.severity().to_owned(),
+            notice.code().to_owned(),
+            notice.message().to_owned(),
+        ));
+    });
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE notices (id INT);
+CREATE TEMPORARY TABLE IF NOT EXISTS notices (id INT);
+        "#,
+    )
+    .await?;
+
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'hello from %', 'plpgsql'; END $$")
+        .await?;
+
+    // the connection remains usable after a notice
+    let (value,): (i32,) = sqlx::query_as("SELECT $1")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5);
+
+    let notices = notices.lock().unwrap();
+
+    assert_eq!(notices.len(), 2);
+    assert_eq!(notices[0].0, "NOTICE");
+    assert_eq!(notices[0].1, "42P07");
+    assert!(notices[0].2.contains("already exists"));
+    assert_eq!(
+        notices[1],
+        (
+            "NOTICE".to_owned(),
+            "00000".to_owned(),
+            "hello from plpgsql".to_owned()
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE chunked (id INT NOT NULL);
+INSERT INTO chunked (id) SELECT generate_series(1, 1050);
+        "#,
+    )
+    .await?;
+
+    let chunks: Vec<Vec<(i32,)>> = sqlx::query_as("SELECT id FROM chunked ORDER BY id")
+        .fetch_chunks(100, &mut conn)
+        .try_collect()
+        .await?;
+
+    let lengths: Vec<usize> = chunks.iter().map(Vec::len).collect();
+
+    assert_eq!(
+        lengths,
+        [100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 50]
+    );
+
+    let ids: Vec<i32> = chunks.into_iter().flatten().map(|(id,)| id).collect();
+
+    assert_eq!(ids, (1..=1050).collect::<Vec<_>>());
+
+    // the rows may also be mapped
+    let chunks: Vec<Vec<i32>> = sqlx::query("SELECT id FROM chunked WHERE id > $1 ORDER BY id")
+        .bind(1000_i32)
+        .map(|row: PgRow| row.get::<i32, _>(0))
+        .fetch_chunks(100, &mut conn)
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks, vec![(1001..=1050).collect::<Vec<_>>()]);
+
+    // a size of zero is treated as one
+    let chunks: Vec<Vec<(i32,)>> = sqlx::query_as("SELECT id FROM chunked WHERE id > $1")
+        .bind(1047_i32)
+        .prefetch(0)
+        .fetch_chunks(0, &mut conn)
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks.iter().all(|chunk| chunk.len() == 1));
+
+    // a query that is not read to completion does not affect the next query
+    {
+        let mut chunks = Box::pin(
+            sqlx::query_as::<_, (i32,)>("SELECT id FROM chunked ORDER BY id")
+                .fetch_chunks(100, &mut conn),
+        );
+
+        let chunk = chunks.try_next().await?.unwrap();
+
+        assert_eq!(chunk.len(), 100);
+    }
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM chunked")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1050);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_prefetches_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut other = new::<Postgres>().await?;
+
+    // A sequence is not transactional so each row that postgres computed is visible to the
+    // other connection while the query is in-flight
+    conn.execute("DROP SEQUENCE IF EXISTS prefetched; CREATE SEQUENCE prefetched")
+        .await?;
+
+    let query = "SELECT nextval('prefetched') FROM generate_series(1, 10000)";
+
+    {
+        // The prefetch is kept when the arguments are replaced
+        let mut cursor = sqlx::query(query)
+            .prefetch(100)
+            .bind_all(PgArguments::default())
+            .fetch(&mut conn);
+        let mut count = 0;
+
+        while cursor.next().await?.is_some() {
+            count += 1;
+
+            if count % 1000 == 1 {
+                let (computed,): (i64,) = sqlx::query_as("SELECT last_value FROM prefetched")
+                    .fetch_one(&mut other)
+                    .await?;
+
+                // only the rows of the current round-trip have been computed
+                assert_eq!(computed, count + 99);
+            }
+        }
+
+        assert_eq!(count, 10000);
+    }
+
+    // The size of a chunk is independent of the prefetch
+    let chunks: Vec<Vec<(i64,)>> = sqlx::query_as(query)
+        .prefetch(300)
+        .fetch_chunks(1000, &mut conn)
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.len(), 10);
+    assert!(chunks.iter().all(|chunk| chunk.len() == 1000));
+
+    conn.execute("DROP SEQUENCE prefetched").await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_fetch_many() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE counters (id INT NOT NULL, value INT NOT NULL);
+INSERT INTO counters (id, value) VALUES (1, 10), (2, 20), (3, 30);
+        "#,
+    )
+    .await?;
+
+    // several statements are run as a simple query
+    let results: Vec<Either<u64, i32>> = sqlx::query(
+        "UPDATE counters SET value = value + 1 WHERE id < 3; SELECT value FROM counters ORDER BY id",
+    )
+    .map(|row: PgRow| row.get::<i32, _>(0))
+    .fetch_many(&mut conn)
+    .try_collect()
+    .await?;
+
+    assert_eq!(
+        results,
+        vec![
+            Either::Left(2),
+            Either::Right(11),
+            Either::Right(21),
+            Either::Right(30),
+            Either::Left(3),
+        ]
+    );
+
+    // a prepared statement with RETURNING yields both its rows and its count
+    let mut results: Vec<Either<u64, i32>> =
+        sqlx::query("DELETE FROM counters WHERE value > $1 RETURNING id")
+            .bind(20_i32)
+            .map(|row: PgRow| row.get::<i32, _>(0))
+            .fetch_many(&mut conn)
+            .try_collect()
+            .await?;
+
+    assert_eq!(results.pop(), Some(Either::Left(2)));
+
+    results.sort();
+
+    assert_eq!(results, vec![Either::Right(2), Either::Right(3)]);
+
+    // the lower-level equivalent on a cursor
+    let mut cursor = conn.fetch("UPDATE counters SET value = 0; SELECT COUNT(*) FROM counters");
+
+    let mut affected = Vec::new();
+    let mut counts = Vec::new();
+
+    while let Some(result) = cursor.next_many().await? {
+        match result {
+            Either::Left(rows) => affected.push(rows),
+            Either::Right(row) => counts.push(row.get::<i64, _>(0)),
+        }
+    }
+
+    assert_eq!(affected, vec![1, 1]);
+    assert_eq!(counts, vec![1]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reads_result_sets() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    {
+        let mut cursor = conn.fetch(
+            "SELECT generate_series(1, 3) AS n; \
+             CREATE TEMPORARY TABLE result_sets (id INT); \
+             SELECT 'a' AS name, 10 AS value UNION ALL SELECT 'b', 20",
+        );
+
+        let mut numbers = cursor.next_result_set().await?.unwrap();
+        let mut values = Vec::new();
+
+        while let Some(row) = numbers.next().await? {
+            values.push(row.get::<i32, _>("n"));
+        }
+
+        assert_eq!(values, vec![1, 2, 3]);
+        assert_eq!(numbers.rows_affected(), Some(3));
+
+        let mut create = cursor.next_result_set().await?.unwrap();
+
+        assert!(create.next().await?.is_none());
+
+        let mut names = cursor.next_result_set().await?.unwrap();
+        let row = names.next().await?.unwrap();
+
+        assert_eq!(row.get::<&str, _>("name"), "a");
+        assert_eq!(row.get::<i32, _>("value"), 10);
+
+        // The rest of a result set is skipped when moving on
+        assert!(cursor.next_result_set().await?.is_none());
+    }
+
+    // A prepared query has a single result set
+    {
+        let mut cursor = sqlx::query("SELECT $1::int4 + 1")
+            .bind(1_i32)
+            .fetch(&mut conn);
+
+        let mut result_set = cursor.next_result_set().await?.unwrap();
+
+        assert_eq!(result_set.next().await?.unwrap().get::<i32, _>(0), 2);
+        assert!(result_set.next().await?.is_none());
+        assert!(cursor.next_result_set().await?.is_none());
+    }
+
+    // The connection is usable after all result sets have been read
+    let (one,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unnest_insert() -> anyhow::Result<()> {
+    struct Price {
+        amount: i64,
+    }
+
+    struct Product {
+        name: String,
+        price: Price,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE products (name TEXT NOT NULL, price INT8 NOT NULL);
+        "#,
+    )
+    .await?;
+
+    let products: Vec<Product> = (0..1000)
+        .map(|i| Product {
+            name: format!("product {}", i),
+            price: Price { amount: i },
+        })
+        .collect();
+
+    let (names, prices) = sqlx::unnest_columns!(products => name, price.amount);
+
+    let affected = sqlx::query(
+        "INSERT INTO products (name, price) SELECT * FROM UNNEST($1::text[], $2::int8[])",
+    )
+    .bind(names)
+    .bind(prices)
+    .execute(&mut conn)
+    .await?;
+
+    assert_eq!(affected, 1000);
+
+    let (count, total, name): (i64, i64, String) = sqlx::query_as(
+        "SELECT COUNT(*), SUM(price)::int8, MAX(name) FILTER (WHERE price = 42) FROM products",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(count, 1000);
+    assert_eq!(total, (0..1000).sum::<i64>());
+    assert_eq!(name, "product 42");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_describe_params() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let _ = conn
+        .execute(
+            r#"
+CREATE TEMPORARY TABLE describe_test (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    score REAL
+)
+            "#,
+        )
+        .await?;
+
+    let describe = conn
+        .describe("SELECT id, name, score FROM describe_test WHERE id = $1 AND name <> $2")
+        .await?;
+
+    // INT4 and TEXT
+    assert_eq!(describe.param_types.len(), 2);
+    assert_eq!(describe.param_types[0].as_ref().unwrap().oid(), Some(23));
+    assert_eq!(describe.param_types[1].as_ref().unwrap().oid(), Some(25));
+
+    let columns: Vec<_> = describe
+        .result_columns
+        .iter()
+        .map(|column| {
+            (
+                column.name.as_deref().unwrap(),
+                column.type_info.as_ref().unwrap().oid(),
+            )
+        })
+        .collect();
+
+    // INT4, TEXT and FLOAT4
+    assert_eq!(
+        columns,
+        vec![("id", Some(23)), ("name", Some(25)), ("score", Some(700))]
+    );
+
+    // Nothing was executed so the connection can be used right away
+    let count: i64 = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM describe_test")
+        .fetch_one(&mut conn)
+        .await?
+        .0;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_unique_violations() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE unique_emails (
+    email TEXT NOT NULL CONSTRAINT unique_emails_email_key UNIQUE
+);
+        "#,
+    )
+    .await?;
+
+    let insert = "INSERT INTO unique_emails (email) VALUES ($1)";
+
+    sqlx::query(insert)
+        .bind("alice@example.com")
+        .execute(&mut conn)
+        .await?;
+
+    let res = sqlx::query(insert)
+        .bind("alice@example.com")
+        .execute(&mut conn)
+        .await;
+
+    let err = match res {
+        Err(sqlx::Error::Database(err)) => err,
+        res => panic!("expected a database error, got {:?}", res),
+    };
+
+    let err = err.downcast_ref::<PgError>();
+
+    assert!(err.is_unique_violation());
+    assert!(!err.is_foreign_key_violation());
+    assert_eq!(err.code(), "23505");
+    assert_eq!(err.severity(), "ERROR");
+    assert_eq!(err.table(), Some("unique_emails"));
+    assert_eq!(err.constraint(), Some("unique_emails_email_key"));
+    assert_eq!(
+        err.detail(),
+        Some("Key (email)=(alice@example.com) already exists.")
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_named_arguments() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut arguments = PgNamedArguments::new();
+    arguments.add("num", 21_i32);
+    arguments.add("name", "Alice".to_owned());
+
+    let (sql, arguments) =
+        arguments.into_query("SELECT @num + @num, @name::text, '@num', @num * 3 - @num");
+
+    let (sum, name, literal, diff) = sqlx::query(&sql)
+        .bind_all(arguments)
+        .map(|row: PgRow| {
+            (
+                row.get::<i32, _>(0),
+                row.get::<String, _>(1),
+                row.get::<String, _>(2),
+                row.get::<i32, _>(3),
+            )
+        })
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 42);
+    assert_eq!(name, "Alice");
+    assert_eq!(literal, "@num");
+    assert_eq!(diff, 42);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_expands_in_list() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (sql, arguments) = query_with_in(
+        "SELECT n FROM generate_series(1, 10) AS n WHERE n IN (?) AND n > $1 ORDER BY n",
+        vec![2_i32, 5, 8],
+    )?;
+
+    let numbers = sqlx::query(&sql)
+        .bind_all(arguments)
+        .bind(3_i32)
+        .map(|row: PgRow| row.get::<i32, _>(0))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(numbers, vec![5, 8]);
+
+    let (sql, arguments) = query_with_in(
+        "SELECT n FROM generate_series(1, 10) AS n WHERE n IN (?)",
+        Vec::<i32>::new(),
+    )?;
+
+    let numbers = sqlx::query(&sql)
+        .bind_all(arguments)
+        .map(|row: PgRow| row.get::<i32, _>(0))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert!(numbers.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fails_to_ping_a_terminated_connection() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.ping().await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut other = new::<Postgres>().await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1), pg_sleep(0.1)")
+        .bind(pid)
+        .execute(&mut other)
+        .await?;
+
+    assert!(conn.ping().await.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_replaces_a_terminated_connection_on_acquire() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .test_on_acquire(true)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut pool.acquire().await?)
+        .await?;
+
+    let mut other = new::<Postgres>().await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1), pg_sleep(0.1)")
+        .bind(pid)
+        .execute(&mut other)
+        .await?;
+
+    // the idle connection fails its ping and a new connection is opened in its place
+    let new_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut pool.acquire().await?)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+    assert_eq!(pool.stats().opened, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_keeps_a_connection_after_a_failed_query() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .test_on_acquire(false)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let size = pool.stats().size;
+
+    // an ERROR from postgres does not break the connection
+    let res = sqlx::query("SELECT 1 / 0").execute(&mut conn).await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+    assert!(!conn.is_broken());
+
+    drop(conn);
+
+    // the connection was returned to the idle connections of the pool
+    assert_eq!(pool.stats().size, size);
+
+    let same_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut pool.acquire().await?)
+        .await?;
+
+    assert_eq!(pid, same_pid);
+    assert_eq!(pool.stats().opened, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_closes_a_broken_connection_on_release() -> anyhow::Result<()> {
+    // without a ping on acquire, only the error in the query shows that the connection is broken
+    let pool = PgPool::builder()
+        .max_size(1)
+        .test_on_acquire(false)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut other = new::<Postgres>().await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1), pg_sleep(0.1)")
+        .bind(pid)
+        .execute(&mut other)
+        .await?;
+
+    assert!(sqlx::query("SELECT 1").execute(&mut conn).await.is_err());
+    assert!(conn.is_broken());
+
+    drop(conn);
+
+    // the broken connection was closed rather than returned to the idle connections
+    assert_eq!(pool.stats().size, 0);
+
+    let new_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut pool.acquire().await?)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+    assert_eq!(pool.stats().opened, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resets_the_role_after_release() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .after_release("RESET ROLE")
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.execute(
+        r#"
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'sqlx_tenant') THEN
+        CREATE ROLE sqlx_tenant;
+    END IF;
+END
+$$;
+        "#,
+    )
+    .await?;
+
+    let session_user: String = sqlx::query_scalar("SELECT session_user::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    conn.execute("SET ROLE sqlx_tenant").await?;
+
+    let role: String = sqlx::query_scalar("SELECT current_user::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(role, "sqlx_tenant");
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    drop(conn);
+
+    // the same connection is checked out again, once the role was reset
+    let mut conn = pool.acquire().await?;
+
+    let (role, new_pid): (String, i32) =
+        sqlx::query_as("SELECT current_user::text, pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(role, session_user);
+    assert_eq!(pid, new_pid);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_queries_from_file() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (id, name) = sqlx::query_from_file::<Postgres>("tests/test-query.sql")
+        .await?
+        .map(|row: PgRow| (row.get::<i32, _>("id"), row.get::<String, _>("name")))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+    assert_eq!(name, "Herp Derpinson");
+
+    // the file is only read once
+    let (first, _) = sqlx::query_from_file::<Postgres>("tests/test-query.sql")
+        .await?
+        .into_parts();
+
+    let (second, _) = sqlx::query_from_file::<Postgres>("tests/test-query.sql")
+        .await?
+        .into_parts();
+
+    assert!(std::ptr::eq(first, second));
+
+    match sqlx::query_from_file::<Postgres>("tests/does-not-exist.sql").await {
+        Err(sqlx::Error::Io(err)) => {
+            assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+            assert!(err.to_string().contains("tests/does-not-exist.sql"));
+        }
+
+        Err(err) => panic!("expected an I/O error, got {}", err),
+        Ok(_) => panic!("expected an I/O error"),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_as_another_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // an INT4 is bound as an INT8
+    let (ty, sum): (String, i64) = sqlx::query_as("SELECT pg_typeof($1)::text, $1 + $1")
+        .bind_as::<i64, _>(i32::MAX)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(ty, "bigint");
+    assert_eq!(sum, 2 * i32::MAX as i64);
+
+    // a NULL in a polymorphic context is declared as TEXT
+    let (ty, is_null): (String, bool) = sqlx::query_as("SELECT pg_typeof($1)::text, $1 IS NULL")
+        .bind_as::<Option<String>, _>(None)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(ty, "text");
+    assert!(is_null);
+
+    // a `u16` is bound as an OID
+    let name: String = sqlx::query_scalar("SELECT $1::regclass::text")
+        .bind_as::<u32, _>(1259_u16)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, "pg_class");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_empty_as_null() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (empty, text): (Option<String>, Option<String>) = sqlx::query_as("SELECT $1, $2")
+        .bind(EmptyAsNull(""))
+        .bind(EmptyAsNull("text"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(empty, None);
+    assert_eq!(text.as_deref(), Some("text"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reads_a_row_into_a_map() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let metrics: HashMap<String, i64> =
+        sqlx::query_as("SELECT 10::int8 AS requests, 2::int8 AS errors, 0::int8 AS timeouts")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(metrics.len(), 3);
+    assert_eq!(metrics["requests"], 10);
+    assert_eq!(metrics["errors"], 2);
+    assert_eq!(metrics["timeouts"], 0);
+
+    // every column must be of the same type
+    let res: Result<HashMap<String, i64>, _> =
+        sqlx::query_as("SELECT 10::int8 AS requests, 'none' AS errors")
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_unchecked() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT $1::int4 AS id, 'hello'::text AS name, NULL::int4 AS missing")
+            .bind(10_i32)
+            .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    // of a matching type, both decode the same value
+    assert_eq!(row.try_get_unchecked::<i32, _>("id")?, 10);
+    assert_eq!(row.try_get::<i32, _>("id")?, row.get_unchecked::<i32, _>(0));
+    assert_eq!(row.try_get_unchecked::<Option<i32>, _>("missing")?, None);
+
+    // otherwise the check is skipped and the value is decoded as if it was of the type
+    assert!(row.try_get::<Vec<u8>, _>("name").is_err());
+    assert_eq!(row.try_get_unchecked::<Vec<u8>, _>("name")?, b"hello");
+
+    // which can still fail to decode
+    assert!(row.try_get_unchecked::<i64, _>("id").is_err());
+
+    assert!(matches!(
+        row.try_get_unchecked::<i32, _>("unknown"),
+        Err(sqlx::Error::ColumnNotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sets_the_application_name() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgConnectOptions, PgConnection};
+
+    let options: PgConnectOptions = dotenv::var("DATABASE_URL")?.parse()?;
+    let options = options.application_name("sqlx_test_app");
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let (name,): (String,) = sqlx::query_as(
+        "SELECT application_name::text FROM pg_stat_activity WHERE pid = pg_backend_pid()",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(name, "sqlx_test_app");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_names_statements_by_prefix() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgConnectOptions, PgConnection};
+
+    let options: PgConnectOptions = dotenv::var("DATABASE_URL")?.parse()?;
+    let options = options.statement_name_prefix("sqlx_test_");
+
+    let query = "SELECT $1::int4 + 1";
+    let mut names = Vec::new();
+
+    for _ in 0..2 {
+        let mut conn = PgConnection::connect_with(&options).await?;
+
+        // once to prepare the statement and once more to reuse it
+        for value in 1..3_i32 {
+            let (sum,): (i32,) = sqlx::query_as(query)
+                .bind(value)
+                .fetch_one(&mut conn)
+                .await?;
+
+            assert_eq!(sum, value + 1);
+        }
+
+        let describe = conn.describe(query).await?;
+
+        assert_eq!(describe.param_types.len(), 1);
+
+        let (name,): (String,) = sqlx::query_as(
+            "SELECT name FROM pg_prepared_statements WHERE statement = $1 AND from_sql = false",
+        )
+        .bind(query)
+        .fetch_one(&mut conn)
+        .await?;
+
+        names.push(name);
+    }
+
+    // The prefix followed by 16 hex digits of the hash of the SQL
+    let hash = names[0].trim_start_matches("sqlx_test_");
+
+    assert!(names[0].starts_with("sqlx_test_"));
+    assert_eq!(hash.len(), 16);
+    assert!(hash.bytes().all(|b| b.is_ascii_hexdigit()));
+
+    // The same on every connection
+    assert_eq!(names[0], names[1]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_cancels_a_query_in_flight() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::{task::spawn, time::delay_for as sleep};
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::{task::sleep, task::spawn};
+
+    let mut conn = new::<Postgres>().await?;
+    let token = conn.cancel_token();
+
+    spawn(async move {
+        sleep(Duration::from_millis(100)).await;
+        token.cancel().await
+    });
+
+    let res = sqlx::query("SELECT pg_sleep(10)").execute(&mut conn).await;
+
+    let err = match res {
+        Err(sqlx::Error::Database(err)) => err,
+        res => panic!("expected a database error, got {:?}", res),
+    };
+
+    // query_canceled
+    assert_eq!(err.downcast_ref::<PgError>().code(), "57014");
+
+    // The connection is still usable
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_in_delimited_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copied (id INT NOT NULL, name TEXT, score FLOAT8)")
+        .await?;
+
+    let statement = "COPY copied (id, name, score) FROM STDIN (FORMAT text, DELIMITER '|')";
+
+    let mut copy = conn.copy_in_raw(statement).await?;
+
+    // the chunks do not need to align with the rows
+    copy.send(b"1|alice|1.5\n2|b").await?;
+    copy.send(b"ob|\\N\n3|a \\| pipe|3\n").await?;
+
+    assert_eq!(copy.finish().await?, 3);
+
+    let rows: Vec<(i32, String, Option<f64>)> =
+        sqlx::query_as("SELECT id, name, score FROM copied ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        rows,
+        vec![
+            (1, "alice".to_owned(), Some(1.5)),
+            (2, "bob".to_owned(), None),
+            (3, "a | pipe".to_owned(), Some(3.0)),
+        ]
+    );
+
+    // an error in the data is returned by `finish` and none of the rows are copied
+    let mut copy = conn.copy_in_raw(statement).await?;
+    copy.send(b"4|dave|4\nfive|eve|5\n").await?;
+    assert!(copy.finish().await.is_err());
+
+    // an abandoned COPY is aborted before the next query
+    let mut copy = conn.copy_in_raw(statement).await?;
+    copy.send(b"6|frank|6\n").await?;
+    drop(copy);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM copied")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reuses_a_connection_after_a_failed_copy() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .test_on_acquire(false)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copied (id INT NOT NULL)")
+        .await?;
+
+    // the error that postgres responds to an abort with is expected
+    let mut copy = conn.copy_in_raw("COPY copied (id) FROM STDIN").await?;
+    copy.send(b"1\n").await?;
+    copy.abort("aborted by the test").await?;
+
+    assert!(!conn.is_broken());
+
+    // an error in the data
+    let mut copy = conn.copy_in_raw("COPY copied (id) FROM STDIN").await?;
+    copy.send(b"one\n").await?;
+    assert!(matches!(copy.finish().await, Err(sqlx::Error::Database(_))));
+
+    assert!(!conn.is_broken());
+
+    drop(conn);
+
+    assert_eq!(pool.stats().size, 1);
+
+    // the same connection, with its temporary table, is acquired again
+    let mut conn = pool.acquire().await?;
+
+    let same_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM copied")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(pid, same_pid);
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_builds_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE built (id INT NOT NULL, name TEXT NOT NULL, team INT)")
+        .await?;
+
+    let mut builder = PgQueryBuilder::new("INSERT INTO built (id, name, team) ");
+
+    builder.push_values(
+        vec![
+            (1, "alice", Some(1)),
+            (2, "bob", Some(2)),
+            (3, "carol", None),
+        ],
+        |mut row, (id, name, team): (i32, &str, Option<i32>)| {
+            row.push_bind(id).push_bind(name).push_bind(team);
+        },
+    );
+
+    assert_eq!(builder.build().execute(&mut conn).await?, 3);
+
+    // the filters that were chosen at runtime
+    let filters: Vec<(Option<&str>, Option<i32>)> = vec![
+        (None, None),
+        (Some("'; DROP TABLE built; --"), None),
+        (None, Some(2)),
+        (Some("alice"), Some(1)),
+    ];
+
+    let mut matches = Vec::new();
+
+    for (name, team) in filters {
+        let mut builder = PgQueryBuilder::new("SELECT id FROM built WHERE ");
+        let mut conditions = builder.separated(" AND ");
+
+        conditions.push("id > ").push_bind_unseparated(0_i32);
+
+        if let Some(name) = name {
+            conditions.push("name = ").push_bind_unseparated(name);
+        }
+
+        if let Some(team) = team {
+            conditions.push("team = ").push_bind_unseparated(team);
+        }
+
+        builder.push(" ORDER BY id");
+
+        let ids: Vec<(i32,)> = builder.build_query_as().fetch_all(&mut conn).await?;
+
+        matches.push(ids.into_iter().map(|(id,)| id).collect::<Vec<_>>());
+    }
+
+    assert_eq!(matches, vec![vec![1, 2, 3], vec![], vec![2], vec![1]]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_parameters_without_prepared_statements() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgConnectOptions, PgConnection};
+
+    let options: PgConnectOptions = dotenv::var("DATABASE_URL")?.parse()?;
+    let options = options.prepared_statements(false);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let text = "it's a \\ '; SELECT 1; --";
+
+    let (echoed, sum, missing, len): (String, i32, Option<i64>, i32) =
+        sqlx::query_as("SELECT $1, 1-$2, $3, array_length($4, 1)")
+            .bind(text)
+            .bind(-5_i32)
+            .bind(None::<i64>)
+            .bind(vec!["a\"b", "c\\d", "e'f"])
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(echoed, text);
+    assert_eq!(sum, 6);
+    assert_eq!(missing, None);
+    assert_eq!(len, 3);
+
+    // the parameter is only replaced outside of string literals and comments
+    let (literal,): (String,) = sqlx::query_as("SELECT '$1' || $1 /* $1 */")
+        .bind("x")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(literal, "$1x");
+
+    // the literal stays one operand of the expression around the parameter
+    let (first,): (i32,) = sqlx::query_as("SELECT $1[1]")
+        .bind(vec![7_i32, 8])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(first, 7);
+
+    let (prepared,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM pg_prepared_statements WHERE from_sql = false")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(prepared, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_an_optional_filter() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let query = "SELECT id FROM (VALUES (1), (2), (3)) AS t (id) \
+                 WHERE ($1 IS NULL OR id = $1) AND ($2 IS NULL OR id <> $2) ORDER BY id";
+
+    let mut matches = Vec::new();
+
+    for &(id, not_id) in &[(None, None), (Some(2), None), (None, Some(1))] {
+        let ids: Vec<(i32,)> = sqlx::query_as(query)
+            .bind_opt::<i32>(id)
+            .bind_opt::<i32>(not_id)
+            .fetch_all(&mut conn)
+            .await?;
+
+        matches.push(ids.into_iter().map(|(id,)| id).collect::<Vec<_>>());
+    }
+
+    assert_eq!(matches, vec![vec![1, 2, 3], vec![2], vec![2, 3]]);
+
+    Ok(())
+}
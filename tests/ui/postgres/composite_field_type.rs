@@ -0,0 +1,10 @@
+// inventory_item is (name TEXT, supplier_id INT, price BIGINT); see tests/fixtures/postgres.sql
+#[derive(sqlx::Type)]
+#[sqlx(check, rename = "inventory_item")]
+struct InventoryItem {
+    name: Option<String>,
+    supplier_id: Option<i32>,
+    price: Option<i32>,
+}
+
+fn main() {}